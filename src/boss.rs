@@ -1 +1,207 @@
+use serde::{Deserialize, Serialize};
 
+use crate::{
+    dungeon::ChallengeBox,
+    hero::{Attribute, DiceColour, Effect, Resource},
+};
+
+/// The boss waiting below the final floor of a dungeon. Each boss round the hero
+/// rolls against `challenges`; every completed box deals one damage to the boss.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Boss {
+    pub name: String,
+    pub health: usize,
+    pub special_ability: Effect,
+    pub challenges: Vec<ChallengeBox>,
+}
+
+impl Boss {
+    pub fn get_phoenix() -> Boss {
+        Boss {
+            name: String::from("Phoenix"),
+            health: 6,
+            special_ability: Effect::Flames,
+            challenges: vec![
+                ChallengeBox {
+                    dice_type: Some(DiceColour::Magic),
+                    total_value: 5,
+                    single_dice: true,
+                    priority: false,
+                    consequences: vec![Attribute::Resource(Resource::Health, 2)],
+                },
+                ChallengeBox {
+                    dice_type: Some(DiceColour::Agility),
+                    total_value: 8,
+                    single_dice: false,
+                    priority: false,
+                    consequences: vec![Attribute::Resource(Resource::Health, 1)],
+                },
+                ChallengeBox {
+                    dice_type: Some(DiceColour::Strength),
+                    total_value: 4,
+                    single_dice: true,
+                    priority: false,
+                    consequences: vec![Attribute::Resource(Resource::Time, 2)],
+                },
+            ],
+        }
+    }
+
+    pub fn get_dragon() -> Boss {
+        Boss {
+            name: String::from("Dragon"),
+            health: 5,
+            special_ability: Effect::None,
+            challenges: vec![
+                ChallengeBox {
+                    dice_type: Some(DiceColour::Strength),
+                    total_value: 8,
+                    single_dice: false,
+                    priority: false,
+                    consequences: vec![Attribute::Resource(Resource::Health, 2)],
+                },
+                ChallengeBox {
+                    dice_type: Some(DiceColour::Agility),
+                    total_value: 4,
+                    single_dice: true,
+                    priority: false,
+                    consequences: vec![Attribute::Resource(Resource::Health, 1)],
+                },
+                ChallengeBox {
+                    dice_type: Some(DiceColour::Magic),
+                    total_value: 4,
+                    single_dice: true,
+                    priority: false,
+                    consequences: vec![Attribute::Resource(Resource::Time, 1)],
+                },
+            ],
+        }
+    }
+
+    pub fn get_hydra() -> Boss {
+        Boss {
+            name: String::from("Hydra"),
+            health: 6,
+            special_ability: Effect::Split,
+            challenges: vec![
+                ChallengeBox {
+                    dice_type: Some(DiceColour::Agility),
+                    total_value: 5,
+                    single_dice: true,
+                    priority: false,
+                    consequences: vec![Attribute::Resource(Resource::Health, 1)],
+                },
+                ChallengeBox {
+                    dice_type: Some(DiceColour::Strength),
+                    total_value: 5,
+                    single_dice: true,
+                    priority: false,
+                    consequences: vec![Attribute::Resource(Resource::Health, 1)],
+                },
+                ChallengeBox {
+                    dice_type: Some(DiceColour::Magic),
+                    total_value: 9,
+                    single_dice: false,
+                    priority: false,
+                    consequences: vec![
+                        Attribute::Resource(Resource::Health, 1),
+                        Attribute::Resource(Resource::Time, 1),
+                    ],
+                },
+            ],
+        }
+    }
+
+    pub fn get_yeti() -> Boss {
+        Boss {
+            name: String::from("Yeti"),
+            health: 6,
+            special_ability: Effect::Frost,
+            challenges: vec![
+                ChallengeBox {
+                    dice_type: Some(DiceColour::Strength),
+                    total_value: 10,
+                    single_dice: false,
+                    priority: false,
+                    consequences: vec![Attribute::Resource(Resource::Health, 2)],
+                },
+                ChallengeBox {
+                    dice_type: Some(DiceColour::Magic),
+                    total_value: 4,
+                    single_dice: true,
+                    priority: false,
+                    consequences: vec![Attribute::Resource(Resource::Time, 2)],
+                },
+                ChallengeBox {
+                    dice_type: None,
+                    total_value: 5,
+                    single_dice: true,
+                    priority: false,
+                    consequences: vec![Attribute::Resource(Resource::Health, 1)],
+                },
+            ],
+        }
+    }
+
+    pub fn get_lich() -> Boss {
+        Boss {
+            name: String::from("Lich"),
+            health: 7,
+            special_ability: Effect::Undying,
+            challenges: vec![
+                ChallengeBox {
+                    dice_type: Some(DiceColour::Magic),
+                    total_value: 10,
+                    single_dice: false,
+                    priority: false,
+                    consequences: vec![Attribute::Resource(Resource::Health, 2)],
+                },
+                ChallengeBox {
+                    dice_type: Some(DiceColour::Strength),
+                    total_value: 5,
+                    single_dice: true,
+                    priority: false,
+                    consequences: vec![Attribute::Resource(Resource::Health, 1)],
+                },
+                ChallengeBox {
+                    dice_type: Some(DiceColour::Agility),
+                    total_value: 5,
+                    single_dice: true,
+                    priority: false,
+                    consequences: vec![Attribute::Resource(Resource::Time, 2)],
+                },
+            ],
+        }
+    }
+
+    pub fn get_minotaur() -> Boss {
+        Boss {
+            name: String::from("Minotaur"),
+            health: 7,
+            special_ability: Effect::None,
+            challenges: vec![
+                ChallengeBox {
+                    dice_type: Some(DiceColour::Strength),
+                    total_value: 6,
+                    single_dice: true,
+                    priority: false,
+                    consequences: vec![Attribute::Resource(Resource::Health, 2)],
+                },
+                ChallengeBox {
+                    dice_type: Some(DiceColour::Agility),
+                    total_value: 12,
+                    single_dice: false,
+                    priority: false,
+                    consequences: vec![Attribute::Resource(Resource::Health, 2)],
+                },
+                ChallengeBox {
+                    dice_type: Some(DiceColour::Magic),
+                    total_value: 4,
+                    single_dice: true,
+                    priority: false,
+                    consequences: vec![Attribute::Resource(Resource::Time, 1)],
+                },
+            ],
+        }
+    }
+}
@@ -0,0 +1,337 @@
+use std::{
+    fmt::Display,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::Path,
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    dungeon::Dungeon,
+    game::Game,
+    game_setup::difficulty::Difficulty,
+    hero::{DiceColour, Hero, HeroError},
+    history::RunRecord,
+};
+
+/// Perks a campaign earns for every run it wins.
+pub const PERKS_PER_WIN: usize = 1;
+
+#[derive(Debug)]
+pub enum CampaignError {
+    Io(io::Error),
+    Parse(String),
+    Hero(HeroError),
+    NoPerkToSpend,
+    InvalidPerk(Perk),
+}
+
+impl Display for CampaignError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CampaignError::Io(err) => write!(f, "could not access the campaign file: {}", err),
+            CampaignError::Parse(message) => write!(f, "could not read the campaign: {}", message),
+            CampaignError::Hero(err) => write!(f, "could not apply a perk: {}", err),
+            CampaignError::NoPerkToSpend => write!(f, "the campaign has no perks to spend"),
+            CampaignError::InvalidPerk(perk) => write!(f, "{} is not a perk heroes can take", perk),
+        }
+    }
+}
+
+impl std::error::Error for CampaignError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            CampaignError::Io(err) => Some(err),
+            CampaignError::Hero(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for CampaignError {
+    fn from(err: io::Error) -> Self {
+        CampaignError::Io(err)
+    }
+}
+
+impl From<HeroError> for CampaignError {
+    fn from(err: HeroError) -> Self {
+        CampaignError::Hero(err)
+    }
+}
+
+pub type Result<T> = std::result::Result<T, CampaignError>;
+
+/// A lasting bonus applied to every hero at the start of each later run.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Perk {
+    ExtraPotion,
+    /// One more Strength, Agility or Magic dice.
+    BonusDice(DiceColour),
+    /// One more skill at every level.
+    ExtraSkillSlot,
+}
+
+impl Display for Perk {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Perk::ExtraPotion => write!(f, "Extra starting potion"),
+            Perk::BonusDice(colour) => write!(f, "Bonus {} dice", colour),
+            Perk::ExtraSkillSlot => write!(f, "Extra skill slot"),
+        }
+    }
+}
+
+impl Perk {
+    /// Looks a perk up by a short name: potion, strength, agility, magic or skill.
+    pub fn from_name(name: &str) -> Option<Perk> {
+        match name.to_ascii_lowercase().as_str() {
+            "potion" => Some(Perk::ExtraPotion),
+            "strength" => Some(Perk::BonusDice(DiceColour::Strength)),
+            "agility" => Some(Perk::BonusDice(DiceColour::Agility)),
+            "magic" => Some(Perk::BonusDice(DiceColour::Magic)),
+            "skill" => Some(Perk::ExtraSkillSlot),
+            _ => None,
+        }
+    }
+
+    pub fn apply(&self, hero: &mut Hero) -> Result<()> {
+        match self {
+            Perk::ExtraPotion => hero.potions += 1,
+            Perk::BonusDice(colour) => {
+                hero.change_attribute_quantity(*colour, 1)?;
+            }
+            Perk::ExtraSkillSlot => {
+                for stats in hero.levels.values_mut() {
+                    stats.skills += 1;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn is_valid(&self) -> bool {
+        match self {
+            Perk::BonusDice(colour) => matches!(
+                colour,
+                DiceColour::Strength | DiceColour::Agility | DiceColour::Magic
+            ),
+            Perk::ExtraPotion | Perk::ExtraSkillSlot => true,
+        }
+    }
+}
+
+/// A linked series of dungeon runs played on one difficulty, with the perks they
+/// have earned along the way.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Campaign {
+    pub name: String,
+    pub difficulty: Difficulty,
+    pub runs: Vec<RunRecord>,
+    pub perks: Vec<Perk>,
+    pub unspent_perks: usize,
+}
+
+impl Campaign {
+    pub fn new(name: &str, difficulty: Difficulty) -> Campaign {
+        Campaign {
+            name: name.to_string(),
+            difficulty,
+            runs: Vec::new(),
+            perks: Vec::new(),
+            unspent_perks: 0,
+        }
+    }
+
+    pub fn load(path: &Path) -> Result<Campaign> {
+        let json = fs::read_to_string(path)?;
+        serde_json::from_str(&json).map_err(|err| CampaignError::Parse(err.to_string()))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        fs::write(path, self.to_json()?)?;
+        Ok(())
+    }
+
+    /// Saves a new campaign, refusing to overwrite a file that is already there.
+    pub fn create(&self, path: &Path) -> Result<()> {
+        let mut file = OpenOptions::new().write(true).create_new(true).open(path)?;
+        file.write_all(self.to_json()?.as_bytes())?;
+        Ok(())
+    }
+
+    fn to_json(&self) -> Result<String> {
+        serde_json::to_string_pretty(self).map_err(|err| CampaignError::Parse(err.to_string()))
+    }
+
+    pub fn wins(&self) -> usize {
+        self.runs.iter().filter(|run| run.summary.won).count()
+    }
+
+    /// Gives `hero` every perk the campaign has chosen so far.
+    pub fn apply_perks(&self, hero: &mut Hero) -> Result<()> {
+        for perk in self.perks.iter() {
+            perk.apply(hero)?;
+        }
+        Ok(())
+    }
+
+    /// Sets up the next run on the campaign's difficulty with the perks applied.
+    pub fn start_run(&self, mut hero: Hero, dungeon: Dungeon, seed: u64) -> Result<Game> {
+        self.apply_perks(&mut hero)?;
+        Ok(Game::with_difficulty(hero, dungeon, seed, self.difficulty))
+    }
+
+    /// Adds a finished game to the campaign, earning perks if it was won.
+    pub fn record_run(&mut self, game: &Game) {
+        let run = RunRecord::from_game(game);
+        if run.summary.won {
+            self.unspent_perks += PERKS_PER_WIN;
+        }
+        self.runs.push(run);
+    }
+
+    pub fn choose_perk(&mut self, perk: Perk) -> Result<()> {
+        if !perk.is_valid() {
+            return Err(CampaignError::InvalidPerk(perk));
+        }
+        if self.unspent_perks == 0 {
+            return Err(CampaignError::NoPerkToSpend);
+        }
+        self.unspent_perks -= 1;
+        self.perks.push(perk);
+        Ok(())
+    }
+}
+
+impl Display for Campaign {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "{} ({}): {} run(s), {} won",
+            self.name,
+            self.difficulty,
+            self.runs.len(),
+            self.wins()
+        )?;
+        for (index, run) in self.runs.iter().enumerate() {
+            writeln!(f, "  {}. {}", index + 1, run)?;
+        }
+        let perks: Vec<String> = self.perks.iter().map(Perk::to_string).collect();
+        writeln!(
+            f,
+            "Perks: {}",
+            if perks.is_empty() {
+                String::from("none")
+            } else {
+                perks.join(", ")
+            }
+        )?;
+        write!(f, "Perks to spend: {}", self.unspent_perks)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hero::PlayerCount;
+
+    /// A campaign saved before runs were shared with the history, when each run
+    /// was stored as a `CampaignRun`.
+    const SAVED_BEFORE_HISTORY: &str = r#"{
+  "name": "Old",
+  "difficulty": "Veteran",
+  "runs": [
+    {
+      "hero": "Warrior",
+      "dungeon": "Dragon's Cave",
+      "seed": 9,
+      "summary": {
+        "difficulty": "Veteran",
+        "won": true,
+        "floor": 4,
+        "remaining_health": 2,
+        "turns": 140,
+        "score": 38,
+        "death_cause": null
+      }
+    }
+  ],
+  "perks": [{ "BonusDice": "Strength" }],
+  "unspent_perks": 0
+}"#;
+
+    #[test]
+    fn loads_campaigns_saved_before_the_history() {
+        let campaign: Campaign = serde_json::from_str(SAVED_BEFORE_HISTORY).unwrap();
+        assert_eq!(campaign.difficulty, Difficulty::Veteran);
+        assert_eq!(campaign.wins(), 1);
+        assert_eq!(campaign.runs[0].hero, "Warrior");
+        assert_eq!(campaign.runs[0].summary.score, 38);
+        assert_eq!(campaign.perks, vec![Perk::BonusDice(DiceColour::Strength)]);
+    }
+
+    #[test]
+    fn round_trips_through_a_file() {
+        let path = std::env::temp_dir().join(format!("odd-campaign-{}.json", std::process::id()));
+        let _ = fs::remove_file(&path);
+        let mut campaign: Campaign = serde_json::from_str(SAVED_BEFORE_HISTORY).unwrap();
+        campaign.create(&path).unwrap();
+        assert!(campaign.create(&path).is_err());
+        campaign.unspent_perks = 1;
+        campaign.choose_perk(Perk::ExtraPotion).unwrap();
+        campaign.save(&path).unwrap();
+        let loaded = Campaign::load(&path);
+        fs::remove_file(&path).unwrap();
+        assert_eq!(loaded.unwrap(), campaign);
+    }
+
+    #[test]
+    fn runs_start_with_every_chosen_perk() {
+        let mut campaign = Campaign::new("Perks", Difficulty::Veteran);
+        campaign.perks = vec![
+            Perk::ExtraPotion,
+            Perk::BonusDice(DiceColour::Magic),
+            Perk::ExtraSkillSlot,
+        ];
+        let hero = Hero::get_mage(PlayerCount::Solo);
+        let game = campaign
+            .start_run(hero.clone(), Dungeon::get_dragons_cave(), 4)
+            .unwrap();
+        assert_eq!(game.difficulty, Difficulty::Veteran);
+        assert_eq!(game.hero.potions, hero.potions + 1);
+        assert_eq!(
+            game.hero.dice_count(&DiceColour::Magic),
+            hero.dice_count(&DiceColour::Magic) + 1
+        );
+        assert_eq!(
+            game.hero.dice_count(&DiceColour::Strength),
+            hero.dice_count(&DiceColour::Strength)
+        );
+        for (level, stats) in game.hero.levels.iter() {
+            assert_eq!(stats.skills, hero.levels[level].skills + 1);
+        }
+    }
+
+    #[test]
+    fn perks_have_to_be_earned() {
+        let mut campaign = Campaign::new("Perks", Difficulty::Standard);
+        assert!(matches!(
+            campaign.choose_perk(Perk::ExtraPotion),
+            Err(CampaignError::NoPerkToSpend)
+        ));
+        campaign.unspent_perks = 1;
+        assert!(matches!(
+            campaign.choose_perk(Perk::BonusDice(DiceColour::Heroic)),
+            Err(CampaignError::InvalidPerk(_))
+        ));
+        campaign.choose_perk(Perk::ExtraPotion).unwrap();
+        assert!(matches!(
+            campaign.choose_perk(Perk::ExtraPotion),
+            Err(CampaignError::NoPerkToSpend)
+        ));
+        assert_eq!(campaign.perks, vec![Perk::ExtraPotion]);
+        assert_eq!(campaign.unspent_perks, 0);
+    }
+}
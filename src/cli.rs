@@ -0,0 +1,762 @@
+use std::{
+    fmt::Display,
+    net::TcpListener,
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use one_deck_dungeon::{
+    campaign::{Campaign, Perk},
+    dungeon::Dungeon,
+    dungeon_builder,
+    encounter::{Combat, Peril},
+    error::{DataError, Error},
+    game::Card,
+    game::Game,
+    game_setup::{
+        self,
+        difficulty::Difficulty,
+        pack::{self, ContentPack},
+    },
+    generator::{card::CardGenerator, dungeon::DungeonGenerator, DifficultyBand},
+    hero::{Hero, PlayerCount},
+    hero_builder,
+    history::{self, History},
+    lint,
+    mcts::{self, MctsPolicy},
+    policy::{FirstFitPolicy, GreedyPolicy, SolverPolicy},
+    print_helper::{print_combat, print_dungeon, print_hero, print_peril},
+    protocol, server,
+    simulator::{self, GameSummary, SimulationConfig, SimulationReport},
+    tui::{self, app::App},
+};
+use serde::Serialize;
+
+pub const USAGE: &str = "\
+Usage: one_deck_dungeon [COMMAND] [OPTIONS]
+
+Commands:
+  play                                  Start the terminal UI (the default)
+  show hero|dungeon <NAME>              Print a hero or dungeon card
+  list heroes|dungeons|perils|combats|skills|packs
+                                        List the built-in cards
+  simulate                              Play many games and report win rates
+  generate dungeon|combat|peril         Make up a dungeon or card, rated with the odds
+  bot                                   Play over stdin/stdout as JSON lines
+  serve                                 Host games for bots over TCP
+  validate                              Check the built-in cards for mistakes
+  campaign new|show|play <FILE>         Start, review or play the next run of a campaign
+  campaign perk <FILE> potion|strength|agility|magic|skill
+                                        Spend a perk earned by winning a run
+  history [runs]                        Report on past games, or list them
+  help                                  Show this message
+
+Options:
+  --seed <N>                            Seed for games, simulations and generated cards
+  --json                                Print JSON instead of text
+  --hero <NAME>                         Hero to play or simulate, or a hero's .json file
+  --partner <NAME>                      Second hero for a co-op game, or a .json file
+  --dungeon <NAME>                      Dungeon to play or simulate, or a dungeon's .json file
+  --difficulty <MODE>                   novice, standard, veteran, heroic or epic
+  --packs <NAMES>                       Content packs to play with, comma separated (default all)
+  --band <MIN-MAX>                      Rating a generated floor or card must land in
+  --games <N>                           Games per matchup, or games for a bot
+  --policy first-fit|greedy|solver|mcts Policy used when simulating
+  --iterations <N>                      Search iterations per move for mcts (default 100)
+  --bind <ADDRESS>                      Address to serve on (default 127.0.0.1:7878)
+  --history <FILE>                      History file (default ~/.one_deck_dungeon_history.jsonl)";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CliError {
+    Usage(String),
+}
+
+impl Display for CliError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CliError::Usage(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+impl std::error::Error for CliError {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CardKind {
+    Heroes,
+    Dungeons,
+    Perils,
+    Combats,
+    Skills,
+    Packs,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+    Play,
+    ShowHero(String),
+    ShowDungeon(String),
+    List(CardKind),
+    Simulate,
+    GenerateDungeon,
+    GenerateCombat,
+    GeneratePeril,
+    Bot,
+    Serve,
+    Validate,
+    CampaignNew(String),
+    CampaignShow(String),
+    CampaignPerk(String, Perk),
+    CampaignPlay(String),
+    History,
+    HistoryRuns,
+    Help,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Options {
+    pub seed: Option<u64>,
+    pub json: bool,
+    pub hero: Option<String>,
+    pub partner: Option<String>,
+    pub dungeon: Option<String>,
+    pub difficulty: Option<Difficulty>,
+    pub packs: Option<Vec<String>>,
+    pub band: Option<DifficultyBand>,
+    pub games: Option<usize>,
+    pub policy: Option<String>,
+    pub iterations: Option<usize>,
+    pub bind: Option<String>,
+    pub history: Option<String>,
+}
+
+pub fn parse(args: impl IntoIterator<Item = String>) -> Result<(Command, Options), CliError> {
+    let mut options = Options::default();
+    let mut positional = Vec::new();
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--json" => options.json = true,
+            "--seed" => options.seed = Some(parse_number(&arg, args.next())?),
+            "--games" => options.games = Some(parse_number(&arg, args.next())?),
+            "--iterations" => options.iterations = Some(parse_number(&arg, args.next())?),
+            "--hero" => options.hero = Some(value(&arg, args.next())?),
+            "--partner" => options.partner = Some(value(&arg, args.next())?),
+            "--dungeon" => options.dungeon = Some(value(&arg, args.next())?),
+            "--difficulty" => {
+                let name = value(&arg, args.next())?;
+                let difficulty = Difficulty::from_name(&name)
+                    .ok_or(CliError::Usage(format!("unknown difficulty {}", name)))?;
+                options.difficulty = Some(difficulty);
+            }
+            "--packs" => {
+                let names = value(&arg, args.next())?;
+                options.packs = Some(
+                    names
+                        .split(',')
+                        .map(|name| name.trim().to_string())
+                        .collect(),
+                );
+            }
+            "--band" => {
+                let range = value(&arg, args.next())?;
+                let band = DifficultyBand::parse(&range).ok_or(CliError::Usage(format!(
+                    "--band expects MIN-MAX, not {}",
+                    range
+                )))?;
+                options.band = Some(band);
+            }
+            "--policy" => options.policy = Some(value(&arg, args.next())?),
+            "--bind" => options.bind = Some(value(&arg, args.next())?),
+            "--history" => options.history = Some(value(&arg, args.next())?),
+            "-h" | "--help" => positional.insert(0, String::from("help")),
+            flag if flag.starts_with("--") => {
+                return Err(CliError::Usage(format!("unknown option {}", flag)))
+            }
+            _ => positional.push(arg),
+        }
+    }
+
+    let positional: Vec<&str> = positional.iter().map(String::as_str).collect();
+    let command = match positional.as_slice() {
+        [] | ["play"] => Command::Play,
+        ["help", ..] => Command::Help,
+        ["show", "hero", name @ ..] if !name.is_empty() => Command::ShowHero(name.join(" ")),
+        ["show", "dungeon", name @ ..] if !name.is_empty() => Command::ShowDungeon(name.join(" ")),
+        ["show", ..] => {
+            return Err(CliError::Usage(String::from(
+                "expected show hero <NAME> or show dungeon <NAME>",
+            )))
+        }
+        ["list", kind] => Command::List(match *kind {
+            "heroes" => CardKind::Heroes,
+            "dungeons" => CardKind::Dungeons,
+            "perils" => CardKind::Perils,
+            "combats" => CardKind::Combats,
+            "skills" => CardKind::Skills,
+            "packs" => CardKind::Packs,
+            _ => return Err(CliError::Usage(format!("cannot list {}", kind))),
+        }),
+        ["list", ..] => {
+            return Err(CliError::Usage(String::from(
+                "expected list heroes|dungeons|perils|combats|skills|packs",
+            )))
+        }
+        ["simulate"] => Command::Simulate,
+        ["generate", "dungeon"] => Command::GenerateDungeon,
+        ["generate", "combat"] => Command::GenerateCombat,
+        ["generate", "peril"] => Command::GeneratePeril,
+        ["generate", ..] => {
+            return Err(CliError::Usage(String::from(
+                "expected generate dungeon|combat|peril",
+            )))
+        }
+        ["bot"] => Command::Bot,
+        ["serve"] => Command::Serve,
+        ["validate"] => Command::Validate,
+        ["campaign", "new", file] => Command::CampaignNew(file.to_string()),
+        ["campaign", "show", file] => Command::CampaignShow(file.to_string()),
+        ["campaign", "play", file] => Command::CampaignPlay(file.to_string()),
+        ["campaign", "perk", file, perk] => match Perk::from_name(perk) {
+            Some(perk) => Command::CampaignPerk(file.to_string(), perk),
+            None => return Err(CliError::Usage(format!("unknown perk {}", perk))),
+        },
+        ["history"] => Command::History,
+        ["history", "runs"] => Command::HistoryRuns,
+        ["campaign", ..] => {
+            return Err(CliError::Usage(String::from(
+                "expected campaign new|show|play <FILE> or campaign perk <FILE> <PERK>",
+            )))
+        }
+        [command, ..] => return Err(CliError::Usage(format!("unknown command {}", command))),
+    };
+    Ok((command, options))
+}
+
+fn value(flag: &str, value: Option<String>) -> Result<String, CliError> {
+    value.ok_or(CliError::Usage(format!("{} needs a value", flag)))
+}
+
+fn parse_number<T: std::str::FromStr>(flag: &str, raw: Option<String>) -> Result<T, CliError> {
+    let raw = value(flag, raw)?;
+    raw.parse()
+        .map_err(|_| CliError::Usage(format!("{} expects a number, not {}", flag, raw)))
+}
+
+/// Runs `command`, returning whether it succeeded.
+pub fn run(command: Command, options: &Options) -> Result<bool, Error> {
+    match command {
+        Command::Play => play(options)?,
+        Command::ShowHero(name) => {
+            let sides = [PlayerCount::Solo, PlayerCount::Coop]
+                .into_iter()
+                .map(|players| game_setup::find_hero(&name, players))
+                .collect::<Result<Vec<Hero>, DataError>>()?;
+            output(options, &sides, || sides.iter().for_each(print_hero));
+        }
+        Command::ShowDungeon(name) => {
+            let dungeon = game_setup::find_dungeon(&name)?;
+            output(options, &dungeon, || print_dungeon(&dungeon));
+        }
+        Command::List(kind) => list(kind, options)?,
+        Command::Simulate => simulate(options)?,
+        Command::GenerateDungeon => generate_dungeon(options)?,
+        Command::GenerateCombat => generate_card(options, true)?,
+        Command::GeneratePeril => generate_card(options, false)?,
+        Command::Bot => bot(options)?,
+        Command::Serve => {
+            let address = options.bind.as_deref().unwrap_or(server::DEFAULT_ADDRESS);
+            let listener = TcpListener::bind(address)?;
+            eprintln!("Serving games on {}", listener.local_addr()?);
+            server::serve(listener, options.seed.unwrap_or_else(clock_seed))?;
+        }
+        Command::Validate => {
+            let issues = lint::lint_all();
+            output(options, &issues, || {
+                for issue in issues.iter() {
+                    println!("{}", issue);
+                }
+                println!("{} issue(s) found", issues.len());
+            });
+            return Ok(issues.is_empty());
+        }
+        Command::CampaignNew(file) => {
+            let path = Path::new(&file);
+            let name = path
+                .file_stem()
+                .map_or(file.clone(), |stem| stem.to_string_lossy().into_owned());
+            let campaign = Campaign::new(&name, options.difficulty.unwrap_or_default());
+            campaign.create(path)?;
+            output(options, &campaign, || println!("{}", campaign));
+        }
+        Command::CampaignShow(file) => {
+            let campaign = Campaign::load(Path::new(&file))?;
+            output(options, &campaign, || println!("{}", campaign));
+        }
+        Command::CampaignPerk(file, perk) => {
+            let path = Path::new(&file);
+            let mut campaign = Campaign::load(path)?;
+            campaign.choose_perk(perk)?;
+            campaign.save(path)?;
+            output(options, &campaign, || println!("{}", campaign));
+        }
+        Command::CampaignPlay(file) => campaign_play(Path::new(&file), options)?,
+        Command::History => {
+            let report = filtered_history(options)?.report();
+            output(options, &report, || println!("{}", report));
+        }
+        Command::HistoryRuns => {
+            let history = filtered_history(options)?;
+            output(options, &history.runs, || {
+                for run in history.runs.iter() {
+                    println!("{}", run);
+                }
+            });
+        }
+        Command::Help => println!("{}", USAGE),
+    }
+    Ok(true)
+}
+
+/// Prints `value` as JSON when asked to, otherwise falls back to `text`.
+fn output<T: Serialize>(options: &Options, value: &T, text: impl FnOnce()) {
+    if options.json {
+        match serde_json::to_string_pretty(value) {
+            Ok(json) => println!("{}", json),
+            Err(err) => eprintln!("could not write JSON: {}", err),
+        }
+    } else {
+        text();
+    }
+}
+
+fn play(options: &Options) -> Result<(), Error> {
+    let seed = options.seed.unwrap_or_else(clock_seed);
+    let app = if options.hero.is_none() && options.partner.is_none() && options.dungeon.is_none() {
+        App::new(seed).with_difficulty(options.difficulty.unwrap_or_default())
+    } else {
+        App::with_game(new_game(
+            options,
+            chosen_hero(options, players(options))?,
+            chosen_dungeon(options)?,
+            seed,
+        )?)
+    };
+    let packs: Vec<String> = chosen_packs(options, PlayerCount::Solo)?
+        .into_iter()
+        .map(|pack| pack.name)
+        .collect();
+    let app = tui::run(app.with_packs(&packs).with_history(history_path(options)))?;
+    if options.json {
+        let summary = app.game.as_ref().map(GameSummary::from_game);
+        output(options, &summary, || {});
+    }
+    Ok(())
+}
+
+/// Plays the campaign's next run in the terminal UI. Only a run played to the end
+/// is recorded; quitting part way leaves the campaign as it was.
+fn campaign_play(path: &Path, options: &Options) -> Result<(), Error> {
+    let mut campaign = Campaign::load(path)?;
+    let seed = options.seed.unwrap_or_else(clock_seed);
+    let game = campaign.start_run(
+        chosen_hero(options, PlayerCount::Solo)?,
+        chosen_dungeon(options)?,
+        seed,
+    )?;
+    let app = tui::run(App::with_game(game).with_history(history_path(options)))?;
+    match app.game {
+        Some(game) if game.is_over() && game.seed == seed => {
+            campaign.record_run(&game);
+            campaign.save(path)?;
+            output(options, &campaign, || println!("{}", campaign));
+        }
+        _ => eprintln!("The run was not finished, so the campaign is unchanged"),
+    }
+    Ok(())
+}
+
+/// Hands the game to a bot on the other end of stdin and stdout. The protocol is
+/// always JSON, so `--json` makes no difference here.
+fn bot(options: &Options) -> Result<(), Error> {
+    let seed = options.seed.unwrap_or_else(clock_seed);
+    let hero = chosen_hero(options, players(options))?;
+    let dungeon = chosen_dungeon(options)?;
+    let stdin = std::io::stdin();
+    for index in 0..options.games.unwrap_or(1) {
+        let mut game = new_game(
+            options,
+            hero.clone(),
+            dungeon.clone(),
+            seed.wrapping_add(index as u64),
+        )?;
+        protocol::play(&mut game, stdin.lock(), std::io::stdout().lock())?;
+    }
+    Ok(())
+}
+
+fn history_path(options: &Options) -> PathBuf {
+    options
+        .history
+        .as_ref()
+        .map_or_else(history::default_path, PathBuf::from)
+}
+
+/// The history narrowed down to the `--hero`, `--dungeon` and `--difficulty` given.
+fn filtered_history(options: &Options) -> Result<History, Error> {
+    let history = History::load(&history_path(options))?;
+    let hero = options
+        .hero
+        .as_deref()
+        .map(|name| game_setup::find_hero(name, PlayerCount::Solo))
+        .transpose()?;
+    let dungeon = options
+        .dungeon
+        .as_deref()
+        .map(game_setup::find_dungeon)
+        .transpose()?;
+    Ok(history.filter(|run| {
+        hero.as_ref().is_none_or(|hero| hero.name == run.hero)
+            && dungeon
+                .as_ref()
+                .is_none_or(|dungeon| dungeon.name == run.dungeon)
+            && options
+                .difficulty
+                .is_none_or(|difficulty| difficulty == run.summary.difficulty)
+    }))
+}
+
+/// Co-op when `--partner` is given, otherwise solo.
+fn players(options: &Options) -> PlayerCount {
+    match options.partner {
+        Some(_) => PlayerCount::Coop,
+        None => PlayerCount::Solo,
+    }
+}
+
+fn chosen_hero(options: &Options, players: PlayerCount) -> Result<Hero, DataError> {
+    match &options.hero {
+        Some(name) => hero_named(name, players),
+        None => Ok(Hero::get_warrior(players)),
+    }
+}
+
+/// A built-in hero, or a homebrew one when `name` is the path of a JSON file.
+fn hero_named(name: &str, players: PlayerCount) -> Result<Hero, DataError> {
+    if name.ends_with(".json") {
+        let hero = hero_builder::load_hero(Path::new(name))?;
+        Ok(match players {
+            PlayerCount::Solo => hero,
+            PlayerCount::Coop => hero_builder::coop_side(hero),
+        })
+    } else {
+        game_setup::find_hero(name, players)
+    }
+}
+
+/// A solo game, or a co-op one when `--partner` is given, dealt from the chosen
+/// packs.
+fn new_game(options: &Options, hero: Hero, dungeon: Dungeon, seed: u64) -> Result<Game, Error> {
+    let difficulty = options.difficulty.unwrap_or_default();
+    let deck = pack::deck(&chosen_packs(options, PlayerCount::Solo)?);
+    let game = match &options.partner {
+        Some(name) => {
+            let partner = hero_named(name, PlayerCount::Coop)?;
+            if partner.name == hero.name {
+                return Err(Error::Data(DataError::Invalid {
+                    source: String::from("--partner"),
+                    issues: vec![format!("{} is already the hero", hero.name)],
+                }));
+            }
+            Game::co_op(hero, partner, dungeon, seed, difficulty)
+        }
+        None => Game::with_difficulty(hero, dungeon, seed, difficulty),
+    };
+    Ok(game.with_deck(deck))
+}
+
+/// The packs named with `--packs`, or every pack.
+fn chosen_packs(options: &Options, players: PlayerCount) -> Result<Vec<ContentPack>, DataError> {
+    match &options.packs {
+        Some(names) => names
+            .iter()
+            .map(|name| pack::find_pack(name, players))
+            .collect(),
+        None => Ok(pack::get_all_packs(players)),
+    }
+}
+
+fn chosen_dungeon(options: &Options) -> Result<Dungeon, DataError> {
+    match &options.dungeon {
+        Some(name) => dungeon_named(name),
+        None => Ok(Dungeon::get_dragons_cave()),
+    }
+}
+
+/// A built-in dungeon, or a homebrew one when `name` is the path of a JSON file.
+fn dungeon_named(name: &str) -> Result<Dungeon, DataError> {
+    if name.ends_with(".json") {
+        dungeon_builder::load_dungeon(Path::new(name))
+    } else {
+        game_setup::find_dungeon(name)
+    }
+}
+
+/// Lists the cards in the packs picked with `--packs`, or in every pack.
+fn list(kind: CardKind, options: &Options) -> Result<(), Error> {
+    let packs = chosen_packs(options, PlayerCount::Solo)?;
+    match kind {
+        CardKind::Heroes => {
+            let heroes: Vec<Hero> = packs.iter().flat_map(|pack| pack.heroes.clone()).collect();
+            output(options, &heroes, || {
+                heroes.iter().for_each(|hero| println!("{}", hero.name))
+            });
+        }
+        CardKind::Dungeons => {
+            let dungeons: Vec<Dungeon> = packs
+                .iter()
+                .flat_map(|pack| pack.dungeons.clone())
+                .collect();
+            output(options, &dungeons, || {
+                for dungeon in dungeons.iter() {
+                    println!("{} (difficulty {})", dungeon.name, dungeon.difficulty);
+                }
+            });
+        }
+        CardKind::Perils => {
+            let perils: Vec<Peril> = packs.iter().flat_map(|pack| pack.perils.clone()).collect();
+            output(options, &perils, || {
+                perils.iter().for_each(|peril| println!("{}", peril.name))
+            });
+        }
+        CardKind::Combats => {
+            let combats: Vec<Combat> = packs.iter().flat_map(|pack| pack.combats.clone()).collect();
+            output(options, &combats, || {
+                for combat in combats.iter() {
+                    println!("{} ({})", combat.name, combat.special_ability);
+                }
+            });
+        }
+        CardKind::Skills => {
+            let skills = game_setup::get_all_skills();
+            output(options, &skills, || {
+                for skill in skills.iter() {
+                    println!("{}: {}", skill.name, skill.effect);
+                }
+            });
+        }
+        CardKind::Packs => {
+            output(options, &packs, || {
+                for pack in packs.iter() {
+                    println!(
+                        "{}: {} heroes, {} dungeons, {} perils, {} combats, {} bosses",
+                        pack.name,
+                        pack.heroes.len(),
+                        pack.dungeons.len(),
+                        pack.perils.len(),
+                        pack.combats.len(),
+                        pack.bosses.len()
+                    );
+                }
+            });
+        }
+    }
+    Ok(())
+}
+
+fn simulate(options: &Options) -> Result<(), Error> {
+    let heroes = match &options.hero {
+        Some(name) => vec![hero_named(name, PlayerCount::Solo)?],
+        None => game_setup::get_all_heroes(PlayerCount::Solo),
+    };
+    let dungeons = match &options.dungeon {
+        Some(name) => vec![dungeon_named(name)?],
+        None => game_setup::get_all_dungeons(),
+    };
+    let defaults = SimulationConfig::default();
+    let config = SimulationConfig {
+        games: options.games.unwrap_or(defaults.games),
+        seed: options.seed.unwrap_or(defaults.seed),
+        difficulty: options.difficulty.unwrap_or(defaults.difficulty),
+        ..defaults
+    };
+    let report: SimulationReport = match options.policy.as_deref().unwrap_or("greedy") {
+        "first-fit" => simulator::simulate(&heroes, &dungeons, |_| FirstFitPolicy, &config),
+        "greedy" => simulator::simulate(&heroes, &dungeons, GreedyPolicy::new, &config),
+        "solver" => simulator::simulate(&heroes, &dungeons, SolverPolicy::new, &config),
+        "mcts" => {
+            let iterations = options.iterations.unwrap_or(mcts::DEFAULT_ITERATIONS);
+            let policy = |seed| MctsPolicy::new(seed).with_iterations(iterations);
+            simulator::simulate(&heroes, &dungeons, policy, &config)
+        }
+        policy => {
+            return Err(Error::Data(DataError::NotFound {
+                kind: "policy",
+                name: policy.to_string(),
+            }))
+        }
+    };
+    output(options, &report, || print!("{}", report));
+    Ok(())
+}
+
+/// Prints a new dungeon and its floor ratings; the `dungeon` part of its JSON can
+/// be saved and played with `--dungeon`.
+fn generate_dungeon(options: &Options) -> Result<(), Error> {
+    let seed = options.seed.unwrap_or_else(clock_seed);
+    let mut generator = DungeonGenerator::new(seed);
+    if let Some(band) = options.band {
+        generator = generator.with_bands(band);
+    }
+    let generated = generator.generate()?;
+    output(options, &generated, || {
+        print_dungeon(&generated.dungeon);
+        println!("Seed: {}", seed);
+        for rating in generated.ratings.iter() {
+            println!(
+                "Floor {} {}: {:.2}",
+                rating.floor, rating.encounter, rating.rating
+            );
+        }
+    });
+    Ok(())
+}
+
+/// Prints a new combat, or a new peril, with the rating it was given.
+fn generate_card(options: &Options, combat: bool) -> Result<(), Error> {
+    let seed = options.seed.unwrap_or_else(clock_seed);
+    let mut generator = CardGenerator::new(seed);
+    if let Some(band) = options.band {
+        generator = generator.with_combat_band(band).with_peril_band(band);
+    }
+    let generated = if combat {
+        generator.generate_combat()?
+    } else {
+        generator.generate_peril()?
+    };
+    output(options, &generated, || {
+        match &generated.card {
+            Card::Combat(combat) => print_combat(combat),
+            Card::Peril(peril) => print_peril(peril),
+        }
+        println!("Seed: {}", seed);
+        println!("Rating: {:.2}", generated.rating);
+    });
+    Ok(())
+}
+
+fn clock_seed() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_secs())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use one_deck_dungeon::hero::DiceColour;
+
+    fn parse_line(line: &str) -> Result<(Command, Options), CliError> {
+        parse(line.split_whitespace().map(String::from))
+    }
+
+    fn command(line: &str) -> Command {
+        parse_line(line).unwrap().0
+    }
+
+    fn usage(line: &str) -> String {
+        match parse_line(line) {
+            Err(CliError::Usage(message)) => message,
+            Ok(parsed) => panic!("{} parsed as {:?}", line, parsed),
+        }
+    }
+
+    #[test]
+    fn parses_every_command() {
+        let commands = [
+            ("", Command::Play),
+            ("play", Command::Play),
+            ("help", Command::Help),
+            ("--help", Command::Help),
+            ("show hero Mage", Command::ShowHero(String::from("Mage"))),
+            (
+                "show dungeon Dragon's Cave",
+                Command::ShowDungeon(String::from("Dragon's Cave")),
+            ),
+            ("list heroes", Command::List(CardKind::Heroes)),
+            ("list dungeons", Command::List(CardKind::Dungeons)),
+            ("list perils", Command::List(CardKind::Perils)),
+            ("list combats", Command::List(CardKind::Combats)),
+            ("list skills", Command::List(CardKind::Skills)),
+            ("list packs", Command::List(CardKind::Packs)),
+            ("simulate", Command::Simulate),
+            ("generate dungeon", Command::GenerateDungeon),
+            ("generate combat", Command::GenerateCombat),
+            ("generate peril", Command::GeneratePeril),
+            ("bot", Command::Bot),
+            ("serve", Command::Serve),
+            ("validate", Command::Validate),
+            (
+                "campaign new run.json",
+                Command::CampaignNew(String::from("run.json")),
+            ),
+            (
+                "campaign show run.json",
+                Command::CampaignShow(String::from("run.json")),
+            ),
+            (
+                "campaign play run.json",
+                Command::CampaignPlay(String::from("run.json")),
+            ),
+            (
+                "campaign perk run.json magic",
+                Command::CampaignPerk(String::from("run.json"), Perk::BonusDice(DiceColour::Magic)),
+            ),
+            ("history", Command::History),
+            ("history runs", Command::HistoryRuns),
+        ];
+        for (line, expected) in commands {
+            assert_eq!(command(line), expected, "{}", line);
+        }
+    }
+
+    #[test]
+    fn parses_options() {
+        let (command, options) = parse_line(
+            "simulate --seed 42 --json --games 3 --hero Mage --partner Rogue --difficulty heroic \
+             --packs core,promo --band 0.5-2 --policy greedy",
+        )
+        .unwrap();
+        assert_eq!(command, Command::Simulate);
+        assert_eq!(options.seed, Some(42));
+        assert!(options.json);
+        assert_eq!(options.games, Some(3));
+        assert_eq!(options.hero.as_deref(), Some("Mage"));
+        assert_eq!(options.partner.as_deref(), Some("Rogue"));
+        assert_eq!(options.difficulty, Some(Difficulty::Heroic));
+        assert_eq!(
+            options.packs,
+            Some(vec![String::from("core"), String::from("promo")])
+        );
+        assert_eq!(options.band, Some(DifficultyBand::new(0.5, 2.0)));
+        assert_eq!(options.policy.as_deref(), Some("greedy"));
+
+        let (_, options) = parse_line("play").unwrap();
+        assert_eq!(options, Options::default());
+    }
+
+    #[test]
+    fn rejects_bad_arguments() {
+        assert_eq!(usage("play --seed"), "--seed needs a value");
+        assert_eq!(usage("play --hero"), "--hero needs a value");
+        assert_eq!(
+            usage("play --seed soon"),
+            "--seed expects a number, not soon"
+        );
+        assert_eq!(usage("play --difficulty easy"), "unknown difficulty easy");
+        assert_eq!(usage("play --colour red"), "unknown option --colour");
+        assert_eq!(usage("dance"), "unknown command dance");
+        assert_eq!(usage("list monsters"), "cannot list monsters");
+        assert_eq!(usage("campaign perk run.json wings"), "unknown perk wings");
+    }
+}
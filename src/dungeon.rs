@@ -1,15 +1,38 @@
 use std::{collections::HashMap, fmt::Display};
 
-use crate::hero::{Attribute, AttributeType};
+use serde::{Deserialize, Serialize};
 
+use crate::{
+    boss::Boss,
+    encounter::Encounter,
+    hero::{Attribute, DiceColour, Resource},
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ChallengeBox {
-    pub dice_type: Option<AttributeType>,
+    pub dice_type: Option<DiceColour>,
     pub total_value: i8,
     pub single_dice: bool,
     pub priority: bool,
     pub consequences: Vec<Attribute>,
 }
 
+impl ChallengeBox {
+    /// The health and time lost if this box is left unfilled.
+    pub fn costs(&self) -> (usize, usize) {
+        let mut health = 0;
+        let mut time = 0;
+        for consequence in self.consequences.iter() {
+            match consequence {
+                Attribute::Resource(Resource::Health, quantity) => health += quantity,
+                Attribute::Resource(Resource::Time, quantity) => time += quantity,
+                _ => {}
+            }
+        }
+        (health, time)
+    }
+}
+
 impl Display for ChallengeBox {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let mut output = String::new();
@@ -43,14 +66,25 @@ impl Display for ChallengeBox {
     }
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Dungeon {
     pub name: String,
     pub difficulty: usize,
     pub peril_challenges: HashMap<i8, Vec<ChallengeBox>>,
     pub combat_challenges: HashMap<i8, Vec<ChallengeBox>>,
+    pub boss: Boss,
 }
 
 impl Dungeon {
+    /// The boxes this dungeon adds to every peril or combat on `floor`.
+    pub fn floor_challenges(&self, floor: i8, encounter: Encounter) -> Vec<ChallengeBox> {
+        let challenges = match encounter {
+            Encounter::Peril => &self.peril_challenges,
+            _ => &self.combat_challenges,
+        };
+        challenges.get(&floor).cloned().unwrap_or_default()
+    }
+
     pub fn get_phoenix_den() -> Dungeon {
         let mut peril_challenges = HashMap::new();
         peril_challenges.insert(
@@ -71,16 +105,8 @@ impl Dungeon {
                 single_dice: true,
                 priority: false,
                 consequences: vec![
-                    Attribute {
-                        attribute: AttributeType::Health,
-                        quantity: Some(1),
-                        ..Default::default()
-                    },
-                    Attribute {
-                        attribute: AttributeType::Time,
-                        quantity: Some(1),
-                        ..Default::default()
-                    },
+                    Attribute::Resource(Resource::Health, 1),
+                    Attribute::Resource(Resource::Time, 1),
                 ],
             }],
         );
@@ -91,11 +117,7 @@ impl Dungeon {
                 total_value: 6,
                 single_dice: true,
                 priority: false,
-                consequences: vec![Attribute {
-                    attribute: AttributeType::Time,
-                    quantity: Some(2),
-                    ..Default::default()
-                }],
+                consequences: vec![Attribute::Resource(Resource::Time, 2)],
             }],
         );
         let mut combat_challenges = HashMap::new();
@@ -103,33 +125,25 @@ impl Dungeon {
             1,
             vec![
                 ChallengeBox {
-                    dice_type: Some(AttributeType::Magic),
+                    dice_type: Some(DiceColour::Magic),
                     total_value: 3,
                     single_dice: true,
                     priority: false,
-                    consequences: vec![Attribute {
-                        attribute: AttributeType::Health,
-                        quantity: Some(1),
-                        ..Default::default()
-                    }],
+                    consequences: vec![Attribute::Resource(Resource::Health, 1)],
                 },
                 ChallengeBox {
-                    dice_type: Some(AttributeType::Strength),
+                    dice_type: Some(DiceColour::Strength),
                     total_value: 3,
                     single_dice: true,
                     priority: false,
-                    consequences: vec![Attribute {
-                        attribute: AttributeType::Time,
-                        quantity: Some(2),
-                        ..Default::default()
-                    }],
+                    consequences: vec![Attribute::Resource(Resource::Time, 2)],
                 },
             ],
         );
         combat_challenges.insert(
             2,
             vec![ChallengeBox {
-                dice_type: Some(AttributeType::Agility),
+                dice_type: Some(DiceColour::Agility),
                 total_value: 3,
                 single_dice: true,
                 priority: true,
@@ -140,26 +154,18 @@ impl Dungeon {
             3,
             vec![
                 ChallengeBox {
-                    dice_type: Some(AttributeType::Strength),
+                    dice_type: Some(DiceColour::Strength),
                     total_value: 4,
                     single_dice: true,
                     priority: false,
-                    consequences: vec![Attribute {
-                        attribute: AttributeType::Health,
-                        quantity: Some(2),
-                        ..Default::default()
-                    }],
+                    consequences: vec![Attribute::Resource(Resource::Health, 2)],
                 },
                 ChallengeBox {
-                    dice_type: Some(AttributeType::Strength),
+                    dice_type: Some(DiceColour::Strength),
                     total_value: 4,
                     single_dice: true,
                     priority: false,
-                    consequences: vec![Attribute {
-                        attribute: AttributeType::Time,
-                        quantity: Some(2),
-                        ..Default::default()
-                    }],
+                    consequences: vec![Attribute::Resource(Resource::Time, 2)],
                 },
             ],
         );
@@ -168,6 +174,7 @@ impl Dungeon {
             difficulty: 2,
             peril_challenges,
             combat_challenges,
+            boss: Boss::get_phoenix(),
         }
     }
 
@@ -190,11 +197,7 @@ impl Dungeon {
                 total_value: 6,
                 single_dice: true,
                 priority: false,
-                consequences: vec![Attribute {
-                    attribute: AttributeType::Health,
-                    quantity: Some(1),
-                    ..Default::default()
-                }],
+                consequences: vec![Attribute::Resource(Resource::Health, 1)],
             }],
         );
         peril_challenges.insert(
@@ -211,35 +214,27 @@ impl Dungeon {
         combat_challenges.insert(
             1,
             vec![ChallengeBox {
-                dice_type: Some(AttributeType::Strength),
+                dice_type: Some(DiceColour::Strength),
                 total_value: 3,
                 single_dice: true,
                 priority: false,
-                consequences: vec![Attribute {
-                    attribute: AttributeType::Health,
-                    quantity: Some(1),
-                    ..Default::default()
-                }],
+                consequences: vec![Attribute::Resource(Resource::Health, 1)],
             }],
         );
         combat_challenges.insert(
             2,
             vec![ChallengeBox {
-                dice_type: Some(AttributeType::Magic),
+                dice_type: Some(DiceColour::Magic),
                 total_value: 5,
                 single_dice: true,
                 priority: false,
-                consequences: vec![Attribute {
-                    attribute: AttributeType::Health,
-                    quantity: Some(2),
-                    ..Default::default()
-                }],
+                consequences: vec![Attribute::Resource(Resource::Health, 2)],
             }],
         );
         combat_challenges.insert(
             3,
             vec![ChallengeBox {
-                dice_type: Some(AttributeType::Strength),
+                dice_type: Some(DiceColour::Strength),
                 total_value: 10,
                 single_dice: false,
                 priority: true,
@@ -252,6 +247,7 @@ impl Dungeon {
             difficulty: 1,
             peril_challenges,
             combat_challenges,
+            boss: Boss::get_dragon(),
         }
     }
 
@@ -265,16 +261,8 @@ impl Dungeon {
                 single_dice: true,
                 priority: false,
                 consequences: vec![
-                    Attribute {
-                        attribute: AttributeType::Health,
-                        quantity: Some(1),
-                        ..Default::default()
-                    },
-                    Attribute {
-                        attribute: AttributeType::Time,
-                        quantity: Some(1),
-                        ..Default::default()
-                    },
+                    Attribute::Resource(Resource::Health, 1),
+                    Attribute::Resource(Resource::Time, 1),
                 ],
             }],
         );
@@ -302,47 +290,31 @@ impl Dungeon {
         combat_challenges.insert(
             1,
             vec![ChallengeBox {
-                dice_type: Some(AttributeType::Agility),
+                dice_type: Some(DiceColour::Agility),
                 total_value: 3,
                 single_dice: true,
                 priority: false,
-                consequences: vec![Attribute {
-                    attribute: AttributeType::Health,
-                    quantity: Some(1),
-                    ..Default::default()
-                }],
+                consequences: vec![Attribute::Resource(Resource::Health, 1)],
             }],
         );
         combat_challenges.insert(
             2,
             vec![
                 ChallengeBox {
-                    dice_type: Some(AttributeType::Agility),
+                    dice_type: Some(DiceColour::Agility),
                     total_value: 5,
                     single_dice: true,
                     priority: false,
-                    consequences: vec![Attribute {
-                        attribute: AttributeType::Health,
-                        quantity: Some(1),
-                        ..Default::default()
-                    }],
+                    consequences: vec![Attribute::Resource(Resource::Health, 1)],
                 },
                 ChallengeBox {
-                    dice_type: Some(AttributeType::Strength),
+                    dice_type: Some(DiceColour::Strength),
                     total_value: 5,
                     single_dice: true,
                     priority: false,
                     consequences: vec![
-                        Attribute {
-                            attribute: AttributeType::Health,
-                            quantity: Some(1),
-                            ..Default::default()
-                        },
-                        Attribute {
-                            attribute: AttributeType::Time,
-                            quantity: Some(1),
-                            ..Default::default()
-                        },
+                        Attribute::Resource(Resource::Health, 1),
+                        Attribute::Resource(Resource::Time, 1),
                     ],
                 },
             ],
@@ -350,21 +322,13 @@ impl Dungeon {
         combat_challenges.insert(
             3,
             vec![ChallengeBox {
-                dice_type: Some(AttributeType::Magic),
+                dice_type: Some(DiceColour::Magic),
                 total_value: 5,
                 single_dice: true,
                 priority: false,
                 consequences: vec![
-                    Attribute {
-                        attribute: AttributeType::Health,
-                        quantity: Some(1),
-                        ..Default::default()
-                    },
-                    Attribute {
-                        attribute: AttributeType::Time,
-                        quantity: Some(1),
-                        ..Default::default()
-                    },
+                    Attribute::Resource(Resource::Health, 1),
+                    Attribute::Resource(Resource::Time, 1),
                 ],
             }],
         );
@@ -374,6 +338,7 @@ impl Dungeon {
             difficulty: 2,
             peril_challenges,
             combat_challenges,
+            boss: Boss::get_hydra(),
         }
     }
 
@@ -386,11 +351,7 @@ impl Dungeon {
                 total_value: 4,
                 single_dice: true,
                 priority: true,
-                consequences: vec![Attribute {
-                    attribute: AttributeType::Time,
-                    quantity: Some(1),
-                    ..Default::default()
-                }],
+                consequences: vec![Attribute::Resource(Resource::Time, 1)],
             }],
         );
         peril_challenges.insert(
@@ -401,16 +362,8 @@ impl Dungeon {
                 single_dice: true,
                 priority: false,
                 consequences: vec![
-                    Attribute {
-                        attribute: AttributeType::Health,
-                        quantity: Some(1),
-                        ..Default::default()
-                    },
-                    Attribute {
-                        attribute: AttributeType::Time,
-                        quantity: Some(1),
-                        ..Default::default()
-                    },
+                    Attribute::Resource(Resource::Health, 1),
+                    Attribute::Resource(Resource::Time, 1),
                 ],
             }],
         );
@@ -421,11 +374,7 @@ impl Dungeon {
                 total_value: 4,
                 single_dice: true,
                 priority: true,
-                consequences: vec![Attribute {
-                    attribute: AttributeType::Health,
-                    quantity: Some(1),
-                    ..Default::default()
-                }],
+                consequences: vec![Attribute::Resource(Resource::Health, 1)],
             }],
         );
         let mut combat_challenges = HashMap::new();
@@ -433,67 +382,47 @@ impl Dungeon {
             1,
             vec![
                 ChallengeBox {
-                    dice_type: Some(AttributeType::Magic),
+                    dice_type: Some(DiceColour::Magic),
                     total_value: 3,
                     single_dice: true,
                     priority: false,
-                    consequences: vec![Attribute {
-                        attribute: AttributeType::Time,
-                        quantity: Some(1),
-                        ..Default::default()
-                    }],
+                    consequences: vec![Attribute::Resource(Resource::Time, 1)],
                 },
                 ChallengeBox {
-                    dice_type: Some(AttributeType::Magic),
+                    dice_type: Some(DiceColour::Magic),
                     total_value: 4,
                     single_dice: true,
                     priority: false,
-                    consequences: vec![Attribute {
-                        attribute: AttributeType::Time,
-                        quantity: Some(1),
-                        ..Default::default()
-                    }],
+                    consequences: vec![Attribute::Resource(Resource::Time, 1)],
                 },
             ],
         );
         combat_challenges.insert(
             2,
             vec![ChallengeBox {
-                dice_type: Some(AttributeType::Strength),
+                dice_type: Some(DiceColour::Strength),
                 total_value: 5,
                 single_dice: true,
                 priority: false,
-                consequences: vec![Attribute {
-                    attribute: AttributeType::Health,
-                    quantity: Some(2),
-                    ..Default::default()
-                }],
+                consequences: vec![Attribute::Resource(Resource::Health, 2)],
             }],
         );
         combat_challenges.insert(
             3,
             vec![
                 ChallengeBox {
-                    dice_type: Some(AttributeType::Strength),
+                    dice_type: Some(DiceColour::Strength),
                     total_value: 3,
                     single_dice: true,
                     priority: true,
-                    consequences: vec![Attribute {
-                        attribute: AttributeType::Health,
-                        quantity: Some(1),
-                        ..Default::default()
-                    }],
+                    consequences: vec![Attribute::Resource(Resource::Health, 1)],
                 },
                 ChallengeBox {
-                    dice_type: Some(AttributeType::Strength),
+                    dice_type: Some(DiceColour::Strength),
                     total_value: 3,
                     single_dice: true,
                     priority: true,
-                    consequences: vec![Attribute {
-                        attribute: AttributeType::Health,
-                        quantity: Some(1),
-                        ..Default::default()
-                    }],
+                    consequences: vec![Attribute::Resource(Resource::Health, 1)],
                 },
             ],
         );
@@ -503,6 +432,7 @@ impl Dungeon {
             difficulty: 2,
             peril_challenges,
             combat_challenges,
+            boss: Boss::get_yeti(),
         }
     }
 
@@ -526,16 +456,8 @@ impl Dungeon {
                 single_dice: true,
                 priority: false,
                 consequences: vec![
-                    Attribute {
-                        attribute: AttributeType::Health,
-                        quantity: Some(1),
-                        ..Default::default()
-                    },
-                    Attribute {
-                        attribute: AttributeType::Time,
-                        quantity: Some(1),
-                        ..Default::default()
-                    },
+                    Attribute::Resource(Resource::Health, 1),
+                    Attribute::Resource(Resource::Time, 1),
                 ],
             }],
         );
@@ -546,11 +468,7 @@ impl Dungeon {
                 total_value: 4,
                 single_dice: true,
                 priority: true,
-                consequences: vec![Attribute {
-                    attribute: AttributeType::Health,
-                    quantity: Some(1),
-                    ..Default::default()
-                }],
+                consequences: vec![Attribute::Resource(Resource::Health, 1)],
             }],
         );
         let mut combat_challenges = HashMap::new();
@@ -558,29 +476,25 @@ impl Dungeon {
             1,
             vec![
                 ChallengeBox {
-                    dice_type: Some(AttributeType::Magic),
+                    dice_type: Some(DiceColour::Magic),
                     total_value: 2,
                     single_dice: true,
                     priority: true,
                     consequences: Vec::new(),
                 },
                 ChallengeBox {
-                    dice_type: Some(AttributeType::Strength),
+                    dice_type: Some(DiceColour::Strength),
                     total_value: 5,
                     single_dice: true,
                     priority: false,
-                    consequences: vec![Attribute {
-                        attribute: AttributeType::Time,
-                        quantity: Some(2),
-                        ..Default::default()
-                    }],
+                    consequences: vec![Attribute::Resource(Resource::Time, 2)],
                 },
             ],
         );
         combat_challenges.insert(
             2,
             vec![ChallengeBox {
-                dice_type: Some(AttributeType::Magic),
+                dice_type: Some(DiceColour::Magic),
                 total_value: 3,
                 single_dice: true,
                 priority: true,
@@ -590,15 +504,11 @@ impl Dungeon {
         combat_challenges.insert(
             3,
             vec![ChallengeBox {
-                dice_type: Some(AttributeType::Magic),
+                dice_type: Some(DiceColour::Magic),
                 total_value: 10,
                 single_dice: false,
                 priority: false,
-                consequences: vec![Attribute {
-                    attribute: AttributeType::Health,
-                    quantity: Some(2),
-                    ..Default::default()
-                }],
+                consequences: vec![Attribute::Resource(Resource::Health, 2)],
             }],
         );
 
@@ -607,6 +517,7 @@ impl Dungeon {
             difficulty: 3,
             peril_challenges,
             combat_challenges,
+            boss: Boss::get_lich(),
         }
     }
 
@@ -619,11 +530,7 @@ impl Dungeon {
                 total_value: 5,
                 single_dice: true,
                 priority: true,
-                consequences: vec![Attribute {
-                    attribute: AttributeType::Health,
-                    quantity: Some(1),
-                    ..Default::default()
-                }],
+                consequences: vec![Attribute::Resource(Resource::Health, 1)],
             }],
         );
         peril_challenges.insert(
@@ -644,16 +551,8 @@ impl Dungeon {
                 single_dice: true,
                 priority: false,
                 consequences: vec![
-                    Attribute {
-                        attribute: AttributeType::Health,
-                        quantity: Some(1),
-                        ..Default::default()
-                    },
-                    Attribute {
-                        attribute: AttributeType::Time,
-                        quantity: Some(1),
-                        ..Default::default()
-                    },
+                    Attribute::Resource(Resource::Health, 1),
+                    Attribute::Resource(Resource::Time, 1),
                 ],
             }],
         );
@@ -662,26 +561,18 @@ impl Dungeon {
             1,
             vec![
                 ChallengeBox {
-                    dice_type: Some(AttributeType::Agility),
+                    dice_type: Some(DiceColour::Agility),
                     total_value: 2,
                     single_dice: true,
                     priority: false,
-                    consequences: vec![Attribute {
-                        attribute: AttributeType::Health,
-                        quantity: Some(1),
-                        ..Default::default()
-                    }],
+                    consequences: vec![Attribute::Resource(Resource::Health, 1)],
                 },
                 ChallengeBox {
-                    dice_type: Some(AttributeType::Agility),
+                    dice_type: Some(DiceColour::Agility),
                     total_value: 2,
                     single_dice: true,
                     priority: false,
-                    consequences: vec![Attribute {
-                        attribute: AttributeType::Health,
-                        quantity: Some(1),
-                        ..Default::default()
-                    }],
+                    consequences: vec![Attribute::Resource(Resource::Health, 1)],
                 },
             ],
         );
@@ -689,14 +580,14 @@ impl Dungeon {
             2,
             vec![
                 ChallengeBox {
-                    dice_type: Some(AttributeType::Strength),
+                    dice_type: Some(DiceColour::Strength),
                     total_value: 3,
                     single_dice: true,
                     priority: true,
                     consequences: Vec::new(),
                 },
                 ChallengeBox {
-                    dice_type: Some(AttributeType::Strength),
+                    dice_type: Some(DiceColour::Strength),
                     total_value: 3,
                     single_dice: true,
                     priority: true,
@@ -707,15 +598,11 @@ impl Dungeon {
         combat_challenges.insert(
             3,
             vec![ChallengeBox {
-                dice_type: Some(AttributeType::Agility),
+                dice_type: Some(DiceColour::Agility),
                 total_value: 12,
                 single_dice: false,
                 priority: false,
-                consequences: vec![Attribute {
-                    attribute: AttributeType::Health,
-                    quantity: Some(3),
-                    ..Default::default()
-                }],
+                consequences: vec![Attribute::Resource(Resource::Health, 3)],
             }],
         );
 
@@ -724,6 +611,7 @@ impl Dungeon {
             difficulty: 3,
             peril_challenges,
             combat_challenges,
+            boss: Boss::get_minotaur(),
         }
     }
 }
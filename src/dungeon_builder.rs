@@ -0,0 +1,219 @@
+use std::{collections::HashMap, fs, io, path::Path};
+
+use crate::{
+    boss::Boss,
+    dungeon::{ChallengeBox, Dungeon},
+    encounter::Encounter,
+    error::DataError,
+    game_setup,
+    lint::{self, LintIssue},
+};
+
+/// Assembles a homebrew dungeon floor by floor. Every dungeon needs peril and
+/// combat boxes for floors 1 to 3 and a boss; `build` refuses to hand over a
+/// dungeon that [`DungeonBuilder::validate`] has anything to say about.
+#[derive(Debug, Clone)]
+pub struct DungeonBuilder {
+    name: String,
+    difficulty: usize,
+    peril_challenges: HashMap<i8, Vec<ChallengeBox>>,
+    combat_challenges: HashMap<i8, Vec<ChallengeBox>>,
+    boss: Option<Boss>,
+}
+
+impl DungeonBuilder {
+    pub fn new(name: &str) -> DungeonBuilder {
+        DungeonBuilder {
+            name: name.to_string(),
+            difficulty: 1,
+            peril_challenges: HashMap::new(),
+            combat_challenges: HashMap::new(),
+            boss: None,
+        }
+    }
+
+    pub fn from_dungeon(dungeon: Dungeon) -> DungeonBuilder {
+        DungeonBuilder {
+            name: dungeon.name,
+            difficulty: dungeon.difficulty,
+            peril_challenges: dungeon.peril_challenges,
+            combat_challenges: dungeon.combat_challenges,
+            boss: Some(dungeon.boss),
+        }
+    }
+
+    pub fn with_name(mut self, name: &str) -> DungeonBuilder {
+        self.name = name.to_string();
+        self
+    }
+
+    pub fn with_difficulty(mut self, difficulty: usize) -> DungeonBuilder {
+        self.difficulty = difficulty;
+        self
+    }
+
+    /// Replaces the boxes added to every peril, or every combat, on `floor`.
+    /// Bosses have their own boxes, so asking for them here changes nothing.
+    pub fn with_floor(
+        mut self,
+        floor: i8,
+        encounter: Encounter,
+        boxes: Vec<ChallengeBox>,
+    ) -> DungeonBuilder {
+        match encounter {
+            Encounter::Peril => {
+                self.peril_challenges.insert(floor, boxes);
+            }
+            Encounter::Combat => {
+                self.combat_challenges.insert(floor, boxes);
+            }
+            Encounter::Boss => {}
+        }
+        self
+    }
+
+    pub fn with_boss(mut self, boss: Boss) -> DungeonBuilder {
+        self.boss = Some(boss);
+        self
+    }
+
+    /// Links the dungeon to a built-in boss.
+    pub fn with_boss_named(self, name: &str) -> Result<DungeonBuilder, DataError> {
+        Ok(self.with_boss(game_setup::find_boss(name)?))
+    }
+
+    pub fn validate(&self) -> Vec<LintIssue> {
+        let mut issues = Vec::new();
+        if self.name.trim().is_empty() {
+            issues.push(LintIssue {
+                card: self.name.clone(),
+                message: String::from("has no name"),
+            });
+        }
+        issues.extend(lint::lint_floors(
+            &self.name,
+            &self.peril_challenges,
+            &self.combat_challenges,
+        ));
+        match self.boss.as_ref() {
+            Some(boss) => issues.extend(lint::lint_boss(boss)),
+            None => issues.push(LintIssue {
+                card: self.name.clone(),
+                message: String::from("has no boss"),
+            }),
+        }
+        issues
+    }
+
+    pub fn build(self) -> Result<Dungeon, DataError> {
+        let issues = self.validate();
+        match self.boss {
+            Some(boss) if issues.is_empty() => Ok(Dungeon {
+                name: self.name,
+                difficulty: self.difficulty,
+                peril_challenges: self.peril_challenges,
+                combat_challenges: self.combat_challenges,
+                boss,
+            }),
+            _ => Err(DataError::Invalid {
+                source: self.name,
+                issues: issues.into_iter().map(|issue| issue.message).collect(),
+            }),
+        }
+    }
+}
+
+/// Reads a dungeon saved as JSON, shaped like `show dungeon --json` prints it,
+/// and checks it the same way [`DungeonBuilder::build`] does.
+pub fn load_dungeon(path: &Path) -> Result<Dungeon, DataError> {
+    let json = fs::read_to_string(path)?;
+    let dungeon = serde_json::from_str(&json).map_err(|err| DataError::Parse {
+        source: path.display().to_string(),
+        message: err.to_string(),
+    })?;
+    DungeonBuilder::from_dungeon(dungeon).build()
+}
+
+pub fn save_dungeon(dungeon: &Dungeon, path: &Path) -> Result<(), DataError> {
+    let json = serde_json::to_string_pretty(dungeon).map_err(io::Error::from)?;
+    fs::write(path, json)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A builder holding every floor of Dragon's Cave but combat floor 2.
+    fn without_combat_floor_two() -> DungeonBuilder {
+        let cave = Dungeon::get_dragons_cave();
+        let mut builder = DungeonBuilder::new("Half Cave").with_boss(cave.boss.clone());
+        for floor in 1..=3 {
+            builder = builder.with_floor(
+                floor,
+                Encounter::Peril,
+                cave.peril_challenges[&floor].clone(),
+            );
+            if floor != 2 {
+                builder = builder.with_floor(
+                    floor,
+                    Encounter::Combat,
+                    cave.combat_challenges[&floor].clone(),
+                );
+            }
+        }
+        builder
+    }
+
+    fn issues(result: Result<Dungeon, DataError>) -> Vec<String> {
+        match result {
+            Err(DataError::Invalid { issues, .. }) => issues,
+            other => panic!(
+                "expected invalid, got {:?}",
+                other.map(|dungeon| dungeon.name)
+            ),
+        }
+    }
+
+    #[test]
+    fn refuses_a_missing_floor() {
+        assert_eq!(
+            issues(without_combat_floor_two().build()),
+            ["has no combat boxes for floor 2"]
+        );
+    }
+
+    #[test]
+    fn refuses_a_missing_boss() {
+        let mut builder = without_combat_floor_two().with_floor(
+            2,
+            Encounter::Combat,
+            Dungeon::get_dragons_cave().combat_challenges[&2].clone(),
+        );
+        assert!(builder.clone().build().is_ok());
+        builder.boss = None;
+        assert_eq!(issues(builder.build()), ["has no boss"]);
+    }
+
+    #[test]
+    fn rebuilds_every_built_in_dungeon() {
+        for dungeon in game_setup::get_all_dungeons() {
+            let expected = serde_json::to_value(&dungeon).unwrap();
+            let rebuilt = DungeonBuilder::from_dungeon(dungeon).build().unwrap();
+            assert_eq!(serde_json::to_value(&rebuilt).unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn saved_dungeons_load_back() {
+        let path = std::env::temp_dir().join(format!("odd-dungeon-{}.json", std::process::id()));
+        let dungeon = Dungeon::get_dragons_cave();
+        save_dungeon(&dungeon, &path).unwrap();
+        let loaded = load_dungeon(&path);
+        fs::remove_file(&path).unwrap();
+        assert_eq!(
+            serde_json::to_value(loaded.unwrap()).unwrap(),
+            serde_json::to_value(&dungeon).unwrap()
+        );
+    }
+}
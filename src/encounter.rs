@@ -1,5 +1,6 @@
 use std::fmt::Display;
 
+use serde::{Deserialize, Serialize};
 use strum_macros::EnumIter;
 
 use crate::{
@@ -7,7 +8,7 @@ use crate::{
     hero::{Attribute, Effect, Skill},
 };
 
-#[derive(Debug, PartialEq, EnumIter)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EnumIter, Serialize, Deserialize)]
 pub enum Encounter {
     Combat,
     Peril,
@@ -20,6 +21,7 @@ impl Display for Encounter {
     }
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Peril {
     pub name: String,
     pub choice_one: Vec<ChallengeBox>,
@@ -33,6 +35,7 @@ pub struct Peril {
 
 impl Peril {}
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Combat {
     pub name: String,
     pub special_ability: Effect,
@@ -0,0 +1,495 @@
+use crate::{
+    dungeon::Dungeon,
+    encounter::Encounter,
+    game::{Action, Card, Game, GameError, LootChoice, Phase, MAX_DOORS},
+    game_setup::difficulty::Difficulty,
+    hero::{DiceColour, Hero},
+};
+
+/// Dice, boxes and skills past these limits are left out of the encoding and
+/// cannot be picked by index.
+pub const MAX_POOL: usize = 16;
+pub const MAX_BOXES: usize = 10;
+pub const MAX_SKILLS: usize = 6;
+
+const HERO_FEATURES: usize = 12;
+const GAME_FEATURES: usize = 5;
+const PHASES: usize = 7;
+const DOOR_FEATURES: usize = 4;
+const ENCOUNTER_FEATURES: usize = 4;
+const DICE_FEATURES: usize = 6;
+const BOX_FEATURES: usize = 12;
+const SKILL_FEATURES: usize = 2;
+
+/// Length of every encoded observation.
+pub const OBSERVATION_SIZE: usize = HERO_FEATURES
+    + GAME_FEATURES
+    + PHASES
+    + MAX_DOORS * DOOR_FEATURES
+    + ENCOUNTER_FEATURES
+    + MAX_POOL * DICE_FEATURES
+    + MAX_BOXES * BOX_FEATURES
+    + MAX_SKILLS * SKILL_FEATURES;
+
+const SIMPLE_ACTIONS: [Action; 8] = [
+    Action::Explore,
+    Action::Fight,
+    Action::Flee,
+    Action::DrinkPotion,
+    Action::Descend,
+    Action::FinishEncounter,
+    Action::ChoosePeril(0),
+    Action::ChoosePeril(1),
+];
+const LOOT_CHOICES: [LootChoice; 3] = [LootChoice::Item, LootChoice::Skill, LootChoice::Xp];
+
+const DOOR_OFFSET: usize = SIMPLE_ACTIONS.len();
+const LOOT_OFFSET: usize = DOOR_OFFSET + MAX_DOORS;
+const PLACE_OFFSET: usize = LOOT_OFFSET + LOOT_CHOICES.len();
+const HEROIC_OFFSET: usize = PLACE_OFFSET + MAX_POOL * MAX_BOXES;
+const SKILL_OFFSET: usize = HEROIC_OFFSET + MAX_POOL;
+const AIMED_SKILL_OFFSET: usize = SKILL_OFFSET + MAX_SKILLS;
+
+/// Number of discrete actions an agent picks from. Skills take one index each
+/// and one more per dice they can be aimed at.
+pub const ACTION_COUNT: usize = AIMED_SKILL_OFFSET + MAX_SKILLS * MAX_POOL;
+
+/// Games still running after this many steps end as a loss.
+pub const DEFAULT_MAX_STEPS: usize = 2_000;
+
+/// What the agent sees after each step.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EnvObservation {
+    /// Always `OBSERVATION_SIZE` long.
+    pub features: Vec<f32>,
+    /// Always `ACTION_COUNT` long; only indices marked `true` may be stepped.
+    pub action_mask: Vec<bool>,
+}
+
+/// A gym-style wrapper around `Game`. Rewards are 1 for a win and -1 for a loss
+/// or a game that runs out of steps, with nothing in between.
+pub struct Environment {
+    hero: Hero,
+    dungeon: Dungeon,
+    difficulty: Difficulty,
+    max_steps: usize,
+    steps: usize,
+    game: Option<Game>,
+}
+
+impl Environment {
+    pub fn new(hero: Hero, dungeon: Dungeon) -> Environment {
+        Environment {
+            hero,
+            dungeon,
+            difficulty: Difficulty::default(),
+            max_steps: DEFAULT_MAX_STEPS,
+            steps: 0,
+            game: None,
+        }
+    }
+
+    pub fn with_difficulty(mut self, difficulty: Difficulty) -> Environment {
+        self.difficulty = difficulty;
+        self
+    }
+
+    pub fn with_max_steps(mut self, max_steps: usize) -> Environment {
+        self.max_steps = max_steps;
+        self
+    }
+
+    /// The game in progress, if `reset` has been called.
+    pub fn game(&self) -> Option<&Game> {
+        self.game.as_ref()
+    }
+
+    /// Starts a fresh game shuffled from `seed`.
+    pub fn reset(&mut self, seed: u64) -> EnvObservation {
+        let game = Game::with_difficulty(
+            self.hero.clone(),
+            self.dungeon.clone(),
+            seed,
+            self.difficulty,
+        );
+        let observation = observe(&game);
+        self.game = Some(game);
+        self.steps = 0;
+        observation
+    }
+
+    /// Takes the action at `index`, returning the new observation, the reward and
+    /// whether the game is over. Masked out indices are rejected.
+    pub fn step(&mut self, index: usize) -> Result<(EnvObservation, f32, bool), GameError> {
+        let game = self
+            .game
+            .as_mut()
+            .ok_or(GameError::InvalidAction(String::from(
+                "reset has to be called before step",
+            )))?;
+        let action = decode_action(game, index).ok_or(GameError::InvalidAction(format!(
+            "action {} is not allowed right now",
+            index
+        )))?;
+        game.apply(action)?;
+        self.steps += 1;
+
+        let (reward, done) = match game.phase {
+            Phase::Won => (1.0, true),
+            Phase::Lost(_) => (-1.0, true),
+            _ if self.steps >= self.max_steps => (-1.0, true),
+            _ => (0.0, false),
+        };
+        Ok((observe(game), reward, done))
+    }
+}
+
+/// Encodes `game` as the agent sees it.
+pub fn observe(game: &Game) -> EnvObservation {
+    EnvObservation {
+        features: encode(game),
+        action_mask: action_mask(game),
+    }
+}
+
+/// Marks every index whose action is in `Game::legal_actions`.
+pub fn action_mask(game: &Game) -> Vec<bool> {
+    let mut mask = vec![false; ACTION_COUNT];
+    for action in game.legal_actions() {
+        if let Some(index) = encode_action(game, &action) {
+            mask[index] = true;
+        }
+    }
+    mask
+}
+
+/// The index for `action`, if it has one. A heroic dice only has an index when
+/// it is made from one dice and the lowest others that keep that dice's value.
+pub fn encode_action(game: &Game, action: &Action) -> Option<usize> {
+    match action {
+        Action::OpenDoor(door) => (*door < MAX_DOORS).then_some(DOOR_OFFSET + door),
+        Action::Loot(choice) => LOOT_CHOICES
+            .iter()
+            .position(|known| known == choice)
+            .map(|position| LOOT_OFFSET + position),
+        Action::PlaceDice {
+            dice,
+            challenge_box,
+        } => (*dice < MAX_POOL && *challenge_box < MAX_BOXES)
+            .then_some(PLACE_OFFSET + dice * MAX_BOXES + challenge_box),
+        Action::MakeHeroic(dice) => {
+            let mut given = dice.clone();
+            given.sort_unstable();
+            dice.iter()
+                .copied()
+                .find(|index| {
+                    *index < MAX_POOL && heroic_group(game, *index).as_ref() == Some(&given)
+                })
+                .map(|index| HEROIC_OFFSET + index)
+        }
+        Action::UseSkill { skill, dice: None } => {
+            (*skill < MAX_SKILLS).then_some(SKILL_OFFSET + skill)
+        }
+        Action::UseSkill {
+            skill,
+            dice: Some(dice),
+        } => (*skill < MAX_SKILLS && *dice < MAX_POOL)
+            .then_some(AIMED_SKILL_OFFSET + skill * MAX_POOL + dice),
+        simple => SIMPLE_ACTIONS.iter().position(|known| known == simple),
+    }
+}
+
+/// The action behind `index`, or `None` if it is masked out.
+pub fn decode_action(game: &Game, index: usize) -> Option<Action> {
+    let action = if index < DOOR_OFFSET {
+        SIMPLE_ACTIONS[index].clone()
+    } else if index < LOOT_OFFSET {
+        Action::OpenDoor(index - DOOR_OFFSET)
+    } else if index < PLACE_OFFSET {
+        Action::Loot(LOOT_CHOICES[index - LOOT_OFFSET])
+    } else if index < HEROIC_OFFSET {
+        let offset = index - PLACE_OFFSET;
+        Action::PlaceDice {
+            dice: offset / MAX_BOXES,
+            challenge_box: offset % MAX_BOXES,
+        }
+    } else if index < SKILL_OFFSET {
+        Action::MakeHeroic(heroic_group(game, index - HEROIC_OFFSET)?)
+    } else if index < AIMED_SKILL_OFFSET {
+        Action::UseSkill {
+            skill: index - SKILL_OFFSET,
+            dice: None,
+        }
+    } else if index < ACTION_COUNT {
+        let offset = index - AIMED_SKILL_OFFSET;
+        Action::UseSkill {
+            skill: offset / MAX_POOL,
+            dice: Some(offset % MAX_POOL),
+        }
+    } else {
+        return None;
+    };
+    // Two dice can lead to the same heroic group; only the index the mask uses counts.
+    let canonical = encode_action(game, &action) == Some(index);
+    (canonical && game.legal_actions().contains(&action)).then_some(action)
+}
+
+/// `dice` together with the lowest dice that are at least as high, enough of
+/// them to make one heroic dice worth `dice`'s value.
+fn heroic_group(game: &Game, dice: usize) -> Option<Vec<usize>> {
+    let state = game.encounter.as_ref()?;
+    let value = state.pool.get(dice)?.value;
+    let mut partners: Vec<usize> = (0..state.pool.len())
+        .filter(|index| *index != dice && state.pool[*index].value >= value)
+        .collect();
+    partners.sort_by_key(|index| (state.pool[*index].value, *index));
+    partners.truncate(state.heroic_cost() - 1);
+    if partners.len() + 1 < state.heroic_cost() {
+        return None;
+    }
+    partners.push(dice);
+    partners.sort_unstable();
+    Some(partners)
+}
+
+/// Fixed-size numeric encoding; counts are left as plain numbers and dice values
+/// are scaled to 0..=1.
+pub fn encode(game: &Game) -> Vec<f32> {
+    let mut features = Vec::with_capacity(OBSERVATION_SIZE);
+    let hero = &game.hero;
+    for colour in [DiceColour::Strength, DiceColour::Agility, DiceColour::Magic] {
+        features.push(hero.dice_count(&colour) as f32);
+    }
+    features.extend([
+        hero.health as f32,
+        game.remaining_health() as f32,
+        game.damage as f32,
+        hero.potions as f32,
+        hero.xp as f32,
+        hero.current_level as f32,
+        hero.encounter_bonus as f32,
+        hero.skills.len() as f32,
+        hero.items.len() as f32,
+    ]);
+
+    features.extend([
+        game.floor as f32,
+        game.deck.len() as f32,
+        game.discard.len() as f32,
+        game.boss_damage as f32,
+        game.dungeon.boss.health as f32,
+    ]);
+
+    let phase = match game.phase {
+        Phase::Exploring => 0,
+        Phase::DoorOpened(_) => 1,
+        Phase::ChoosingPeril(_) => 2,
+        Phase::Encounter => 3,
+        Phase::Loot => 4,
+        Phase::Won => 5,
+        Phase::Lost(_) => 6,
+    };
+    features.extend(one_hot(phase, PHASES));
+
+    for index in 0..MAX_DOORS {
+        match game.doors.get(index) {
+            Some(door) => features.extend([
+                1.0,
+                flag(door.revealed),
+                flag(door.revealed && matches!(door.card, Card::Peril(_))),
+                flag(door.revealed && matches!(door.card, Card::Combat(_))),
+            ]),
+            None => features.extend([0.0; DOOR_FEATURES]),
+        }
+    }
+
+    let state = game.encounter.as_ref();
+    let encounter = state.map(|state| match state.encounter {
+        Encounter::Combat => 0,
+        Encounter::Peril => 1,
+        Encounter::Boss => 2,
+    });
+    features.extend(match encounter {
+        Some(encounter) => one_hot(encounter, 3),
+        None => vec![0.0; 3],
+    });
+    features.push(state.map_or(0.0, |state| state.heroic_cost() as f32));
+
+    for index in 0..MAX_POOL {
+        match state.and_then(|state| state.pool.get(index)) {
+            Some(dice) => {
+                features.extend([1.0, dice.value as f32 / 6.0]);
+                features.extend(dice_type(Some(&dice.dice_type)));
+            }
+            None => features.extend([0.0; DICE_FEATURES]),
+        }
+    }
+
+    for index in 0..MAX_BOXES {
+        match state.and_then(|state| state.boxes.get(index)) {
+            Some(placed) => {
+                let challenge = &placed.challenge;
+                let (health, time) = challenge.costs();
+                features.extend([
+                    1.0,
+                    flag(placed.is_complete()),
+                    placed.total() as f32 / 6.0,
+                    challenge.total_value as f32 / 6.0,
+                    flag(challenge.single_dice),
+                    flag(challenge.priority),
+                ]);
+                features.extend(dice_type(challenge.dice_type.as_ref()));
+                features.extend([health as f32, time as f32]);
+            }
+            None => features.extend([0.0; BOX_FEATURES]),
+        }
+    }
+
+    let legal_actions = game.legal_actions();
+    let skills = game.encounter_skills().len();
+    for index in 0..MAX_SKILLS {
+        let usable = legal_actions.contains(&Action::UseSkill {
+            skill: index,
+            dice: None,
+        });
+        features.extend([flag(index < skills), flag(usable)]);
+    }
+    features
+}
+
+fn flag(value: bool) -> f32 {
+    if value {
+        1.0
+    } else {
+        0.0
+    }
+}
+
+fn one_hot(index: usize, size: usize) -> Vec<f32> {
+    (0..size).map(|position| flag(position == index)).collect()
+}
+
+/// Strength, Agility, Magic and Heroic flags; boxes that take any dice set none.
+fn dice_type(dice_type: Option<&DiceColour>) -> [f32; 4] {
+    let mut flags = [0.0; 4];
+    let position = match dice_type {
+        Some(DiceColour::Strength) => Some(0),
+        Some(DiceColour::Agility) => Some(1),
+        Some(DiceColour::Magic) => Some(2),
+        Some(DiceColour::Heroic) => Some(3),
+        _ => None,
+    };
+    if let Some(position) = position {
+        flags[position] = 1.0;
+    }
+    flags
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, seq::SliceRandom, SeedableRng};
+
+    use super::*;
+    use crate::{
+        game_setup,
+        hero::{Effect, PlayerCount, Skill},
+    };
+
+    #[test]
+    fn observation_blocks_add_up() {
+        // Hero, game, phase, then per door, encounter, per dice, per box and per skill.
+        assert_eq!(
+            OBSERVATION_SIZE,
+            12 + 5 + 7 + MAX_DOORS * 4 + 4 + MAX_POOL * 6 + MAX_BOXES * 12 + MAX_SKILLS * 2
+        );
+        let hero = game_setup::get_all_heroes(PlayerCount::Solo).remove(0);
+        let dungeon = game_setup::get_all_dungeons().remove(0);
+        let game = Game::new(hero, dungeon, 1);
+        assert_eq!(encode(&game).len(), OBSERVATION_SIZE);
+        assert_eq!(action_mask(&game).len(), ACTION_COUNT);
+    }
+
+    #[test]
+    fn aimed_skills_have_their_own_indices() {
+        // A free skill that can be aimed at any dice.
+        let mut hero = game_setup::get_all_heroes(PlayerCount::Solo).remove(0);
+        hero.skills.push(Skill {
+            name: String::from("NUDGE"),
+            description: None,
+            requirements: None,
+            effect: Effect::Increase(1),
+            encounters: vec![Encounter::Combat, Encounter::Peril, Encounter::Boss],
+        });
+        let dungeon = game_setup::get_all_dungeons().remove(0);
+        let mut game = Game::new(hero, dungeon, 2);
+        let mut rng = StdRng::seed_from_u64(2);
+        let mut checked = 0;
+        while !game.is_over() {
+            let legal = game.legal_actions();
+            for action in legal.iter() {
+                if let Action::UseSkill { dice: Some(_), .. } = action {
+                    let index = encode_action(&game, action).unwrap();
+                    assert!(index >= AIMED_SKILL_OFFSET);
+                    assert_eq!(decode_action(&game, index).as_ref(), Some(action));
+                    checked += 1;
+                }
+            }
+            game.apply(legal.choose(&mut rng).unwrap().clone()).unwrap();
+        }
+        assert!(checked > 0);
+    }
+
+    #[test]
+    fn encoding_and_mask_follow_the_game() {
+        let heroes = game_setup::get_all_heroes(PlayerCount::Solo);
+        let dungeon = game_setup::get_all_dungeons().remove(0);
+        for seed in 0..4u64 {
+            let hero = heroes[seed as usize % heroes.len()].clone();
+            let mut env = Environment::new(hero, dungeon.clone()).with_max_steps(300);
+            let mut rng = StdRng::seed_from_u64(seed);
+            let mut observation = env.reset(seed);
+            loop {
+                let game = env.game().unwrap();
+                assert_eq!(observation.features.len(), OBSERVATION_SIZE);
+                assert_eq!(observation.action_mask.len(), ACTION_COUNT);
+                let phases = &observation.features[HERO_FEATURES + GAME_FEATURES..][..PHASES];
+                assert_eq!(phases.iter().sum::<f32>(), 1.0);
+
+                let legal = game.legal_actions();
+                for (index, allowed) in observation.action_mask.iter().enumerate() {
+                    match decode_action(game, index) {
+                        Some(action) => {
+                            assert!(allowed, "{} is legal but masked out", action);
+                            assert!(legal.contains(&action));
+                            assert_eq!(encode_action(game, &action), Some(index));
+                        }
+                        None => assert!(!allowed, "index {} is masked in but illegal", index),
+                    }
+                }
+                for action in legal.iter() {
+                    if let Some(index) = encode_action(game, action) {
+                        assert!(
+                            observation.action_mask[index],
+                            "{} is not masked in",
+                            action
+                        );
+                    }
+                }
+
+                let allowed: Vec<usize> = (0..ACTION_COUNT)
+                    .filter(|index| observation.action_mask[*index])
+                    .collect();
+                // Masked out indices are refused before the game is touched.
+                if let Some(masked) = (0..ACTION_COUNT).find(|index| !allowed.contains(index)) {
+                    assert!(env.step(masked).is_err());
+                }
+                let index = *allowed.choose(&mut rng).expect("some action is allowed");
+                let (next, _, done) = env.step(index).unwrap();
+                if done {
+                    break;
+                }
+                observation = next;
+            }
+        }
+    }
+}
@@ -0,0 +1,146 @@
+use std::fmt::Display;
+
+use crate::{
+    campaign::CampaignError, game::GameError, generator::GeneratorError, hero::HeroError,
+    history::HistoryError, odds::OddsError,
+};
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// Failures while loading card data, heroes or dungeons from outside the built-in set.
+#[derive(Debug)]
+pub enum DataError {
+    Io(std::io::Error),
+    Parse {
+        source: String,
+        message: String,
+    },
+    NotFound {
+        kind: &'static str,
+        name: String,
+    },
+    /// The data was read but breaks the rules for its kind of card.
+    Invalid {
+        source: String,
+        issues: Vec<String>,
+    },
+}
+
+impl Display for DataError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DataError::Io(err) => write!(f, "could not read data: {}", err),
+            DataError::Parse { source, message } => {
+                write!(f, "could not parse {}: {}", source, message)
+            }
+            DataError::NotFound { kind, name } => write!(f, "no {} named {}", kind, name),
+            DataError::Invalid { source, issues } => {
+                write!(f, "{} is not valid: {}", source, issues.join("; "))
+            }
+        }
+    }
+}
+
+impl std::error::Error for DataError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            DataError::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for DataError {
+    fn from(err: std::io::Error) -> Self {
+        DataError::Io(err)
+    }
+}
+
+/// Crate-wide error, covering hero updates, the game engine, odds, campaigns, the
+/// run history, data loading and generated content.
+#[derive(Debug)]
+pub enum Error {
+    Hero(HeroError),
+    Game(GameError),
+    Odds(OddsError),
+    Campaign(CampaignError),
+    History(HistoryError),
+    Data(DataError),
+    Generator(GeneratorError),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Hero(err) => write!(f, "hero error: {}", err),
+            Error::Game(err) => write!(f, "game error: {}", err),
+            Error::Odds(err) => write!(f, "odds error: {}", err),
+            Error::Campaign(err) => write!(f, "campaign error: {}", err),
+            Error::History(err) => write!(f, "history error: {}", err),
+            Error::Data(err) => write!(f, "data error: {}", err),
+            Error::Generator(err) => write!(f, "generator error: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Hero(err) => Some(err),
+            Error::Game(err) => Some(err),
+            Error::Odds(err) => Some(err),
+            Error::Campaign(err) => Some(err),
+            Error::History(err) => Some(err),
+            Error::Data(err) => Some(err),
+            Error::Generator(err) => Some(err),
+        }
+    }
+}
+
+impl From<HeroError> for Error {
+    fn from(err: HeroError) -> Self {
+        Error::Hero(err)
+    }
+}
+
+impl From<GameError> for Error {
+    fn from(err: GameError) -> Self {
+        Error::Game(err)
+    }
+}
+
+impl From<OddsError> for Error {
+    fn from(err: OddsError) -> Self {
+        Error::Odds(err)
+    }
+}
+
+impl From<CampaignError> for Error {
+    fn from(err: CampaignError) -> Self {
+        Error::Campaign(err)
+    }
+}
+
+impl From<HistoryError> for Error {
+    fn from(err: HistoryError) -> Self {
+        Error::History(err)
+    }
+}
+
+impl From<DataError> for Error {
+    fn from(err: DataError) -> Self {
+        Error::Data(err)
+    }
+}
+
+impl From<GeneratorError> for Error {
+    fn from(err: GeneratorError) -> Self {
+        Error::Generator(err)
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(err: std::io::Error) -> Self {
+        Error::Data(DataError::Io(err))
+    }
+}
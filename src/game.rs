@@ -1 +1,1708 @@
+use std::fmt::Display;
 
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    dungeon::{ChallengeBox, Dungeon},
+    encounter::{Combat, Encounter, Peril},
+    game_setup::{self, difficulty::Difficulty},
+    hero::{Attribute, AttributeType, DiceColour, Effect, Hero, Item, Resource, Skill},
+};
+
+pub const MAX_DOORS: usize = 4;
+pub const EXPLORE_TIME_COST: usize = 2;
+pub const FLEE_TIME_COST: usize = 1;
+pub const POTION_HEAL: usize = 2;
+pub const FINAL_FLOOR: i8 = 3;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GameError {
+    GameOver,
+    InvalidAction(String),
+    DiceNotFound(usize),
+    ChallengeBoxNotFound(usize),
+    EmptyDeck,
+}
+
+impl Display for GameError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GameError::GameOver => write!(f, "the game is already over"),
+            GameError::InvalidAction(reason) => write!(f, "invalid action: {}", reason),
+            GameError::DiceNotFound(index) => write!(f, "there is no dice at position {}", index),
+            GameError::ChallengeBoxNotFound(index) => {
+                write!(f, "there is no challenge box at position {}", index)
+            }
+            GameError::EmptyDeck => write!(f, "the dungeon deck is empty"),
+        }
+    }
+}
+
+impl std::error::Error for GameError {}
+
+type Result<T> = std::result::Result<T, GameError>;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Dice {
+    pub dice_type: DiceColour,
+    pub value: i8,
+}
+
+impl Dice {
+    pub fn roll(dice_type: DiceColour, rng: &mut impl Rng) -> Dice {
+        Dice {
+            dice_type,
+            value: rng.gen_range(1..=6),
+        }
+    }
+
+    /// Heroic dice are wild, and a box without a dice type accepts anything.
+    pub fn fits(&self, challenge: &ChallengeBox) -> bool {
+        match &challenge.dice_type {
+            None => true,
+            Some(dice_type) => self.dice_type == DiceColour::Heroic || &self.dice_type == dice_type,
+        }
+    }
+}
+
+impl Display for Dice {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.dice_type, self.value)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum Card {
+    Peril(Peril),
+    Combat(Combat),
+}
+
+impl Card {
+    pub fn name(&self) -> &str {
+        match self {
+            Card::Peril(peril) => &peril.name,
+            Card::Combat(combat) => &combat.name,
+        }
+    }
+
+    pub fn encounter(&self) -> Encounter {
+        match self {
+            Card::Peril(_) => Encounter::Peril,
+            Card::Combat(_) => Encounter::Combat,
+        }
+    }
+
+    pub fn xp_reward(&self) -> i8 {
+        match self {
+            Card::Peril(peril) => peril.xp_reward,
+            Card::Combat(combat) => combat.xp_reward,
+        }
+    }
+
+    pub fn item_reward(&self) -> &Vec<Attribute> {
+        match self {
+            Card::Peril(peril) => &peril.item_reward,
+            Card::Combat(combat) => &combat.item_reward,
+        }
+    }
+
+    pub fn skill_reward(&self) -> &Skill {
+        match self {
+            Card::Peril(peril) => &peril.skill_reward,
+            Card::Combat(combat) => &combat.skill_reward,
+        }
+    }
+
+    pub fn special_ability(&self) -> &Effect {
+        match self {
+            Card::Peril(_) => &Effect::None,
+            Card::Combat(combat) => &combat.special_ability,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Door {
+    pub card: Card,
+    pub revealed: bool,
+}
+
+/// A challenge box together with the dice placed in it so far.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlacedBox {
+    pub challenge: ChallengeBox,
+    pub dice: Vec<Dice>,
+}
+
+impl PlacedBox {
+    pub fn new(challenge: ChallengeBox) -> PlacedBox {
+        PlacedBox {
+            challenge,
+            dice: Vec::new(),
+        }
+    }
+
+    pub fn total(&self) -> i8 {
+        self.dice.iter().map(|dice| dice.value).sum()
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.total() >= self.challenge.total_value
+    }
+
+    /// Single dice boxes only take one dice that meets the target on its own.
+    pub fn accepts(&self, dice: &Dice) -> bool {
+        if self.is_complete() || !dice.fits(&self.challenge) {
+            return false;
+        }
+        if self.challenge.single_dice {
+            return dice.value >= self.challenge.total_value;
+        }
+        true
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EncounterState {
+    pub encounter: Encounter,
+    pub name: String,
+    pub special_ability: Effect,
+    pub boxes: Vec<PlacedBox>,
+    pub pool: Vec<Dice>,
+    pub prevented: Vec<Resource>,
+    pub used_skills: Vec<usize>,
+}
+
+impl EncounterState {
+    /// Priority boxes have to be filled first, for as long as any dice in the pool fits one.
+    pub fn priority_outstanding(&self) -> bool {
+        self.boxes.iter().any(|placed| {
+            placed.challenge.priority && self.pool.iter().any(|dice| placed.accepts(dice))
+        })
+    }
+
+    /// Number of dice that have to be combined into one heroic dice.
+    pub fn heroic_cost(&self) -> usize {
+        if self.special_ability == Effect::Dodge {
+            3
+        } else {
+            2
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LootChoice {
+    Item,
+    Skill,
+    Xp,
+}
+
+impl Display for LootChoice {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Action {
+    Explore,
+    OpenDoor(usize),
+    Fight,
+    Flee,
+    ChoosePeril(usize),
+    PlaceDice { dice: usize, challenge_box: usize },
+    MakeHeroic(Vec<usize>),
+    UseSkill { skill: usize, dice: Option<usize> },
+    FinishEncounter,
+    Loot(LootChoice),
+    DrinkPotion,
+    Descend,
+}
+
+impl Display for Action {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Action::Explore => write!(f, "Explore"),
+            Action::OpenDoor(door) => write!(f, "Open door {}", door + 1),
+            Action::Fight => write!(f, "Fight"),
+            Action::Flee => write!(f, "Flee"),
+            Action::ChoosePeril(choice) => write!(f, "Choose peril option {}", choice + 1),
+            Action::PlaceDice {
+                dice,
+                challenge_box,
+            } => write!(f, "Place dice {} in box {}", dice + 1, challenge_box + 1),
+            Action::MakeHeroic(dice) => write!(
+                f,
+                "Make heroic from dice {}",
+                dice.iter()
+                    .map(|index| (index + 1).to_string())
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
+            Action::UseSkill { skill, dice } => match dice {
+                Some(dice) => write!(f, "Use skill {} on dice {}", skill + 1, dice + 1),
+                None => write!(f, "Use skill {}", skill + 1),
+            },
+            Action::FinishEncounter => write!(f, "Finish encounter"),
+            Action::Loot(choice) => write!(f, "Loot as {}", choice),
+            Action::DrinkPotion => write!(f, "Drink potion"),
+            Action::Descend => write!(f, "Descend"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Phase {
+    Exploring,
+    DoorOpened(usize),
+    ChoosingPeril(usize),
+    Encounter,
+    Loot,
+    Won,
+    Lost(String),
+}
+
+/// The hero waiting for their turn in a co-op game, with the damage they have
+/// taken so far.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Partner {
+    pub hero: Hero,
+    pub damage: usize,
+}
+
+impl Partner {
+    pub fn remaining_health(&self) -> usize {
+        self.hero.health.saturating_sub(self.damage)
+    }
+}
+
+/// Everything a player is allowed to see: the order of the deck stays hidden.
+#[derive(Serialize)]
+pub struct Observation<'a> {
+    pub hero: &'a Hero,
+    pub partner: Option<&'a Partner>,
+    pub dungeon: &'a Dungeon,
+    pub phase: &'a Phase,
+    pub difficulty: Difficulty,
+    pub doors: Vec<Option<&'a Card>>,
+    pub deck_size: usize,
+    pub discard_size: usize,
+    pub floor: i8,
+    pub damage: usize,
+    pub remaining_health: usize,
+    pub boss_damage: usize,
+    pub encounter: Option<&'a EncounterState>,
+    pub loot: Option<&'a Card>,
+    pub loot_options: Vec<LootChoice>,
+    #[serde(skip)]
+    game: &'a Game,
+}
+
+impl Observation<'_> {
+    /// One way the hidden parts of the game could be; see [`Game::determinize`].
+    pub fn determinize(&self, seed: u64) -> Game {
+        self.game.determinize(seed)
+    }
+}
+
+#[derive(Clone)]
+pub struct Game {
+    /// The hero whose turn it is.
+    pub hero: Hero,
+    /// The other hero in a co-op game. The two swap places as turns pass.
+    pub partner: Option<Partner>,
+    pub dungeon: Dungeon,
+    pub seed: u64,
+    pub difficulty: Difficulty,
+    pub deck: Vec<Card>,
+    pub discard: Vec<Card>,
+    pub doors: Vec<Door>,
+    pub floor: i8,
+    pub damage: usize,
+    pub boss_damage: usize,
+    /// Doors fought or fled from, plus boss rounds.
+    pub turns: usize,
+    pub phase: Phase,
+    pub encounter: Option<EncounterState>,
+    pub loot: Option<Card>,
+    fighting_door: Option<usize>,
+    rng: StdRng,
+}
+
+impl Game {
+    /// Starts a game with the full built-in deck, shuffled from `seed`.
+    pub fn new(hero: Hero, dungeon: Dungeon, seed: u64) -> Game {
+        Game::with_difficulty(hero, dungeon, seed, Difficulty::default())
+    }
+
+    /// Starts a game like [`Game::new`], with `hero` and the boss adjusted for
+    /// `difficulty`.
+    pub fn with_difficulty(
+        mut hero: Hero,
+        mut dungeon: Dungeon,
+        seed: u64,
+        difficulty: Difficulty,
+    ) -> Game {
+        difficulty.adjust_hero(&mut hero);
+        difficulty.adjust_dungeon(&mut dungeon);
+        let mut deck: Vec<Card> = game_setup::get_all_perils()
+            .into_iter()
+            .map(Card::Peril)
+            .chain(game_setup::get_all_combats().into_iter().map(Card::Combat))
+            .collect();
+        let mut rng = StdRng::seed_from_u64(seed);
+        deck.shuffle(&mut rng);
+        Game {
+            hero,
+            partner: None,
+            dungeon,
+            seed,
+            difficulty,
+            deck,
+            discard: Vec::new(),
+            doors: Vec::new(),
+            floor: 1,
+            damage: 0,
+            boss_damage: 0,
+            turns: 0,
+            phase: Phase::Exploring,
+            encounter: None,
+            loot: None,
+            fighting_door: None,
+            rng,
+        }
+    }
+
+    /// Swaps the deck for `deck`, shuffled from the game's seed just as the
+    /// built-in deck would have been. Only meant for a game that has not started.
+    pub fn with_deck(mut self, mut deck: Vec<Card>) -> Game {
+        self.rng = StdRng::seed_from_u64(self.seed);
+        deck.shuffle(&mut self.rng);
+        self.deck = deck;
+        self
+    }
+
+    /// Starts a two-player game where `hero` and `partner` share the deck, the
+    /// time and every encounter, taking turns to explore. Damage falls on the
+    /// hero whose turn it is, while either hero's skills and potions can be used
+    /// on it. Both heroes should be
+    /// built for [`PlayerCount::Coop`](crate::hero::PlayerCount::Coop).
+    pub fn co_op(
+        hero: Hero,
+        mut partner: Hero,
+        dungeon: Dungeon,
+        seed: u64,
+        difficulty: Difficulty,
+    ) -> Game {
+        difficulty.adjust_hero(&mut partner);
+        let mut game = Game::with_difficulty(hero, dungeon, seed, difficulty);
+        game.partner = Some(Partner {
+            hero: partner,
+            damage: 0,
+        });
+        game
+    }
+
+    pub fn observe(&self) -> Observation<'_> {
+        Observation {
+            hero: &self.hero,
+            partner: self.partner.as_ref(),
+            dungeon: &self.dungeon,
+            phase: &self.phase,
+            difficulty: self.difficulty,
+            doors: self
+                .doors
+                .iter()
+                .map(|door| door.revealed.then_some(&door.card))
+                .collect(),
+            deck_size: self.deck.len(),
+            discard_size: self.discard.len(),
+            floor: self.floor,
+            damage: self.damage,
+            remaining_health: self.remaining_health(),
+            boss_damage: self.boss_damage,
+            encounter: self.encounter.as_ref(),
+            loot: self.loot.as_ref(),
+            loot_options: [LootChoice::Item, LootChoice::Skill, LootChoice::Xp]
+                .into_iter()
+                .filter(|choice| self.can_loot(*choice))
+                .collect(),
+            game: self,
+        }
+    }
+
+    /// A copy of the game where everything the player cannot see, the cards in
+    /// the deck and behind closed doors and every future roll, is redrawn from
+    /// `seed`. Searches play these copies out instead of the real game.
+    pub fn determinize(&self, seed: u64) -> Game {
+        let mut game = self.clone();
+        game.rng = StdRng::seed_from_u64(seed);
+        let mut hidden: Vec<Card> = game.deck.drain(..).collect();
+        let closed: Vec<&mut Door> = game
+            .doors
+            .iter_mut()
+            .filter(|door| !door.revealed)
+            .collect();
+        hidden.extend(closed.iter().map(|door| door.card.clone()));
+        hidden.shuffle(&mut game.rng);
+        for door in closed {
+            if let Some(card) = hidden.pop() {
+                door.card = card;
+            }
+        }
+        game.deck = hidden;
+        game
+    }
+
+    pub fn is_over(&self) -> bool {
+        matches!(self.phase, Phase::Won | Phase::Lost(_))
+    }
+
+    pub fn remaining_health(&self) -> usize {
+        self.hero.health.saturating_sub(self.damage)
+    }
+
+    /// The hero, or both heroes in a co-op game with the names in a fixed order.
+    pub fn hero_names(&self) -> String {
+        match self.partner.as_ref() {
+            None => self.hero.name.clone(),
+            Some(partner) => {
+                let mut names = [self.hero.name.as_str(), partner.hero.name.as_str()];
+                names.sort();
+                names.join(" & ")
+            }
+        }
+    }
+
+    pub fn is_boss_floor(&self) -> bool {
+        self.floor > FINAL_FLOOR
+    }
+
+    pub fn score(&self) -> i32 {
+        let partner_xp = self
+            .partner
+            .as_ref()
+            .map_or(0, |partner| partner.hero.xp as i32);
+        let mut score = self.hero.xp as i32 + partner_xp + (self.floor as i32 - 1) * 5;
+        if self.phase == Phase::Won {
+            score += 20 + self.remaining_health() as i32 + self.difficulty.score_bonus();
+        }
+        score
+    }
+
+    /// Every action `apply` would accept right now. Skills that aim at a dice are
+    /// listed once with no target, letting the engine pick, and once per dice they
+    /// could hit when paying for them leaves the pool as it is.
+    pub fn legal_actions(&self) -> Vec<Action> {
+        let mut actions = Vec::new();
+        match &self.phase {
+            Phase::Exploring => {
+                if !self.deck.is_empty() && self.doors.len() < MAX_DOORS {
+                    actions.push(Action::Explore);
+                }
+                actions.extend((0..self.doors.len()).map(Action::OpenDoor));
+                if self.potion_drinker().is_some() {
+                    actions.push(Action::DrinkPotion);
+                }
+                if self.deck.is_empty() {
+                    actions.push(Action::Descend);
+                }
+            }
+            Phase::DoorOpened(_) => actions.extend([Action::Fight, Action::Flee]),
+            Phase::ChoosingPeril(_) => {
+                actions.extend([Action::ChoosePeril(0), Action::ChoosePeril(1)])
+            }
+            Phase::Encounter => {
+                let Some(state) = self.encounter.as_ref() else {
+                    return actions;
+                };
+                let priority = state.priority_outstanding();
+                for (dice, rolled) in state.pool.iter().enumerate() {
+                    for (challenge_box, placed) in state.boxes.iter().enumerate() {
+                        if placed.accepts(rolled) && (placed.challenge.priority || !priority) {
+                            actions.push(Action::PlaceDice {
+                                dice,
+                                challenge_box,
+                            });
+                        }
+                    }
+                }
+                actions.extend(
+                    combinations(state.pool.len(), state.heroic_cost())
+                        .into_iter()
+                        .map(Action::MakeHeroic),
+                );
+                for (index, (owner, skill)) in self.encounter_skills().into_iter().enumerate() {
+                    if !skill.encounters.contains(&state.encounter)
+                        || state.used_skills.contains(&index)
+                        || !self.can_pay_from(owner, skill.requirements.as_ref())
+                    {
+                        continue;
+                    }
+                    actions.push(Action::UseSkill {
+                        skill: index,
+                        dice: None,
+                    });
+                    if keeps_pool(skill) {
+                        for (dice, rolled) in state.pool.iter().enumerate() {
+                            if targetable(&skill.effect, rolled) {
+                                actions.push(Action::UseSkill {
+                                    skill: index,
+                                    dice: Some(dice),
+                                });
+                            }
+                        }
+                    }
+                }
+                actions.push(Action::FinishEncounter);
+            }
+            Phase::Loot => actions.extend(
+                [LootChoice::Item, LootChoice::Skill, LootChoice::Xp]
+                    .into_iter()
+                    .filter(|choice| self.can_loot(*choice))
+                    .map(Action::Loot),
+            ),
+            Phase::Won | Phase::Lost(_) => {}
+        }
+        actions
+    }
+
+    /// Applies one action and returns the log messages it produced.
+    pub fn apply(&mut self, action: Action) -> Result<Vec<String>> {
+        if self.is_over() {
+            return Err(GameError::GameOver);
+        }
+        let mut log = Vec::new();
+        match (&self.phase, action) {
+            (Phase::Exploring, Action::Explore) => self.explore(&mut log)?,
+            (Phase::Exploring, Action::OpenDoor(door)) => self.open_door(door, &mut log)?,
+            (Phase::Exploring, Action::DrinkPotion) => self.drink_potion(&mut log)?,
+            (Phase::Exploring, Action::Descend) => self.descend(&mut log)?,
+            (Phase::DoorOpened(door), Action::Fight) => {
+                let door = *door;
+                self.fight(door, &mut log)?
+            }
+            (Phase::DoorOpened(door), Action::Flee) => {
+                let door = *door;
+                log.push(format!("Fled from {}", self.doors[door].card.name()));
+                self.turns += 1;
+                self.phase = Phase::Exploring;
+                self.spend_time(FLEE_TIME_COST, &mut log);
+                self.pass_turn(&mut log);
+            }
+            (Phase::ChoosingPeril(door), Action::ChoosePeril(choice)) => {
+                let door = *door;
+                self.choose_peril(door, choice, &mut log)?
+            }
+            (
+                Phase::Encounter,
+                Action::PlaceDice {
+                    dice,
+                    challenge_box,
+                },
+            ) => self.place_dice(dice, challenge_box, &mut log)?,
+            (Phase::Encounter, Action::MakeHeroic(dice)) => self.make_heroic(dice, &mut log)?,
+            (Phase::Encounter, Action::UseSkill { skill, dice }) => {
+                self.use_skill(skill, dice, &mut log)?
+            }
+            (Phase::Encounter, Action::FinishEncounter) => self.finish_encounter(&mut log),
+            (Phase::Loot, Action::Loot(choice)) => self.claim_loot(choice, &mut log)?,
+            (phase, action) => {
+                return Err(GameError::InvalidAction(format!(
+                    "cannot {} while {:?}",
+                    action, phase
+                )))
+            }
+        }
+        self.check_death(&mut log);
+        Ok(log)
+    }
+
+    fn explore(&mut self, log: &mut Vec<String>) -> Result<()> {
+        if self.deck.is_empty() {
+            return Err(GameError::EmptyDeck);
+        }
+        if self.doors.len() >= MAX_DOORS {
+            return Err(GameError::InvalidAction(String::from(
+                "every door is already open",
+            )));
+        }
+        self.spend_time(EXPLORE_TIME_COST, log);
+        while self.doors.len() < MAX_DOORS {
+            match self.deck.pop() {
+                Some(card) => self.doors.push(Door {
+                    card,
+                    revealed: false,
+                }),
+                None => break,
+            }
+        }
+        log.push(format!("Explored: {} doors in the room", self.doors.len()));
+        Ok(())
+    }
+
+    fn open_door(&mut self, door: usize, log: &mut Vec<String>) -> Result<()> {
+        let opened = self
+            .doors
+            .get_mut(door)
+            .ok_or(GameError::InvalidAction(format!(
+                "there is no door {}",
+                door + 1
+            )))?;
+        opened.revealed = true;
+        log.push(format!(
+            "Opened a door: {} ({})",
+            opened.card.name(),
+            opened.card.encounter()
+        ));
+        self.phase = Phase::DoorOpened(door);
+        Ok(())
+    }
+
+    /// The active hero drinks their own potions first; in a co-op game the
+    /// partner drinks one of theirs when the active hero cannot.
+    fn drink_potion(&mut self, log: &mut Vec<String>) -> Result<()> {
+        if self.potion_drinker() != Some(HeroSlot::Partner) {
+            return self.drink_own_potion(log);
+        }
+        self.swap_heroes();
+        let result = self.drink_own_potion(log);
+        self.swap_heroes();
+        result
+    }
+
+    /// Which hero would drink a potion now, if either can.
+    fn potion_drinker(&self) -> Option<HeroSlot> {
+        let can_drink = |hero: &Hero, damage: usize| hero.potions > 0 && damage > 0;
+        if can_drink(&self.hero, self.damage) {
+            return Some(HeroSlot::Active);
+        }
+        self.partner
+            .as_ref()
+            .filter(|partner| can_drink(&partner.hero, partner.damage))
+            .map(|_| HeroSlot::Partner)
+    }
+
+    fn drink_own_potion(&mut self, log: &mut Vec<String>) -> Result<()> {
+        if self.hero.potions <= 0 {
+            return Err(GameError::InvalidAction(String::from("no potions left")));
+        }
+        if self.damage == 0 {
+            return Err(GameError::InvalidAction(String::from(
+                "the hero is not damaged",
+            )));
+        }
+        self.hero.potions -= 1;
+        let healed = POTION_HEAL.min(self.damage);
+        self.damage -= healed;
+        log.push(format!(
+            "{} drank a potion and healed {} damage",
+            self.hero.name, healed
+        ));
+        Ok(())
+    }
+
+    fn descend(&mut self, log: &mut Vec<String>) -> Result<()> {
+        if !self.deck.is_empty() {
+            return Err(GameError::InvalidAction(String::from(
+                "the stairs only appear once the deck runs out",
+            )));
+        }
+        self.floor += 1;
+        // Levels past the end of the hero's table only stop the bonuses from growing.
+        let _ = self.hero.descend_level();
+        if let Some(partner) = self.partner.as_mut() {
+            let _ = partner.hero.descend_level();
+        }
+        for door in self.doors.drain(..) {
+            self.discard.push(door.card);
+        }
+        self.deck.append(&mut self.discard);
+        self.deck.shuffle(&mut self.rng);
+        if self.is_boss_floor() {
+            log.push(format!("Descended to face the {}", self.dungeon.boss.name));
+            self.start_boss_round(log);
+        } else {
+            log.push(format!("Descended to floor {}", self.floor));
+        }
+        Ok(())
+    }
+
+    fn fight(&mut self, door: usize, log: &mut Vec<String>) -> Result<()> {
+        let card = self.doors[door].card.clone();
+        match card {
+            Card::Peril(_) => {
+                self.phase = Phase::ChoosingPeril(door);
+            }
+            Card::Combat(combat) => {
+                let mut boxes = combat.challenges.clone();
+                boxes.extend(self.dungeon.floor_challenges(self.floor, Encounter::Combat));
+                self.fighting_door = Some(door);
+                self.start_encounter(
+                    Encounter::Combat,
+                    combat.name.clone(),
+                    combat.special_ability.clone(),
+                    boxes,
+                    log,
+                );
+            }
+        }
+        Ok(())
+    }
+
+    fn choose_peril(&mut self, door: usize, choice: usize, log: &mut Vec<String>) -> Result<()> {
+        let Card::Peril(peril) = self.doors[door].card.clone() else {
+            return Err(GameError::InvalidAction(String::from(
+                "the open door is not a peril",
+            )));
+        };
+        let (challenges, time_cost) = match choice {
+            0 => (peril.choice_one, peril.choice_one_time_cost),
+            1 => (peril.choice_two, peril.choice_two_time_cost),
+            _ => {
+                return Err(GameError::InvalidAction(format!(
+                    "perils only have two options, not {}",
+                    choice + 1
+                )))
+            }
+        };
+        if let Some(time_cost) = time_cost {
+            self.spend_time(time_cost.max(0) as usize, log);
+        }
+        let mut boxes = challenges;
+        boxes.extend(self.dungeon.floor_challenges(self.floor, Encounter::Peril));
+        self.fighting_door = Some(door);
+        self.start_encounter(Encounter::Peril, peril.name, Effect::None, boxes, log);
+        Ok(())
+    }
+
+    fn start_boss_round(&mut self, log: &mut Vec<String>) {
+        let boss = self.dungeon.boss.clone();
+        self.start_encounter(
+            Encounter::Boss,
+            boss.name,
+            boss.special_ability,
+            boss.challenges,
+            log,
+        );
+    }
+
+    fn start_encounter(
+        &mut self,
+        encounter: Encounter,
+        name: String,
+        special_ability: Effect,
+        boxes: Vec<ChallengeBox>,
+        log: &mut Vec<String>,
+    ) {
+        // Both heroes in a co-op game roll into the one pool.
+        let mut rolls = dice_to_roll(&self.hero);
+        if let Some(partner) = self.partner.as_ref() {
+            rolls.extend(dice_to_roll(&partner.hero));
+        }
+        let pool: Vec<Dice> = rolls
+            .into_iter()
+            .map(|colour| Dice::roll(colour, &mut self.rng))
+            .collect();
+
+        log.push(format!(
+            "{} encounter: {} (rolled {} dice)",
+            encounter,
+            name,
+            pool.len()
+        ));
+        self.encounter = Some(EncounterState {
+            encounter,
+            name,
+            special_ability: special_ability.clone(),
+            boxes: boxes.into_iter().map(PlacedBox::new).collect(),
+            pool,
+            prevented: Vec::new(),
+            used_skills: Vec::new(),
+        });
+        self.phase = Phase::Encounter;
+        self.turns += 1;
+
+        let time_cost = self.difficulty.encounter_time_cost();
+        if encounter != Encounter::Boss && time_cost > 0 {
+            log.push(format!("{}: spend {} x Time", self.difficulty, time_cost));
+            self.spend_time(time_cost, log);
+        }
+
+        match special_ability {
+            Effect::Frost => {
+                log.push(String::from("Frost: spend 3 x Time"));
+                self.spend_time(3, log);
+            }
+            Effect::Flames => {
+                log.push(String::from("Flames: take 1 x Health"));
+                self.take_damage(1, log);
+            }
+            Effect::Drain => {
+                if let Some(item) = self.hero.items.pop() {
+                    log.push(format!("Drain: {} was turned into 1 XP", item.name));
+                    self.hero.xp += 1;
+                }
+            }
+            Effect::Ethereal => {
+                if let Some(state) = self.encounter.as_mut() {
+                    let before = state.pool.len();
+                    state.pool.retain(|dice| dice.value != 1 && dice.value != 3);
+                    log.push(format!(
+                        "Ethereal: discarded {} dice showing 1 or 3",
+                        before - state.pool.len()
+                    ));
+                }
+            }
+            Effect::Split => {
+                let ones = self.encounter.as_ref().map_or(0, |state| {
+                    state.pool.iter().filter(|dice| dice.value == 1).count()
+                });
+                if ones > 0 {
+                    log.push(format!("Split: spend {} x Time for the 1s rolled", ones));
+                    self.spend_time(ones, log);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn encounter_mut(&mut self) -> Result<&mut EncounterState> {
+        self.encounter
+            .as_mut()
+            .ok_or(GameError::InvalidAction(String::from(
+                "there is no encounter in progress",
+            )))
+    }
+
+    fn place_dice(
+        &mut self,
+        dice: usize,
+        challenge_box: usize,
+        log: &mut Vec<String>,
+    ) -> Result<()> {
+        let state = self.encounter_mut()?;
+        let placed = state.pool.get(dice).ok_or(GameError::DiceNotFound(dice))?;
+        let target = state
+            .boxes
+            .get(challenge_box)
+            .ok_or(GameError::ChallengeBoxNotFound(challenge_box))?;
+        if !target.challenge.priority && state.priority_outstanding() {
+            return Err(GameError::InvalidAction(String::from(
+                "priority boxes have to be filled first",
+            )));
+        }
+        if !target.accepts(placed) {
+            return Err(GameError::InvalidAction(format!(
+                "{} does not fit in box {}",
+                placed,
+                challenge_box + 1
+            )));
+        }
+        let placed = state.pool.remove(dice);
+        log.push(format!("Placed {} in box {}", placed, challenge_box + 1));
+        state.boxes[challenge_box].dice.push(placed);
+        Ok(())
+    }
+
+    fn make_heroic(&mut self, mut dice: Vec<usize>, log: &mut Vec<String>) -> Result<()> {
+        let state = self.encounter_mut()?;
+        dice.sort_unstable();
+        dice.dedup();
+        if dice.len() != state.heroic_cost() {
+            return Err(GameError::InvalidAction(format!(
+                "a heroic dice needs {} dice",
+                state.heroic_cost()
+            )));
+        }
+        if let Some(missing) = dice.iter().find(|index| **index >= state.pool.len()) {
+            return Err(GameError::DiceNotFound(*missing));
+        }
+        let value = dice
+            .iter()
+            .map(|index| state.pool[*index].value)
+            .min()
+            .unwrap_or(1);
+        for index in dice.iter().rev() {
+            state.pool.remove(*index);
+        }
+        let heroic = Dice {
+            dice_type: DiceColour::Heroic,
+            value,
+        };
+        log.push(format!("Combined dice into {}", heroic));
+        state.pool.push(heroic);
+        Ok(())
+    }
+
+    /// The skills on offer in an encounter and the hero each belongs to: the
+    /// active hero's, then the partner's in a co-op game.
+    /// [`Action::UseSkill`] counts through this list.
+    pub fn encounter_skills(&self) -> Vec<(&Hero, &Skill)> {
+        let mut skills: Vec<(&Hero, &Skill)> = self
+            .hero
+            .skills
+            .iter()
+            .map(|skill| (&self.hero, skill))
+            .collect();
+        if let Some(partner) = self.partner.as_ref() {
+            skills.extend(
+                partner
+                    .hero
+                    .skills
+                    .iter()
+                    .map(|skill| (&partner.hero, skill)),
+            );
+        }
+        skills
+    }
+
+    /// A partner's skill is used as though it were their turn, so whatever it
+    /// costs or heals lands on them.
+    fn use_skill(
+        &mut self,
+        skill_index: usize,
+        target: Option<usize>,
+        log: &mut Vec<String>,
+    ) -> Result<()> {
+        let own_skills = self.hero.skills.len();
+        if self.partner.is_none() || skill_index < own_skills {
+            return self.use_own_skill(skill_index, skill_index, target, log);
+        }
+        self.swap_heroes();
+        let result = self.use_own_skill(skill_index - own_skills, skill_index, target, log);
+        self.swap_heroes();
+        result
+    }
+
+    /// Uses the active hero's skill `own_index`, which the encounter knows as
+    /// `skill_index`.
+    fn use_own_skill(
+        &mut self,
+        own_index: usize,
+        skill_index: usize,
+        target: Option<usize>,
+        log: &mut Vec<String>,
+    ) -> Result<()> {
+        let skill = self
+            .hero
+            .skills
+            .get(own_index)
+            .cloned()
+            .ok_or(GameError::InvalidAction(format!(
+                "there is no skill {}",
+                skill_index + 1
+            )))?;
+        let state = self
+            .encounter
+            .as_ref()
+            .ok_or(GameError::InvalidAction(String::from(
+                "there is no encounter in progress",
+            )))?;
+        if !skill.encounters.contains(&state.encounter) {
+            return Err(GameError::InvalidAction(format!(
+                "{} cannot be used in a {} encounter",
+                skill.name, state.encounter
+            )));
+        }
+        if state.used_skills.contains(&skill_index) {
+            return Err(GameError::InvalidAction(format!(
+                "{} has already been used",
+                skill.name
+            )));
+        }
+        if !self.can_pay(skill.requirements.as_ref()) {
+            return Err(GameError::InvalidAction(format!(
+                "the requirements for {} are not met",
+                skill.name
+            )));
+        }
+        if let Some(dice) = target {
+            let aimable = keeps_pool(&skill)
+                && state
+                    .pool
+                    .get(dice)
+                    .is_some_and(|rolled| targetable(&skill.effect, rolled));
+            if !aimable {
+                return Err(GameError::InvalidAction(format!(
+                    "{} cannot be aimed at dice {}",
+                    skill.name,
+                    dice + 1
+                )));
+            }
+        }
+        self.pay(skill.requirements.as_ref(), log);
+        let fade = self
+            .encounter
+            .as_ref()
+            .is_some_and(|state| state.special_ability == Effect::Fade);
+        if let Some(state) = self.encounter.as_mut() {
+            state.used_skills.push(skill_index);
+        }
+        log.push(format!("Used {}", skill.name));
+        self.apply_effect(&skill.effect, target, log);
+        if fade {
+            log.push(String::from("Fade: spend 1 x Time"));
+            self.spend_time(1, log);
+        }
+        Ok(())
+    }
+
+    /// Checks whether a skill requirement can be paid from the pool or the hero's resources.
+    pub fn can_pay(&self, requirement: Option<&Attribute>) -> bool {
+        self.can_pay_from(&self.hero, requirement)
+    }
+
+    /// Like [`Game::can_pay`], with resources coming from `hero`.
+    fn can_pay_from(&self, hero: &Hero, requirement: Option<&Attribute>) -> bool {
+        let pool = self
+            .encounter
+            .as_ref()
+            .map(|state| state.pool.as_slice())
+            .unwrap_or(&[]);
+        match requirement {
+            None => true,
+            Some(Attribute::DiceCount(colour, quantity)) => {
+                pool.iter().filter(|dice| &dice.dice_type == colour).count() >= *quantity
+            }
+            Some(Attribute::DieOfValue(colour, value)) => pool
+                .iter()
+                .any(|dice| &dice.dice_type == colour && dice.value >= *value),
+            Some(Attribute::Resource(Resource::Potion, quantity)) => {
+                hero.potions >= *quantity as i8
+            }
+            Some(Attribute::Resource(_, _)) => true,
+        }
+    }
+
+    fn pay(&mut self, requirement: Option<&Attribute>, log: &mut Vec<String>) {
+        match requirement {
+            None => {}
+            Some(Attribute::DiceCount(colour, quantity)) => {
+                if let Some(state) = self.encounter.as_mut() {
+                    for _ in 0..*quantity {
+                        if let Some(index) =
+                            lowest_matching(&state.pool, |dice| &dice.dice_type == colour)
+                        {
+                            state.pool.remove(index);
+                        }
+                    }
+                }
+            }
+            Some(Attribute::DieOfValue(colour, value)) => {
+                if let Some(state) = self.encounter.as_mut() {
+                    if let Some(index) = lowest_matching(&state.pool, |dice| {
+                        &dice.dice_type == colour && dice.value >= *value
+                    }) {
+                        state.pool.remove(index);
+                    }
+                }
+            }
+            Some(Attribute::Resource(Resource::Potion, quantity)) => {
+                self.hero.potions -= *quantity as i8;
+            }
+            Some(Attribute::Resource(Resource::Time, quantity)) => self.spend_time(*quantity, log),
+            Some(Attribute::Resource(Resource::Health, quantity)) => {
+                self.take_damage(*quantity, log)
+            }
+            Some(Attribute::Resource(Resource::Door, _)) => {}
+        }
+    }
+
+    fn apply_effect(&mut self, effect: &Effect, target: Option<usize>, log: &mut Vec<String>) {
+        match effect {
+            Effect::Gain(attributes) | Effect::Roll(attributes) => {
+                for attribute in attributes {
+                    self.gain(attribute, log);
+                }
+            }
+            Effect::Increase(by) => {
+                if let Some(dice) = self.target_dice(target, |dice| targetable(effect, dice)) {
+                    dice.value = (dice.value + *by as i8).min(6);
+                    let dice = dice.to_string();
+                    log.push(format!("Increased a dice to {}", dice));
+                }
+            }
+            Effect::Reroll(AttributeType::Value(values)) => {
+                if let Some(state) = self.encounter.as_mut() {
+                    for dice in state.pool.iter_mut() {
+                        if values.contains(&dice.value) {
+                            dice.value = self.rng.gen_range(1..=6);
+                        }
+                    }
+                    log.push(String::from("Rerolled matching dice"));
+                }
+            }
+            Effect::Reroll(_) => {
+                let value = self.rng.gen_range(1..=6);
+                if let Some(dice) = self.target_dice(target, |dice| targetable(effect, dice)) {
+                    dice.value = value;
+                    let dice = dice.to_string();
+                    log.push(format!("Rerolled a dice to {}", dice));
+                }
+            }
+            Effect::Change { value, .. } => {
+                if let Some(dice) = self.target_dice(target, |dice| targetable(effect, dice)) {
+                    dice.value = *value as i8;
+                    let dice = dice.to_string();
+                    log.push(format!("Changed a dice to {}", dice));
+                }
+            }
+            Effect::Discard(attribute_type) => {
+                if let Some(state) = self.encounter.as_mut() {
+                    let index = match target {
+                        Some(index) if index < state.pool.len() => Some(index),
+                        _ => lowest_matching(&state.pool, |dice| {
+                            AttributeType::from(dice.dice_type) == *attribute_type
+                        }),
+                    };
+                    if let Some(index) = index {
+                        state.pool.remove(index);
+                    }
+                }
+            }
+            Effect::Prevent(resource) => {
+                if let Some(state) = self.encounter.as_mut() {
+                    state.prevented.push(*resource);
+                    log.push(format!("Will prevent 1 x {}", resource));
+                }
+            }
+            Effect::Heal(amount) => {
+                let healed = (*amount).min(self.damage);
+                self.damage -= healed;
+                log.push(format!("Healed {} damage", healed));
+            }
+            Effect::Skip(cost) => {
+                self.pay(Some(cost), log);
+                log.push(String::from("Skipped to the Claim Loot phase"));
+                self.encounter = None;
+                self.enter_loot(log);
+            }
+            _ => log.push(format!("{} has no automatic effect", effect)),
+        }
+    }
+
+    fn gain(&mut self, attribute: &Attribute, log: &mut Vec<String>) {
+        match attribute {
+            Attribute::DiceCount(colour, quantity) => {
+                for _ in 0..*quantity {
+                    let dice = Dice::roll(*colour, &mut self.rng);
+                    log.push(format!("Added {} to the pool", dice));
+                    if let Some(state) = self.encounter.as_mut() {
+                        state.pool.push(dice);
+                    }
+                }
+            }
+            Attribute::DieOfValue(colour, value) => {
+                let dice = Dice {
+                    dice_type: *colour,
+                    value: *value,
+                };
+                log.push(format!("Added {} to the pool", dice));
+                if let Some(state) = self.encounter.as_mut() {
+                    state.pool.push(dice);
+                }
+            }
+            Attribute::Resource(Resource::Health, quantity) => {
+                let healed = (*quantity).min(self.damage);
+                self.damage -= healed;
+            }
+            Attribute::Resource(Resource::Potion, quantity) => {
+                self.hero.potions += *quantity as i8;
+            }
+            Attribute::Resource(_, _) => {}
+        }
+    }
+
+    fn target_dice(
+        &mut self,
+        target: Option<usize>,
+        predicate: impl Fn(&Dice) -> bool,
+    ) -> Option<&mut Dice> {
+        let state = self.encounter.as_mut()?;
+        let index = match target {
+            Some(index) if state.pool.get(index).is_some_and(&predicate) => index,
+            Some(_) => return None,
+            None => lowest_matching(&state.pool, &predicate)?,
+        };
+        state.pool.get_mut(index)
+    }
+
+    fn finish_encounter(&mut self, log: &mut Vec<String>) {
+        let Some(mut state) = self.encounter.take() else {
+            return;
+        };
+        let mut health = 0;
+        let mut time = 0;
+        let mut empty_boxes = 0;
+        for placed in state.boxes.iter().filter(|placed| !placed.is_complete()) {
+            empty_boxes += 1;
+            for consequence in placed.challenge.consequences.iter() {
+                match consequence {
+                    Attribute::Resource(Resource::Health, quantity) => health += quantity,
+                    Attribute::Resource(Resource::Time, quantity) => time += quantity,
+                    _ => {}
+                }
+            }
+        }
+        if state.special_ability == Effect::Undying && empty_boxes > 0 {
+            log.push(String::from("Undying: spend 2 x Time"));
+            time += 2;
+        }
+        for resource in state.prevented.drain(..) {
+            match resource {
+                Resource::Health if health > 0 => health -= 1,
+                Resource::Time if time > 0 => time -= 1,
+                _ => {}
+            }
+        }
+        log.push(format!(
+            "{} finished: lose {} x Health and {} x Time",
+            state.name, health, time
+        ));
+        self.take_damage(health, log);
+        self.spend_time(time, log);
+        if let Some(fallen) = self.fallen() {
+            log.push(format!("{} has fallen to {}", fallen, state.name));
+            self.phase = Phase::Lost(state.name);
+            return;
+        }
+
+        if state.encounter == Encounter::Boss {
+            let hits = state
+                .boxes
+                .iter()
+                .filter(|placed| placed.is_complete())
+                .count();
+            self.boss_damage += hits;
+            log.push(format!(
+                "The {} takes {} damage ({}/{})",
+                state.name, hits, self.boss_damage, self.dungeon.boss.health
+            ));
+            if self.boss_damage >= self.dungeon.boss.health {
+                log.push(format!("The {} is defeated!", state.name));
+                self.phase = Phase::Won;
+            } else {
+                self.pass_turn(log);
+                self.start_boss_round(log);
+            }
+            return;
+        }
+
+        let survivor_failed = state.special_ability == Effect::Survivor
+            && state
+                .boxes
+                .iter()
+                .any(|placed| placed.challenge.priority && !placed.is_complete());
+        if survivor_failed {
+            log.push(format!("Survivor: {} escapes without loot", state.name));
+            if let Some(door) = self.fighting_door.take() {
+                let door = self.doors.remove(door);
+                self.discard.push(door.card);
+            }
+            self.phase = Phase::Exploring;
+            self.pass_turn(log);
+            return;
+        }
+        self.enter_loot(log);
+    }
+
+    fn enter_loot(&mut self, log: &mut Vec<String>) {
+        if let Some(door) = self.fighting_door.take() {
+            let door = self.doors.remove(door);
+            log.push(format!("Claim loot from {}", door.card.name()));
+            self.loot = Some(door.card);
+            self.phase = Phase::Loot;
+        } else {
+            self.phase = Phase::Exploring;
+        }
+    }
+
+    /// Whether a loot choice fits under the caps for the hero's current level.
+    pub fn can_loot(&self, choice: LootChoice) -> bool {
+        let Some(card) = self.loot.as_ref() else {
+            return false;
+        };
+        let stats = self.hero.level_stats();
+        match choice {
+            LootChoice::Item => {
+                stats.is_some_and(|stats| self.hero.items.len() < stats.items as usize)
+            }
+            LootChoice::Skill => {
+                stats.is_some_and(|stats| self.hero.skills.len() < stats.skills as usize)
+                    && !self
+                        .hero
+                        .skills
+                        .iter()
+                        .any(|skill| skill.name == card.skill_reward().name)
+            }
+            LootChoice::Xp => true,
+        }
+    }
+
+    fn claim_loot(&mut self, choice: LootChoice, log: &mut Vec<String>) -> Result<()> {
+        if !self.can_loot(choice) {
+            return Err(GameError::InvalidAction(format!(
+                "cannot take this card as {}",
+                choice
+            )));
+        }
+        let Some(card) = self.loot.take() else {
+            return Err(GameError::InvalidAction(String::from("there is no loot")));
+        };
+        let result = match choice {
+            LootChoice::Item => self.hero.add_item(Item {
+                name: card.name().to_string(),
+                attributes: card.item_reward().clone(),
+            }),
+            LootChoice::Skill => self.hero.add_skill(card.skill_reward().clone()),
+            LootChoice::Xp => {
+                self.hero.xp += card.xp_reward();
+                Ok(())
+            }
+        };
+        if let Err(err) = result {
+            self.loot = Some(card);
+            return Err(GameError::InvalidAction(err.to_string()));
+        }
+        log.push(format!("Took {} as {}", card.name(), choice));
+        self.discard.push(card);
+        self.phase = Phase::Exploring;
+        self.pass_turn(log);
+        Ok(())
+    }
+
+    /// Spending time discards cards from the deck; once it is empty, time costs health.
+    fn spend_time(&mut self, amount: usize, log: &mut Vec<String>) {
+        let mut overflow = 0;
+        for _ in 0..amount {
+            match self.deck.pop() {
+                Some(card) => self.discard.push(card),
+                None => overflow += 1,
+            }
+        }
+        if overflow > 0 {
+            log.push(format!("Out of time: take {} x Health", overflow));
+            self.take_damage(overflow, log);
+        }
+    }
+
+    fn take_damage(&mut self, amount: usize, log: &mut Vec<String>) {
+        if amount == 0 {
+            return;
+        }
+        self.damage += amount;
+        let remaining = self.remaining_health();
+        match self.partner {
+            None => log.push(format!(
+                "Took {} damage ({} health left)",
+                amount, remaining
+            )),
+            Some(_) => log.push(format!(
+                "{} took {} damage ({} health left)",
+                self.hero.name, amount, remaining
+            )),
+        }
+    }
+
+    /// Hands the turn to the partner in a co-op game.
+    fn pass_turn(&mut self, log: &mut Vec<String>) {
+        if self.partner.is_some() {
+            self.swap_heroes();
+            log.push(format!("{}'s turn", self.hero.name));
+        }
+    }
+
+    /// Swaps the active hero and their damage with the partner's.
+    fn swap_heroes(&mut self) {
+        if let Some(partner) = self.partner.as_mut() {
+            std::mem::swap(&mut self.hero, &mut partner.hero);
+            std::mem::swap(&mut self.damage, &mut partner.damage);
+        }
+    }
+
+    /// The name of a hero who has run out of health, if any has.
+    fn fallen(&self) -> Option<&str> {
+        let dead = |hero: &Hero, damage: usize| damage > 0 && damage >= hero.health;
+        if dead(&self.hero, self.damage) {
+            return Some(&self.hero.name);
+        }
+        self.partner
+            .as_ref()
+            .filter(|partner| dead(&partner.hero, partner.damage))
+            .map(|partner| partner.hero.name.as_str())
+    }
+
+    fn check_death(&mut self, log: &mut Vec<String>) {
+        if self.is_over() {
+            return;
+        }
+        let Some(fallen) = self.fallen().map(str::to_string) else {
+            return;
+        };
+        let cause = match self.encounter.as_ref() {
+            Some(state) => state.name.clone(),
+            None => match self.loot.as_ref() {
+                Some(card) => card.name().to_string(),
+                None => String::from("Out of time"),
+            },
+        };
+        log.push(format!("{} has fallen to {}", fallen, cause));
+        self.phase = Phase::Lost(cause);
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum HeroSlot {
+    Active,
+    Partner,
+}
+
+/// The dice `hero` rolls at the start of an encounter, heroic bonus dice last.
+fn dice_to_roll(hero: &Hero) -> Vec<DiceColour> {
+    let mut rolls = Vec::new();
+    for colour in [DiceColour::Strength, DiceColour::Agility, DiceColour::Magic] {
+        rolls.extend(std::iter::repeat_n(colour, hero.dice_count(&colour)));
+    }
+    rolls.extend(std::iter::repeat_n(
+        DiceColour::Heroic,
+        hero.encounter_bonus.max(0) as usize,
+    ));
+    rolls
+}
+
+fn lowest_matching(pool: &[Dice], predicate: impl Fn(&Dice) -> bool) -> Option<usize> {
+    pool.iter()
+        .enumerate()
+        .filter(|(_, dice)| predicate(dice))
+        .min_by_key(|(_, dice)| dice.value)
+        .map(|(index, _)| index)
+}
+
+/// Whether the dice a skill is aimed at is still in the pool once its
+/// requirement is paid; requirements that spend dice could take it.
+fn keeps_pool(skill: &Skill) -> bool {
+    !matches!(
+        skill.requirements,
+        Some(Attribute::DiceCount(_, _) | Attribute::DieOfValue(_, _))
+    )
+}
+
+/// Whether a skill with `effect` can be aimed at `dice`. Effects that do not
+/// pick out a single dice never can.
+fn targetable(effect: &Effect, dice: &Dice) -> bool {
+    match effect {
+        Effect::Increase(_) => true,
+        Effect::Reroll(AttributeType::Value(_)) => false,
+        Effect::Reroll(attribute_type) => {
+            attribute_type == &AttributeType::Default
+                || AttributeType::from(dice.dice_type) == *attribute_type
+        }
+        Effect::Change { attribute_type, .. } => {
+            if attribute_type == &AttributeType::Default {
+                dice.dice_type != DiceColour::Heroic
+            } else {
+                AttributeType::from(dice.dice_type) == *attribute_type
+            }
+        }
+        Effect::Discard(attribute_type) => AttributeType::from(dice.dice_type) == *attribute_type,
+        _ => false,
+    }
+}
+
+/// Every way of picking `size` indices out of `0..count`, in increasing order.
+fn combinations(count: usize, size: usize) -> Vec<Vec<usize>> {
+    let mut result = Vec::new();
+    let mut current = Vec::with_capacity(size);
+    collect_combinations(count, size, 0, &mut current, &mut result);
+    result
+}
+
+fn collect_combinations(
+    count: usize,
+    size: usize,
+    start: usize,
+    current: &mut Vec<usize>,
+    result: &mut Vec<Vec<usize>>,
+) {
+    if current.len() == size {
+        result.push(current.clone());
+        return;
+    }
+    for index in start..count {
+        current.push(index);
+        collect_combinations(count, size, index + 1, current, result);
+        current.pop();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hero::PlayerCount;
+
+    fn co_op_game(seed: u64) -> Game {
+        let mut heroes = game_setup::get_all_heroes(PlayerCount::Coop);
+        let partner = heroes.remove(1);
+        let hero = heroes.remove(0);
+        let dungeon = game_setup::get_all_dungeons().remove(0);
+        Game::co_op(hero, partner, dungeon, seed, Difficulty::default())
+    }
+
+    /// Actions worth trying in `game`, legal or not: everything without an
+    /// index, and every index up to one past what is there.
+    fn action_samples(game: &Game) -> Vec<Action> {
+        let mut actions = vec![
+            Action::Explore,
+            Action::Fight,
+            Action::Flee,
+            Action::FinishEncounter,
+            Action::DrinkPotion,
+            Action::Descend,
+            Action::Loot(LootChoice::Item),
+            Action::Loot(LootChoice::Skill),
+            Action::Loot(LootChoice::Xp),
+        ];
+        actions.extend((0..=MAX_DOORS).map(Action::OpenDoor));
+        actions.extend((0..=2).map(Action::ChoosePeril));
+        let (pool, boxes) = game
+            .encounter
+            .as_ref()
+            .map_or((0, 0), |state| (state.pool.len(), state.boxes.len()));
+        for dice in 0..=pool {
+            actions.extend((0..=boxes).map(|challenge_box| Action::PlaceDice {
+                dice,
+                challenge_box,
+            }));
+            actions.push(Action::MakeHeroic(vec![dice]));
+            actions.push(Action::MakeHeroic(vec![dice, dice + 1]));
+            actions.push(Action::MakeHeroic(vec![dice, dice + 1, dice + 2]));
+        }
+        for skill in 0..=game.encounter_skills().len() {
+            actions.push(Action::UseSkill { skill, dice: None });
+            actions.extend((0..=pool).map(|dice| Action::UseSkill {
+                skill,
+                dice: Some(dice),
+            }));
+        }
+        actions
+    }
+
+    fn observed(game: &Game) -> serde_json::Value {
+        serde_json::to_value(game.observe()).unwrap()
+    }
+
+    #[test]
+    fn legal_actions_are_exactly_the_accepted_ones() {
+        let heroes = game_setup::get_all_heroes(PlayerCount::Solo);
+        let dungeons = game_setup::get_all_dungeons();
+        for seed in 0..8u64 {
+            let hero = heroes[seed as usize % heroes.len()].clone();
+            let dungeon = dungeons[seed as usize % dungeons.len()].clone();
+            let mut game = if seed % 2 == 0 {
+                Game::new(hero, dungeon, seed)
+            } else {
+                co_op_game(seed)
+            };
+            let mut rng = StdRng::seed_from_u64(seed);
+            for _ in 0..300 {
+                if game.is_over() {
+                    break;
+                }
+                assert_eq!(observed(&game.determinize(seed)), observed(&game));
+
+                let legal = game.legal_actions();
+                assert!(!legal.is_empty(), "stuck in {:?}", game.phase);
+                for action in legal.iter() {
+                    let result = game.clone().apply(action.clone());
+                    assert!(result.is_ok(), "{} was refused: {:?}", action, result);
+                }
+                for action in action_samples(&game) {
+                    if !legal.contains(&action) {
+                        let result = game.clone().apply(action.clone());
+                        assert!(
+                            result.is_err(),
+                            "{} is not legal in {:?}",
+                            action,
+                            game.phase
+                        );
+                    }
+                }
+                let action = legal.choose(&mut rng).unwrap().clone();
+                game.apply(action).unwrap();
+            }
+        }
+    }
+
+    #[test]
+    fn turns_count_doors_not_actions() {
+        let mut game = co_op_game(7);
+        game.apply(Action::Explore).unwrap();
+        game.apply(Action::OpenDoor(0)).unwrap();
+        assert_eq!(game.turns, 0);
+        game.apply(Action::Flee).unwrap();
+        assert_eq!(game.turns, 1);
+        game.apply(Action::OpenDoor(0)).unwrap();
+        game.apply(Action::Fight).unwrap();
+        if game.phase != Phase::Encounter {
+            game.apply(Action::ChoosePeril(0)).unwrap();
+        }
+        assert_eq!(game.turns, 2);
+        game.apply(Action::FinishEncounter).unwrap();
+        assert_eq!(game.turns, 2);
+    }
+
+    #[test]
+    fn co_op_damage_falls_on_the_active_hero() {
+        let mut game = co_op_game(7);
+        game.take_damage(2, &mut Vec::new());
+        assert_eq!(game.damage, 2);
+        assert_eq!(game.partner.as_ref().unwrap().damage, 0);
+    }
+
+    #[test]
+    fn co_op_partner_drinks_when_the_active_hero_cannot() {
+        let mut game = co_op_game(7);
+        game.partner.as_mut().unwrap().damage = 2;
+        let potions = game.partner.as_ref().unwrap().hero.potions;
+        assert!(game.legal_actions().contains(&Action::DrinkPotion));
+        game.apply(Action::DrinkPotion).unwrap();
+        let partner = game.partner.as_ref().unwrap();
+        assert_eq!(partner.hero.potions, potions - 1);
+        assert!(partner.damage < 2);
+        assert_eq!(game.damage, 0);
+    }
+
+    #[test]
+    fn co_op_partner_skills_can_be_used() {
+        let mut game = co_op_game(7);
+        let partner = &mut game.partner.as_mut().unwrap().hero;
+        partner.potions = 2;
+        partner.skills = vec![Skill {
+            name: String::from("Second Wind"),
+            description: None,
+            requirements: Some(Attribute::Resource(Resource::Potion, 1)),
+            effect: Effect::Prevent(Resource::Health),
+            encounters: vec![Encounter::Combat],
+        }];
+        game.start_encounter(
+            Encounter::Combat,
+            String::from("Test"),
+            Effect::None,
+            Vec::new(),
+            &mut Vec::new(),
+        );
+        let skill = game.hero.skills.len();
+        let action = Action::UseSkill { skill, dice: None };
+        assert!(game.legal_actions().contains(&action));
+        game.apply(action.clone()).unwrap();
+        // The partner pays for their own skill.
+        assert_eq!(game.partner.as_ref().unwrap().hero.potions, 1);
+        let state = game.encounter.as_ref().unwrap();
+        assert_eq!(state.used_skills, vec![skill]);
+        assert_eq!(state.prevented, vec![Resource::Health]);
+        assert!(game.apply(action).is_err());
+    }
+}
@@ -1,7 +1,7 @@
 use crate::{
     dungeon::ChallengeBox,
     encounter::{Combat, Encounter},
-    hero::{Attribute, AttributeType, Effect, Skill},
+    hero::{Attribute, AttributeType, DiceColour, Effect, Resource, Skill},
 };
 
 pub fn get_all_combats() -> Vec<Combat> {
@@ -40,28 +40,16 @@ fn get_goblin(is_option_one: bool) -> Combat {
             description: Some(String::from(
                 "Prevent 1 x HEALTH. In a boss fight prevent 2 x HEALTH.",
             )),
-            requirements: Some(Attribute {
-                attribute: AttributeType::Agility,
-                quantity: Some(1),
-                ..Default::default()
-            }),
-            effect: Effect::Prevent(AttributeType::Health),
+            requirements: Some(Attribute::DiceCount(DiceColour::Agility, 1)),
+            effect: Effect::Prevent(Resource::Health),
             encounters: vec![Encounter::Combat, Encounter::Boss],
         }
     } else {
         Skill {
             name: String::from("SHIMMERBLAST"),
             description: Some(String::from("Roll 1 x HEROIC DICE")),
-            requirements: Some(Attribute {
-                attribute: AttributeType::Magic,
-                value: Some(3),
-                ..Default::default()
-            }),
-            effect: Effect::Roll(vec![Attribute {
-                attribute: AttributeType::Heroic,
-                quantity: Some(1),
-                ..Default::default()
-            }]),
+            requirements: Some(Attribute::DieOfValue(DiceColour::Magic, 3)),
+            effect: Effect::Roll(vec![Attribute::DiceCount(DiceColour::Heroic, 1)]),
             encounters: vec![Encounter::Combat, Encounter::Boss],
         }
     };
@@ -70,70 +58,46 @@ fn get_goblin(is_option_one: bool) -> Combat {
         special_ability: Effect::Swarm,
         challenges: vec![
             ChallengeBox {
-                dice_type: Some(AttributeType::Strength),
+                dice_type: Some(DiceColour::Strength),
                 total_value: 4,
                 single_dice: false,
                 priority: true,
                 consequences: Vec::new(),
             },
             ChallengeBox {
-                dice_type: Some(AttributeType::Strength),
+                dice_type: Some(DiceColour::Strength),
                 total_value: 3,
                 single_dice: true,
                 priority: false,
-                consequences: vec![Attribute {
-                    attribute: AttributeType::Health,
-                    quantity: Some(1),
-                    ..Default::default()
-                }],
+                consequences: vec![Attribute::Resource(Resource::Health, 1)],
             },
             ChallengeBox {
-                dice_type: Some(AttributeType::Agility),
+                dice_type: Some(DiceColour::Agility),
                 total_value: 4,
                 single_dice: true,
                 priority: false,
-                consequences: vec![Attribute {
-                    attribute: AttributeType::Time,
-                    quantity: Some(1),
-                    ..Default::default()
-                }],
+                consequences: vec![Attribute::Resource(Resource::Time, 1)],
             },
             ChallengeBox {
-                dice_type: Some(AttributeType::Agility),
+                dice_type: Some(DiceColour::Agility),
                 total_value: 4,
                 single_dice: true,
                 priority: false,
                 consequences: vec![
-                    Attribute {
-                        attribute: AttributeType::Health,
-                        quantity: Some(1),
-                        ..Default::default()
-                    },
-                    Attribute {
-                        attribute: AttributeType::Time,
-                        quantity: Some(1),
-                        ..Default::default()
-                    },
+                    Attribute::Resource(Resource::Health, 1),
+                    Attribute::Resource(Resource::Time, 1),
                 ],
             },
             ChallengeBox {
-                dice_type: Some(AttributeType::Strength),
+                dice_type: Some(DiceColour::Strength),
                 total_value: 5,
                 single_dice: true,
                 priority: false,
-                consequences: vec![Attribute {
-                    attribute: AttributeType::Health,
-                    quantity: Some(2),
-                    ..Default::default()
-                }],
+                consequences: vec![Attribute::Resource(Resource::Health, 2)],
             },
         ],
         xp_reward: 2,
-        item_reward: vec![Attribute {
-            attribute: AttributeType::Strength,
-            quantity: Some(1),
-            ..Default::default()
-        }],
+        item_reward: vec![Attribute::DiceCount(DiceColour::Strength, 1)],
         skill_reward,
     }
 }
@@ -141,29 +105,13 @@ fn get_goblin(is_option_one: bool) -> Combat {
 fn get_ogre(is_option_one: bool) -> Combat {
     let item_reward = if is_option_one {
         vec![
-            Attribute {
-                attribute: AttributeType::Magic,
-                quantity: Some(1),
-                ..Default::default()
-            },
-            Attribute {
-                attribute: AttributeType::Health,
-                quantity: Some(1),
-                ..Default::default()
-            },
+            Attribute::DiceCount(DiceColour::Magic, 1),
+            Attribute::Resource(Resource::Health, 1),
         ]
     } else {
         vec![
-            Attribute {
-                attribute: AttributeType::Strength,
-                quantity: Some(1),
-                ..Default::default()
-            },
-            Attribute {
-                attribute: AttributeType::Health,
-                quantity: Some(1),
-                ..Default::default()
-            },
+            Attribute::DiceCount(DiceColour::Strength, 1),
+            Attribute::Resource(Resource::Health, 1),
         ]
     };
     let skill_reward = if is_option_one {
@@ -173,11 +121,7 @@ fn get_ogre(is_option_one: bool) -> Combat {
                 "Discard any number of value 5 dice. Gain that many value 6 STRENGTH dice.",
             )),
             requirements: None,
-            effect: Effect::Gain(vec![Attribute {
-                attribute: AttributeType::Strength,
-                value: Some(6),
-                ..Default::default()
-            }]),
+            effect: Effect::Gain(vec![Attribute::DieOfValue(DiceColour::Strength, 6)]),
             encounters: vec![Encounter::Combat, Encounter::Peril, Encounter::Boss],
         }
     } else {
@@ -186,11 +130,7 @@ fn get_ogre(is_option_one: bool) -> Combat {
             description: Some(String::from(
                 "Pick a value. Change up to five of your dice of that value to sixes.",
             )),
-            requirements: Some(Attribute {
-                attribute: AttributeType::Magic,
-                value: Some(6),
-                quantity: Some(1),
-            }),
+            requirements: Some(Attribute::DieOfValue(DiceColour::Magic, 6)),
             effect: Effect::Change {
                 attribute_type: AttributeType::Default,
                 value: 6,
@@ -203,83 +143,51 @@ fn get_ogre(is_option_one: bool) -> Combat {
         special_ability: Effect::None,
         challenges: vec![
             ChallengeBox {
-                dice_type: Some(AttributeType::Strength),
+                dice_type: Some(DiceColour::Strength),
                 total_value: 6,
                 single_dice: false,
                 priority: false,
-                consequences: vec![Attribute {
-                    attribute: AttributeType::Health,
-                    quantity: Some(1),
-                    ..Default::default()
-                }],
+                consequences: vec![Attribute::Resource(Resource::Health, 1)],
             },
             ChallengeBox {
-                dice_type: Some(AttributeType::Agility),
+                dice_type: Some(DiceColour::Agility),
                 total_value: 4,
                 single_dice: true,
                 priority: false,
-                consequences: vec![Attribute {
-                    attribute: AttributeType::Time,
-                    quantity: Some(1),
-                    ..Default::default()
-                }],
+                consequences: vec![Attribute::Resource(Resource::Time, 1)],
             },
             ChallengeBox {
-                dice_type: Some(AttributeType::Strength),
+                dice_type: Some(DiceColour::Strength),
                 total_value: 9,
                 single_dice: false,
                 priority: false,
-                consequences: vec![Attribute {
-                    attribute: AttributeType::Health,
-                    quantity: Some(2),
-                    ..Default::default()
-                }],
+                consequences: vec![Attribute::Resource(Resource::Health, 2)],
             },
             ChallengeBox {
-                dice_type: Some(AttributeType::Agility),
+                dice_type: Some(DiceColour::Agility),
                 total_value: 5,
                 single_dice: true,
                 priority: false,
                 consequences: vec![
-                    Attribute {
-                        attribute: AttributeType::Health,
-                        quantity: Some(1),
-                        ..Default::default()
-                    },
-                    Attribute {
-                        attribute: AttributeType::Time,
-                        quantity: Some(1),
-                        ..Default::default()
-                    },
+                    Attribute::Resource(Resource::Health, 1),
+                    Attribute::Resource(Resource::Time, 1),
                 ],
             },
             ChallengeBox {
-                dice_type: Some(AttributeType::Strength),
+                dice_type: Some(DiceColour::Strength),
                 total_value: 12,
                 single_dice: false,
                 priority: false,
-                consequences: vec![Attribute {
-                    attribute: AttributeType::Health,
-                    quantity: Some(3),
-                    ..Default::default()
-                }],
+                consequences: vec![Attribute::Resource(Resource::Health, 3)],
             },
             ChallengeBox {
-                dice_type: Some(AttributeType::Agility),
+                dice_type: Some(DiceColour::Agility),
                 total_value: 6,
                 single_dice: true,
                 priority: false,
                 consequences: vec![
-                    Attribute {
-                        attribute: AttributeType::Health,
-                        quantity: Some(1),
-                        ..Default::default()
-                    },
-                    Attribute {
-                        attribute: AttributeType::Time,
-                        quantity: Some(1),
-                        ..Default::default()
-                    },
+                    Attribute::Resource(Resource::Health, 1),
+                    Attribute::Resource(Resource::Time, 1),
                 ],
             },
         ],
@@ -294,32 +202,16 @@ fn get_beetle(is_option_one: bool) -> Combat {
         Skill {
             name: String::from("CRUSHING BLOW"),
             description: Some(String::from("Gain a STRENGTH value 6")),
-            requirements: Some(Attribute {
-                attribute: AttributeType::Strength,
-                quantity: Some(1),
-                ..Default::default()
-            }),
-            effect: Effect::Gain(vec![Attribute {
-                attribute: AttributeType::Strength,
-                quantity: Some(1),
-                value: Some(6),
-            }]),
+            requirements: Some(Attribute::DiceCount(DiceColour::Strength, 1)),
+            effect: Effect::Gain(vec![Attribute::DieOfValue(DiceColour::Strength, 6)]),
             encounters: vec![Encounter::Combat, Encounter::Boss],
         }
     } else {
         Skill {
             name: String::from("ACCURACY"),
             description: Some(String::from("Gain a HEROIC DICE 6")),
-            requirements: Some(Attribute {
-                attribute: AttributeType::Agility,
-                quantity: Some(3),
-                ..Default::default()
-            }),
-            effect: Effect::Gain(vec![Attribute {
-                attribute: AttributeType::Heroic,
-                value: Some(6),
-                quantity: Some(1),
-            }]),
+            requirements: Some(Attribute::DiceCount(DiceColour::Agility, 3)),
+            effect: Effect::Gain(vec![Attribute::DieOfValue(DiceColour::Heroic, 6)]),
             encounters: vec![Encounter::Combat, Encounter::Peril, Encounter::Boss],
         }
     };
@@ -328,69 +220,53 @@ fn get_beetle(is_option_one: bool) -> Combat {
         special_ability: Effect::Survivor,
         challenges: vec![
             ChallengeBox {
-                dice_type: Some(AttributeType::Agility),
+                dice_type: Some(DiceColour::Agility),
                 total_value: 3,
                 single_dice: true,
                 priority: true,
                 consequences: Vec::new(),
             },
             ChallengeBox {
-                dice_type: Some(AttributeType::Agility),
+                dice_type: Some(DiceColour::Agility),
                 total_value: 4,
                 single_dice: true,
                 priority: true,
                 consequences: Vec::new(),
             },
             ChallengeBox {
-                dice_type: Some(AttributeType::Strength),
+                dice_type: Some(DiceColour::Strength),
                 total_value: 5,
                 single_dice: true,
                 priority: true,
                 consequences: Vec::new(),
             },
             ChallengeBox {
-                dice_type: Some(AttributeType::Magic),
+                dice_type: Some(DiceColour::Magic),
                 total_value: 3,
                 single_dice: true,
                 priority: true,
                 consequences: Vec::new(),
             },
             ChallengeBox {
-                dice_type: Some(AttributeType::Agility),
+                dice_type: Some(DiceColour::Agility),
                 total_value: 4,
                 single_dice: true,
                 priority: false,
-                consequences: vec![Attribute {
-                    attribute: AttributeType::Time,
-                    quantity: Some(2),
-                    ..Default::default()
-                }],
+                consequences: vec![Attribute::Resource(Resource::Time, 2)],
             },
             ChallengeBox {
-                dice_type: Some(AttributeType::Strength),
+                dice_type: Some(DiceColour::Strength),
                 total_value: 6,
                 single_dice: true,
                 priority: false,
                 consequences: vec![
-                    Attribute {
-                        attribute: AttributeType::Time,
-                        quantity: Some(1),
-                        ..Default::default()
-                    },
-                    Attribute {
-                        attribute: AttributeType::Health,
-                        quantity: Some(1),
-                        ..Default::default()
-                    },
+                    Attribute::Resource(Resource::Time, 1),
+                    Attribute::Resource(Resource::Health, 1),
                 ],
             },
         ],
         xp_reward: 2,
-        item_reward: vec![Attribute {
-            attribute: AttributeType::Magic,
-            quantity: Some(1),
-            ..Default::default()
-        }],
+        item_reward: vec![Attribute::DiceCount(DiceColour::Magic, 1)],
         skill_reward,
     }
 }
@@ -398,55 +274,23 @@ fn get_beetle(is_option_one: bool) -> Combat {
 fn get_phantom(is_option_one: bool) -> Combat {
     let item_reward = if is_option_one {
         vec![
-            Attribute {
-                attribute: AttributeType::Agility,
-                quantity: Some(1),
-                ..Default::default()
-            },
-            Attribute {
-                attribute: AttributeType::Health,
-                quantity: Some(1),
-                ..Default::default()
-            },
+            Attribute::DiceCount(DiceColour::Agility, 1),
+            Attribute::Resource(Resource::Health, 1),
         ]
     } else {
         vec![
-            Attribute {
-                attribute: AttributeType::Strength,
-                quantity: Some(1),
-                ..Default::default()
-            },
-            Attribute {
-                attribute: AttributeType::Health,
-                quantity: Some(1),
-                ..Default::default()
-            },
+            Attribute::DiceCount(DiceColour::Strength, 1),
+            Attribute::Resource(Resource::Health, 1),
         ]
     };
     let skill_reward = if is_option_one {
         Skill {
             name: String::from("TRIPLE STRIKE"),
             description: Some(String::from("Gain value 5 STRENGTH, value 5 AGILITY, and value 5 MAGIC dice. Then make one of them a 6.")),
-            requirements: Some(Attribute {
-                attribute: AttributeType::Strength,
-                quantity: Some(3),
-                ..Default::default()
-            }),
-            effect: Effect::Gain(vec![Attribute {
-                attribute: AttributeType::Strength,
-                quantity: Some(1),
-                value: Some(5),
-            },
-            Attribute {
-                attribute: AttributeType::Agility,
-                quantity: Some(1),
-                value: Some(5),
-            },
-            Attribute {
-                attribute: AttributeType::Magic,
-                quantity: Some(1),
-                value: Some(5),
-            }]),
+            requirements: Some(Attribute::DiceCount(DiceColour::Strength, 3)),
+            effect: Effect::Gain(vec![Attribute::DieOfValue(DiceColour::Strength, 5),
+            Attribute::DieOfValue(DiceColour::Agility, 5),
+            Attribute::DieOfValue(DiceColour::Magic, 5)]),
             encounters: vec![Encounter::Combat, Encounter::Boss],
         }
     } else {
@@ -454,11 +298,7 @@ fn get_phantom(is_option_one: bool) -> Combat {
             name: String::from("STEADY HANDS"),
             description: Some(String::from("Roll a HEROIC DICE.")),
             requirements: None,
-            effect: Effect::Roll(vec![Attribute {
-                attribute: AttributeType::Heroic,
-                quantity: Some(1),
-                ..Default::default()
-            }]),
+            effect: Effect::Roll(vec![Attribute::DiceCount(DiceColour::Heroic, 1)]),
             encounters: vec![Encounter::Peril, Encounter::Boss],
         }
     };
@@ -467,76 +307,52 @@ fn get_phantom(is_option_one: bool) -> Combat {
         special_ability: Effect::Ethereal,
         challenges: vec![
             ChallengeBox {
-                dice_type: Some(AttributeType::Strength),
+                dice_type: Some(DiceColour::Strength),
                 total_value: 4,
                 single_dice: true,
                 priority: true,
                 consequences: Vec::new(),
             },
             ChallengeBox {
-                dice_type: Some(AttributeType::Agility),
+                dice_type: Some(DiceColour::Agility),
                 total_value: 4,
                 single_dice: true,
                 priority: true,
                 consequences: Vec::new(),
             },
             ChallengeBox {
-                dice_type: Some(AttributeType::Agility),
+                dice_type: Some(DiceColour::Agility),
                 total_value: 5,
                 single_dice: true,
                 priority: false,
-                consequences: vec![Attribute {
-                    attribute: AttributeType::Health,
-                    quantity: Some(2),
-                    ..Default::default()
-                }],
+                consequences: vec![Attribute::Resource(Resource::Health, 2)],
             },
             ChallengeBox {
-                dice_type: Some(AttributeType::Agility),
+                dice_type: Some(DiceColour::Agility),
                 total_value: 6,
                 single_dice: true,
                 priority: false,
                 consequences: vec![
-                    Attribute {
-                        attribute: AttributeType::Health,
-                        quantity: Some(1),
-                        ..Default::default()
-                    },
-                    Attribute {
-                        attribute: AttributeType::Time,
-                        quantity: Some(1),
-                        ..Default::default()
-                    },
+                    Attribute::Resource(Resource::Health, 1),
+                    Attribute::Resource(Resource::Time, 1),
                 ],
             },
             ChallengeBox {
-                dice_type: Some(AttributeType::Strength),
+                dice_type: Some(DiceColour::Strength),
                 total_value: 5,
                 single_dice: true,
                 priority: false,
                 consequences: vec![
-                    Attribute {
-                        attribute: AttributeType::Health,
-                        quantity: Some(1),
-                        ..Default::default()
-                    },
-                    Attribute {
-                        attribute: AttributeType::Time,
-                        quantity: Some(1),
-                        ..Default::default()
-                    },
+                    Attribute::Resource(Resource::Health, 1),
+                    Attribute::Resource(Resource::Time, 1),
                 ],
             },
             ChallengeBox {
-                dice_type: Some(AttributeType::Strength),
+                dice_type: Some(DiceColour::Strength),
                 total_value: 6,
                 single_dice: true,
                 priority: false,
-                consequences: vec![Attribute {
-                    attribute: AttributeType::Health,
-                    quantity: Some(2),
-                    ..Default::default()
-                }],
+                consequences: vec![Attribute::Resource(Resource::Health, 2)],
             },
         ],
         xp_reward: 4,
@@ -547,27 +363,15 @@ fn get_phantom(is_option_one: bool) -> Combat {
 
 fn get_bandit(is_option_one: bool) -> Combat {
     let item_reward = if is_option_one {
-        vec![Attribute {
-            attribute: AttributeType::Agility,
-            quantity: Some(1),
-            ..Default::default()
-        }]
+        vec![Attribute::DiceCount(DiceColour::Agility, 1)]
     } else {
-        vec![Attribute {
-            attribute: AttributeType::Magic,
-            quantity: Some(1),
-            ..Default::default()
-        }]
+        vec![Attribute::DiceCount(DiceColour::Magic, 1)]
     };
     let skill_reward = if is_option_one {
         Skill {
             name: String::from("CLEAVE"),
             description: Some(String::from("Increase up to four of your dice by 1 each.")),
-            requirements: Some(Attribute {
-                attribute: AttributeType::Strength,
-                quantity: Some(1),
-                ..Default::default()
-            }),
+            requirements: Some(Attribute::DiceCount(DiceColour::Strength, 1)),
             effect: Effect::Increase(4),
             encounters: vec![Encounter::Combat, Encounter::Boss],
         }
@@ -575,16 +379,8 @@ fn get_bandit(is_option_one: bool) -> Combat {
         Skill {
             name: String::from("BACKSTAB"),
             description: Some(String::from("Roll 2 x STRENGTH dice.")),
-            requirements: Some(Attribute {
-                attribute: AttributeType::Agility,
-                quantity: Some(1),
-                ..Default::default()
-            }),
-            effect: Effect::Roll(vec![Attribute {
-                attribute: AttributeType::Strength,
-                quantity: Some(2),
-                ..Default::default()
-            }]),
+            requirements: Some(Attribute::DiceCount(DiceColour::Agility, 1)),
+            effect: Effect::Roll(vec![Attribute::DiceCount(DiceColour::Strength, 2)]),
             encounters: vec![Encounter::Combat, Encounter::Boss],
         }
     };
@@ -593,65 +389,41 @@ fn get_bandit(is_option_one: bool) -> Combat {
         special_ability: Effect::Dodge,
         challenges: vec![
             ChallengeBox {
-                dice_type: Some(AttributeType::Agility),
+                dice_type: Some(DiceColour::Agility),
                 total_value: 8,
                 single_dice: false,
                 priority: false,
-                consequences: vec![Attribute {
-                    attribute: AttributeType::Health,
-                    quantity: Some(2),
-                    ..Default::default()
-                }],
+                consequences: vec![Attribute::Resource(Resource::Health, 2)],
             },
             ChallengeBox {
-                dice_type: Some(AttributeType::Agility),
+                dice_type: Some(DiceColour::Agility),
                 total_value: 3,
                 single_dice: true,
                 priority: false,
-                consequences: vec![Attribute {
-                    attribute: AttributeType::Health,
-                    quantity: Some(1),
-                    ..Default::default()
-                }],
+                consequences: vec![Attribute::Resource(Resource::Health, 1)],
             },
             ChallengeBox {
-                dice_type: Some(AttributeType::Agility),
+                dice_type: Some(DiceColour::Agility),
                 total_value: 4,
                 single_dice: true,
                 priority: false,
-                consequences: vec![Attribute {
-                    attribute: AttributeType::Time,
-                    quantity: Some(1),
-                    ..Default::default()
-                }],
+                consequences: vec![Attribute::Resource(Resource::Time, 1)],
             },
             ChallengeBox {
-                dice_type: Some(AttributeType::Strength),
+                dice_type: Some(DiceColour::Strength),
                 total_value: 5,
                 single_dice: true,
                 priority: false,
-                consequences: vec![Attribute {
-                    attribute: AttributeType::Time,
-                    quantity: Some(2),
-                    ..Default::default()
-                }],
+                consequences: vec![Attribute::Resource(Resource::Time, 2)],
             },
             ChallengeBox {
-                dice_type: Some(AttributeType::Strength),
+                dice_type: Some(DiceColour::Strength),
                 total_value: 5,
                 single_dice: true,
                 priority: false,
                 consequences: vec![
-                    Attribute {
-                        attribute: AttributeType::Health,
-                        quantity: Some(1),
-                        ..Default::default()
-                    },
-                    Attribute {
-                        attribute: AttributeType::Time,
-                        quantity: Some(1),
-                        ..Default::default()
-                    },
+                    Attribute::Resource(Resource::Health, 1),
+                    Attribute::Resource(Resource::Time, 1),
                 ],
             },
         ],
@@ -664,23 +436,11 @@ fn get_bandit(is_option_one: bool) -> Combat {
 fn get_shadow(is_option_one: bool) -> Combat {
     let item_reward = if is_option_one {
         vec![
-            Attribute {
-                attribute: AttributeType::Strength,
-                quantity: Some(1),
-                ..Default::default()
-            },
-            Attribute {
-                attribute: AttributeType::Health,
-                quantity: Some(1),
-                ..Default::default()
-            },
+            Attribute::DiceCount(DiceColour::Strength, 1),
+            Attribute::Resource(Resource::Health, 1),
         ]
     } else {
-        vec![Attribute {
-            attribute: AttributeType::Agility,
-            quantity: Some(1),
-            ..Default::default()
-        }]
+        vec![Attribute::DiceCount(DiceColour::Agility, 1)]
     };
     let skill_reward = if is_option_one {
         Skill {
@@ -688,11 +448,7 @@ fn get_shadow(is_option_one: bool) -> Combat {
             description: Some(String::from(
                 "Change two of your non-HEROIC dice to by sixes.",
             )),
-            requirements: Some(Attribute {
-                attribute: AttributeType::Potion,
-                quantity: Some(1),
-                ..Default::default()
-            }),
+            requirements: Some(Attribute::Resource(Resource::Potion, 1)),
             effect: Effect::Change {
                 attribute_type: AttributeType::Default,
                 value: 6,
@@ -703,20 +459,8 @@ fn get_shadow(is_option_one: bool) -> Combat {
         Skill {
             name: String::from("STATIC BURST"),
             description: Some(String::from("Gain a value 4 STRENGTH and a value 4 AGILITY. Then, increase one of your dice by 1.")),
-            requirements: Some(Attribute {
-                attribute: AttributeType::Magic,
-                quantity: Some(1),
-                value: Some(4),
-            }),
-            effect: Effect::Gain(vec![Attribute {
-                attribute: AttributeType::Strength,
-                quantity: Some(1),
-                value: Some(4),
-            }, Attribute {
-                attribute: AttributeType::Agility,
-                quantity: Some(1),
-                value: Some(4),
-            }]),
+            requirements: Some(Attribute::DieOfValue(DiceColour::Magic, 4)),
+            effect: Effect::Gain(vec![Attribute::DieOfValue(DiceColour::Strength, 4), Attribute::DieOfValue(DiceColour::Agility, 4)]),
             encounters: vec![Encounter::Combat, Encounter::Boss],
         }
     };
@@ -725,82 +469,50 @@ fn get_shadow(is_option_one: bool) -> Combat {
         special_ability: Effect::Fade,
         challenges: vec![
             ChallengeBox {
-                dice_type: Some(AttributeType::Agility),
+                dice_type: Some(DiceColour::Agility),
                 total_value: 10,
                 single_dice: false,
                 priority: true,
                 consequences: Vec::new(),
             },
             ChallengeBox {
-                dice_type: Some(AttributeType::Strength),
+                dice_type: Some(DiceColour::Strength),
                 total_value: 5,
                 single_dice: true,
                 priority: false,
                 consequences: vec![
-                    Attribute {
-                        attribute: AttributeType::Health,
-                        quantity: Some(1),
-                        ..Default::default()
-                    },
-                    Attribute {
-                        attribute: AttributeType::Time,
-                        quantity: Some(1),
-                        ..Default::default()
-                    },
+                    Attribute::Resource(Resource::Health, 1),
+                    Attribute::Resource(Resource::Time, 1),
                 ],
             },
             ChallengeBox {
-                dice_type: Some(AttributeType::Magic),
+                dice_type: Some(DiceColour::Magic),
                 total_value: 3,
                 single_dice: true,
                 priority: false,
                 consequences: vec![
-                    Attribute {
-                        attribute: AttributeType::Health,
-                        quantity: Some(1),
-                        ..Default::default()
-                    },
-                    Attribute {
-                        attribute: AttributeType::Time,
-                        quantity: Some(1),
-                        ..Default::default()
-                    },
+                    Attribute::Resource(Resource::Health, 1),
+                    Attribute::Resource(Resource::Time, 1),
                 ],
             },
             ChallengeBox {
-                dice_type: Some(AttributeType::Magic),
+                dice_type: Some(DiceColour::Magic),
                 total_value: 4,
                 single_dice: true,
                 priority: false,
                 consequences: vec![
-                    Attribute {
-                        attribute: AttributeType::Health,
-                        quantity: Some(1),
-                        ..Default::default()
-                    },
-                    Attribute {
-                        attribute: AttributeType::Time,
-                        quantity: Some(1),
-                        ..Default::default()
-                    },
+                    Attribute::Resource(Resource::Health, 1),
+                    Attribute::Resource(Resource::Time, 1),
                 ],
             },
             ChallengeBox {
-                dice_type: Some(AttributeType::Magic),
+                dice_type: Some(DiceColour::Magic),
                 total_value: 5,
                 single_dice: true,
                 priority: false,
                 consequences: vec![
-                    Attribute {
-                        attribute: AttributeType::Health,
-                        quantity: Some(1),
-                        ..Default::default()
-                    },
-                    Attribute {
-                        attribute: AttributeType::Time,
-                        quantity: Some(1),
-                        ..Default::default()
-                    },
+                    Attribute::Resource(Resource::Health, 1),
+                    Attribute::Resource(Resource::Time, 1),
                 ],
             },
         ],
@@ -815,38 +527,18 @@ fn get_plague_rat(is_option_one: bool) -> Combat {
         Skill {
             name: String::from("FLAMEWEAVE"),
             description: Some(String::from("Gain a MAGIC value 5")),
-            requirements: Some(Attribute {
-                attribute: AttributeType::Magic,
-                quantity: Some(1),
-                value: Some(2),
-            }),
-            effect: Effect::Gain(vec![Attribute {
-                attribute: AttributeType::Magic,
-                quantity: Some(1),
-                value: Some(5),
-            }]),
+            requirements: Some(Attribute::DieOfValue(DiceColour::Magic, 2)),
+            effect: Effect::Gain(vec![Attribute::DieOfValue(DiceColour::Magic, 5)]),
             encounters: vec![Encounter::Combat, Encounter::Peril, Encounter::Boss],
         }
     } else {
         Skill {
             name: String::from("PERSISTENCE"),
             description: Some(String::from("Roll 1 x STRENGTH DICE and 1 x HEROIC DICE.")),
-            requirements: Some(Attribute {
-                attribute: AttributeType::Strength,
-                quantity: Some(2),
-                ..Default::default()
-            }),
+            requirements: Some(Attribute::DiceCount(DiceColour::Strength, 2)),
             effect: Effect::Roll(vec![
-                Attribute {
-                    attribute: AttributeType::Strength,
-                    quantity: Some(1),
-                    ..Default::default()
-                },
-                Attribute {
-                    attribute: AttributeType::Heroic,
-                    quantity: Some(1),
-                    ..Default::default()
-                },
+                Attribute::DiceCount(DiceColour::Strength, 1),
+                Attribute::DiceCount(DiceColour::Heroic, 1),
             ]),
             encounters: vec![Encounter::Combat, Encounter::Peril, Encounter::Boss],
         }
@@ -856,94 +548,58 @@ fn get_plague_rat(is_option_one: bool) -> Combat {
         special_ability: Effect::Swarm,
         challenges: vec![
             ChallengeBox {
-                dice_type: Some(AttributeType::Agility),
+                dice_type: Some(DiceColour::Agility),
                 total_value: 4,
                 single_dice: false,
                 priority: true,
                 consequences: Vec::new(),
             },
             ChallengeBox {
-                dice_type: Some(AttributeType::Agility),
+                dice_type: Some(DiceColour::Agility),
                 total_value: 3,
                 single_dice: true,
                 priority: false,
                 consequences: vec![
-                    Attribute {
-                        attribute: AttributeType::Health,
-                        quantity: Some(1),
-                        ..Default::default()
-                    },
-                    Attribute {
-                        attribute: AttributeType::Time,
-                        quantity: Some(1),
-                        ..Default::default()
-                    },
+                    Attribute::Resource(Resource::Health, 1),
+                    Attribute::Resource(Resource::Time, 1),
                 ],
             },
             ChallengeBox {
-                dice_type: Some(AttributeType::Strength),
+                dice_type: Some(DiceColour::Strength),
                 total_value: 3,
                 single_dice: true,
                 priority: false,
-                consequences: vec![Attribute {
-                    attribute: AttributeType::Time,
-                    quantity: Some(1),
-                    ..Default::default()
-                }],
+                consequences: vec![Attribute::Resource(Resource::Time, 1)],
             },
             ChallengeBox {
-                dice_type: Some(AttributeType::Strength),
+                dice_type: Some(DiceColour::Strength),
                 total_value: 5,
                 single_dice: true,
                 priority: false,
                 consequences: vec![
-                    Attribute {
-                        attribute: AttributeType::Health,
-                        quantity: Some(1),
-                        ..Default::default()
-                    },
-                    Attribute {
-                        attribute: AttributeType::Time,
-                        quantity: Some(1),
-                        ..Default::default()
-                    },
+                    Attribute::Resource(Resource::Health, 1),
+                    Attribute::Resource(Resource::Time, 1),
                 ],
             },
             ChallengeBox {
-                dice_type: Some(AttributeType::Agility),
+                dice_type: Some(DiceColour::Agility),
                 total_value: 5,
                 single_dice: true,
                 priority: false,
-                consequences: vec![Attribute {
-                    attribute: AttributeType::Time,
-                    quantity: Some(2),
-                    ..Default::default()
-                }],
+                consequences: vec![Attribute::Resource(Resource::Time, 2)],
             },
         ],
         xp_reward: 2,
-        item_reward: vec![Attribute {
-            attribute: AttributeType::Agility,
-            quantity: Some(1),
-            ..Default::default()
-        }],
+        item_reward: vec![Attribute::DiceCount(DiceColour::Agility, 1)],
         skill_reward,
     }
 }
 
 fn get_skeleton(is_option_one: bool) -> Combat {
     let item_reward = if is_option_one {
-        vec![Attribute {
-            attribute: AttributeType::Strength,
-            quantity: Some(1),
-            ..Default::default()
-        }]
+        vec![Attribute::DiceCount(DiceColour::Strength, 1)]
     } else {
-        vec![Attribute {
-            attribute: AttributeType::Agility,
-            quantity: Some(1),
-            ..Default::default()
-        }]
+        vec![Attribute::DiceCount(DiceColour::Agility, 1)]
     };
     let skill_reward = if is_option_one {
         Skill {
@@ -951,11 +607,7 @@ fn get_skeleton(is_option_one: bool) -> Combat {
             description: Some(String::from(
                 "Reroll all your 1s and 2s. Roll 1 x HEROIC DICE",
             )),
-            requirements: Some(Attribute {
-                attribute: AttributeType::Potion,
-                quantity: Some(1),
-                ..Default::default()
-            }),
+            requirements: Some(Attribute::Resource(Resource::Potion, 1)),
             effect: Effect::Reroll(AttributeType::Value(vec![1, 2])),
             encounters: vec![Encounter::Combat, Encounter::Peril, Encounter::Boss],
         }
@@ -965,16 +617,8 @@ fn get_skeleton(is_option_one: bool) -> Combat {
             description: Some(String::from(
                 "Spend 2 x TIME before an encounter. Skip to the Claim Loot phase.",
             )),
-            requirements: Some(Attribute {
-                attribute: AttributeType::Potion,
-                quantity: Some(1),
-                ..Default::default()
-            }),
-            effect: Effect::Skip(Attribute {
-                attribute: AttributeType::Time,
-                quantity: Some(2),
-                ..Default::default()
-            }),
+            requirements: Some(Attribute::Resource(Resource::Potion, 1)),
+            effect: Effect::Skip(Attribute::Resource(Resource::Time, 2)),
             encounters: vec![Encounter::Combat, Encounter::Peril, Encounter::Boss],
         }
     };
@@ -983,76 +627,52 @@ fn get_skeleton(is_option_one: bool) -> Combat {
         special_ability: Effect::Undying,
         challenges: vec![
             ChallengeBox {
-                dice_type: Some(AttributeType::Magic),
+                dice_type: Some(DiceColour::Magic),
                 total_value: 2,
                 single_dice: true,
                 priority: true,
                 consequences: Vec::new(),
             },
             ChallengeBox {
-                dice_type: Some(AttributeType::Magic),
+                dice_type: Some(DiceColour::Magic),
                 total_value: 4,
                 single_dice: true,
                 priority: true,
                 consequences: Vec::new(),
             },
             ChallengeBox {
-                dice_type: Some(AttributeType::Strength),
+                dice_type: Some(DiceColour::Strength),
                 total_value: 3,
                 single_dice: true,
                 priority: false,
-                consequences: vec![Attribute {
-                    attribute: AttributeType::Time,
-                    quantity: Some(1),
-                    ..Default::default()
-                }],
+                consequences: vec![Attribute::Resource(Resource::Time, 1)],
             },
             ChallengeBox {
-                dice_type: Some(AttributeType::Strength),
+                dice_type: Some(DiceColour::Strength),
                 total_value: 5,
                 single_dice: true,
                 priority: false,
                 consequences: vec![
-                    Attribute {
-                        attribute: AttributeType::Health,
-                        quantity: Some(1),
-                        ..Default::default()
-                    },
-                    Attribute {
-                        attribute: AttributeType::Time,
-                        quantity: Some(1),
-                        ..Default::default()
-                    },
+                    Attribute::Resource(Resource::Health, 1),
+                    Attribute::Resource(Resource::Time, 1),
                 ],
             },
             ChallengeBox {
-                dice_type: Some(AttributeType::Agility),
+                dice_type: Some(DiceColour::Agility),
                 total_value: 5,
                 single_dice: true,
                 priority: false,
                 consequences: vec![
-                    Attribute {
-                        attribute: AttributeType::Health,
-                        quantity: Some(1),
-                        ..Default::default()
-                    },
-                    Attribute {
-                        attribute: AttributeType::Time,
-                        quantity: Some(1),
-                        ..Default::default()
-                    },
+                    Attribute::Resource(Resource::Health, 1),
+                    Attribute::Resource(Resource::Time, 1),
                 ],
             },
             ChallengeBox {
-                dice_type: Some(AttributeType::Strength),
+                dice_type: Some(DiceColour::Strength),
                 total_value: 6,
                 single_dice: true,
                 priority: false,
-                consequences: vec![Attribute {
-                    attribute: AttributeType::Time,
-                    quantity: Some(2),
-                    ..Default::default()
-                }],
+                consequences: vec![Attribute::Resource(Resource::Time, 2)],
             },
         ],
         xp_reward: 2,
@@ -1064,23 +684,11 @@ fn get_skeleton(is_option_one: bool) -> Combat {
 fn get_glooping_ooze(is_option_one: bool) -> Combat {
     let item_reward = if is_option_one {
         vec![
-            Attribute {
-                attribute: AttributeType::Strength,
-                quantity: Some(1),
-                ..Default::default()
-            },
-            Attribute {
-                attribute: AttributeType::Health,
-                quantity: Some(1),
-                ..Default::default()
-            },
+            Attribute::DiceCount(DiceColour::Strength, 1),
+            Attribute::Resource(Resource::Health, 1),
         ]
     } else {
-        vec![Attribute {
-            attribute: AttributeType::Magic,
-            quantity: Some(1),
-            ..Default::default()
-        }]
+        vec![Attribute::DiceCount(DiceColour::Magic, 1)]
     };
     let skill_reward = if is_option_one {
         Skill {
@@ -1088,11 +696,7 @@ fn get_glooping_ooze(is_option_one: bool) -> Combat {
             description: Some(String::from(
                 "Change two of your non-HEROIC dice to by sixes.",
             )),
-            requirements: Some(Attribute {
-                attribute: AttributeType::Potion,
-                quantity: Some(1),
-                ..Default::default()
-            }),
+            requirements: Some(Attribute::Resource(Resource::Potion, 1)),
             effect: Effect::Change {
                 attribute_type: AttributeType::Default,
                 value: 6,
@@ -1105,16 +709,8 @@ fn get_glooping_ooze(is_option_one: bool) -> Combat {
             description: Some(String::from(
                 "Gain a value 6 HEROIC dice. You can only use it to cover a box with PRIORITY.",
             )),
-            requirements: Some(Attribute {
-                attribute: AttributeType::Strength,
-                quantity: Some(1),
-                ..Default::default()
-            }),
-            effect: Effect::Gain(vec![Attribute {
-                attribute: AttributeType::Heroic,
-                quantity: Some(1),
-                value: Some(6),
-            }]),
+            requirements: Some(Attribute::DiceCount(DiceColour::Strength, 1)),
+            effect: Effect::Gain(vec![Attribute::DieOfValue(DiceColour::Heroic, 6)]),
             encounters: vec![Encounter::Combat, Encounter::Boss],
         }
     };
@@ -1123,82 +719,54 @@ fn get_glooping_ooze(is_option_one: bool) -> Combat {
         special_ability: Effect::Split,
         challenges: vec![
             ChallengeBox {
-                dice_type: Some(AttributeType::Magic),
+                dice_type: Some(DiceColour::Magic),
                 total_value: 2,
                 single_dice: true,
                 priority: true,
                 consequences: Vec::new(),
             },
             ChallengeBox {
-                dice_type: Some(AttributeType::Magic),
+                dice_type: Some(DiceColour::Magic),
                 total_value: 3,
                 single_dice: true,
                 priority: true,
                 consequences: Vec::new(),
             },
             ChallengeBox {
-                dice_type: Some(AttributeType::Strength),
+                dice_type: Some(DiceColour::Strength),
                 total_value: 4,
                 single_dice: true,
                 priority: false,
                 consequences: vec![
-                    Attribute {
-                        attribute: AttributeType::Health,
-                        quantity: Some(1),
-                        ..Default::default()
-                    },
-                    Attribute {
-                        attribute: AttributeType::Time,
-                        quantity: Some(1),
-                        ..Default::default()
-                    },
+                    Attribute::Resource(Resource::Health, 1),
+                    Attribute::Resource(Resource::Time, 1),
                 ],
             },
             ChallengeBox {
-                dice_type: Some(AttributeType::Strength),
+                dice_type: Some(DiceColour::Strength),
                 total_value: 4,
                 single_dice: true,
                 priority: false,
                 consequences: vec![
-                    Attribute {
-                        attribute: AttributeType::Health,
-                        quantity: Some(1),
-                        ..Default::default()
-                    },
-                    Attribute {
-                        attribute: AttributeType::Time,
-                        quantity: Some(1),
-                        ..Default::default()
-                    },
+                    Attribute::Resource(Resource::Health, 1),
+                    Attribute::Resource(Resource::Time, 1),
                 ],
             },
             ChallengeBox {
-                dice_type: Some(AttributeType::Magic),
+                dice_type: Some(DiceColour::Magic),
                 total_value: 5,
                 single_dice: true,
                 priority: false,
-                consequences: vec![Attribute {
-                    attribute: AttributeType::Time,
-                    quantity: Some(2),
-                    ..Default::default()
-                }],
+                consequences: vec![Attribute::Resource(Resource::Time, 2)],
             },
             ChallengeBox {
-                dice_type: Some(AttributeType::Strength),
+                dice_type: Some(DiceColour::Strength),
                 total_value: 6,
                 single_dice: true,
                 priority: false,
                 consequences: vec![
-                    Attribute {
-                        attribute: AttributeType::Health,
-                        quantity: Some(1),
-                        ..Default::default()
-                    },
-                    Attribute {
-                        attribute: AttributeType::Time,
-                        quantity: Some(1),
-                        ..Default::default()
-                    },
+                    Attribute::Resource(Resource::Health, 1),
+                    Attribute::Resource(Resource::Time, 1),
                 ],
             },
         ],
@@ -1223,22 +791,10 @@ fn get_ice_elemental(is_option_one: bool) -> Combat {
         Skill {
             name: String::from("FLURRY"),
             description: Some(String::from("Roll 2 x AGILITY, 1 x STRENGTH dice.")),
-            requirements: Some(Attribute {
-                attribute: AttributeType::Strength,
-                quantity: Some(2),
-                ..Default::default()
-            }),
+            requirements: Some(Attribute::DiceCount(DiceColour::Strength, 2)),
             effect: Effect::Roll(vec![
-                Attribute {
-                    attribute: AttributeType::Agility,
-                    quantity: Some(2),
-                    ..Default::default()
-                },
-                Attribute {
-                    attribute: AttributeType::Strength,
-                    quantity: Some(1),
-                    ..Default::default()
-                },
+                Attribute::DiceCount(DiceColour::Agility, 2),
+                Attribute::DiceCount(DiceColour::Strength, 1),
             ]),
             encounters: vec![Encounter::Combat, Encounter::Boss],
         }
@@ -1248,83 +804,51 @@ fn get_ice_elemental(is_option_one: bool) -> Combat {
         special_ability: Effect::Frost,
         challenges: vec![
             ChallengeBox {
-                dice_type: Some(AttributeType::Strength),
+                dice_type: Some(DiceColour::Strength),
                 total_value: 11,
                 single_dice: false,
                 priority: true,
                 consequences: Vec::new(),
             },
             ChallengeBox {
-                dice_type: Some(AttributeType::Strength),
+                dice_type: Some(DiceColour::Strength),
                 total_value: 3,
                 single_dice: true,
                 priority: false,
-                consequences: vec![Attribute {
-                    attribute: AttributeType::Time,
-                    quantity: Some(1),
-                    ..Default::default()
-                }],
+                consequences: vec![Attribute::Resource(Resource::Time, 1)],
             },
             ChallengeBox {
-                dice_type: Some(AttributeType::Magic),
+                dice_type: Some(DiceColour::Magic),
                 total_value: 4,
                 single_dice: true,
                 priority: false,
-                consequences: vec![Attribute {
-                    attribute: AttributeType::Health,
-                    quantity: Some(2),
-                    ..Default::default()
-                }],
+                consequences: vec![Attribute::Resource(Resource::Health, 2)],
             },
             ChallengeBox {
-                dice_type: Some(AttributeType::Magic),
+                dice_type: Some(DiceColour::Magic),
                 total_value: 5,
                 single_dice: true,
                 priority: false,
                 consequences: vec![
-                    Attribute {
-                        attribute: AttributeType::Health,
-                        quantity: Some(1),
-                        ..Default::default()
-                    },
-                    Attribute {
-                        attribute: AttributeType::Time,
-                        quantity: Some(1),
-                        ..Default::default()
-                    },
+                    Attribute::Resource(Resource::Health, 1),
+                    Attribute::Resource(Resource::Time, 1),
                 ],
             },
             ChallengeBox {
-                dice_type: Some(AttributeType::Magic),
+                dice_type: Some(DiceColour::Magic),
                 total_value: 6,
                 single_dice: true,
                 priority: false,
                 consequences: vec![
-                    Attribute {
-                        attribute: AttributeType::Health,
-                        quantity: Some(1),
-                        ..Default::default()
-                    },
-                    Attribute {
-                        attribute: AttributeType::Time,
-                        quantity: Some(1),
-                        ..Default::default()
-                    },
+                    Attribute::Resource(Resource::Health, 1),
+                    Attribute::Resource(Resource::Time, 1),
                 ],
             },
         ],
         xp_reward: 4,
         item_reward: vec![
-            Attribute {
-                attribute: AttributeType::Agility,
-                quantity: Some(1),
-                ..Default::default()
-            },
-            Attribute {
-                attribute: AttributeType::Health,
-                quantity: Some(1),
-                ..Default::default()
-            },
+            Attribute::DiceCount(DiceColour::Agility, 1),
+            Attribute::Resource(Resource::Health, 1),
         ],
         skill_reward,
     }
@@ -1333,29 +857,13 @@ fn get_ice_elemental(is_option_one: bool) -> Combat {
 fn get_fire_elemental(is_option_one: bool) -> Combat {
     let item_reward = if is_option_one {
         vec![
-            Attribute {
-                attribute: AttributeType::Agility,
-                quantity: Some(1),
-                ..Default::default()
-            },
-            Attribute {
-                attribute: AttributeType::Health,
-                quantity: Some(1),
-                ..Default::default()
-            },
+            Attribute::DiceCount(DiceColour::Agility, 1),
+            Attribute::Resource(Resource::Health, 1),
         ]
     } else {
         vec![
-            Attribute {
-                attribute: AttributeType::Magic,
-                quantity: Some(1),
-                ..Default::default()
-            },
-            Attribute {
-                attribute: AttributeType::Health,
-                quantity: Some(1),
-                ..Default::default()
-            },
+            Attribute::DiceCount(DiceColour::Magic, 1),
+            Attribute::Resource(Resource::Health, 1),
         ]
     };
     let skill_reward = if is_option_one {
@@ -1364,11 +872,7 @@ fn get_fire_elemental(is_option_one: bool) -> Combat {
             description: Some(String::from(
                 "Choose MAGIC, STRENGTH, or AGILITY. Increase all of your dice of that color by 1.",
             )),
-            requirements: Some(Attribute {
-                attribute: AttributeType::Magic,
-                quantity: Some(1),
-                value: Some(2),
-            }),
+            requirements: Some(Attribute::DieOfValue(DiceColour::Magic, 2)),
             effect: Effect::Increase(1),
             encounters: vec![Encounter::Combat, Encounter::Boss],
         }
@@ -1376,11 +880,7 @@ fn get_fire_elemental(is_option_one: bool) -> Combat {
         Skill {
             name: String::from("CONSISTENCY"),
             description: Some(String::from("Change any or all of your dice to 4s.")),
-            requirements: Some(Attribute {
-                attribute: AttributeType::Agility,
-                quantity: Some(1),
-                ..Default::default()
-            }),
+            requirements: Some(Attribute::DiceCount(DiceColour::Agility, 1)),
             effect: Effect::Change {
                 attribute_type: AttributeType::Default,
                 value: 4,
@@ -1393,79 +893,47 @@ fn get_fire_elemental(is_option_one: bool) -> Combat {
         special_ability: Effect::Flames,
         challenges: vec![
             ChallengeBox {
-                dice_type: Some(AttributeType::Magic),
+                dice_type: Some(DiceColour::Magic),
                 total_value: 3,
                 single_dice: false,
                 priority: false,
-                consequences: vec![Attribute {
-                    attribute: AttributeType::Time,
-                    quantity: Some(1),
-                    ..Default::default()
-                }],
+                consequences: vec![Attribute::Resource(Resource::Time, 1)],
             },
             ChallengeBox {
-                dice_type: Some(AttributeType::Agility),
+                dice_type: Some(DiceColour::Agility),
                 total_value: 3,
                 single_dice: true,
                 priority: false,
-                consequences: vec![Attribute {
-                    attribute: AttributeType::Time,
-                    quantity: Some(1),
-                    ..Default::default()
-                }],
+                consequences: vec![Attribute::Resource(Resource::Time, 1)],
             },
             ChallengeBox {
-                dice_type: Some(AttributeType::Magic),
+                dice_type: Some(DiceColour::Magic),
                 total_value: 6,
                 single_dice: true,
                 priority: false,
                 consequences: vec![
-                    Attribute {
-                        attribute: AttributeType::Health,
-                        quantity: Some(1),
-                        ..Default::default()
-                    },
-                    Attribute {
-                        attribute: AttributeType::Time,
-                        quantity: Some(1),
-                        ..Default::default()
-                    },
+                    Attribute::Resource(Resource::Health, 1),
+                    Attribute::Resource(Resource::Time, 1),
                 ],
             },
             ChallengeBox {
-                dice_type: Some(AttributeType::Magic),
+                dice_type: Some(DiceColour::Magic),
                 total_value: 11,
                 single_dice: false,
                 priority: false,
                 consequences: vec![
-                    Attribute {
-                        attribute: AttributeType::Health,
-                        quantity: Some(2),
-                        ..Default::default()
-                    },
-                    Attribute {
-                        attribute: AttributeType::Time,
-                        quantity: Some(1),
-                        ..Default::default()
-                    },
+                    Attribute::Resource(Resource::Health, 2),
+                    Attribute::Resource(Resource::Time, 1),
                 ],
             },
             ChallengeBox {
-                dice_type: Some(AttributeType::Agility),
+                dice_type: Some(DiceColour::Agility),
                 total_value: 6,
                 single_dice: true,
                 priority: false,
                 consequences: vec![
-                    Attribute {
-                        attribute: AttributeType::Health,
-                        quantity: Some(1),
-                        ..Default::default()
-                    },
-                    Attribute {
-                        attribute: AttributeType::Time,
-                        quantity: Some(1),
-                        ..Default::default()
-                    },
+                    Attribute::Resource(Resource::Health, 1),
+                    Attribute::Resource(Resource::Time, 1),
                 ],
             },
         ],
@@ -1480,32 +948,16 @@ fn get_wraith(is_option_one: bool) -> Combat {
         Skill {
             name: String::from("DEXTERITY"),
             description: Some(String::from("Gain a value 6 AGILITY dice")),
-            requirements: Some(Attribute {
-                attribute: AttributeType::Agility,
-                quantity: Some(1),
-                ..Default::default()
-            }),
-            effect: Effect::Gain(vec![Attribute {
-                attribute: AttributeType::Agility,
-                value: Some(6),
-                quantity: Some(1),
-            }]),
+            requirements: Some(Attribute::DiceCount(DiceColour::Agility, 1)),
+            effect: Effect::Gain(vec![Attribute::DieOfValue(DiceColour::Agility, 6)]),
             encounters: vec![Encounter::Combat, Encounter::Boss],
         }
     } else {
         Skill {
             name: String::from("HASTE"),
             description: Some(String::from("Roll 2 x AGILITY dice.")),
-            requirements: Some(Attribute {
-                attribute: AttributeType::Magic,
-                quantity: Some(1),
-                value: Some(3),
-            }),
-            effect: Effect::Gain(vec![Attribute {
-                attribute: AttributeType::Agility,
-                quantity: Some(2),
-                ..Default::default()
-            }]),
+            requirements: Some(Attribute::DieOfValue(DiceColour::Magic, 3)),
+            effect: Effect::Gain(vec![Attribute::DiceCount(DiceColour::Agility, 2)]),
             encounters: vec![Encounter::Combat, Encounter::Boss],
         }
     };
@@ -1514,66 +966,46 @@ fn get_wraith(is_option_one: bool) -> Combat {
         special_ability: Effect::Drain,
         challenges: vec![
             ChallengeBox {
-                dice_type: Some(AttributeType::Magic),
+                dice_type: Some(DiceColour::Magic),
                 total_value: 9,
                 single_dice: false,
                 priority: true,
                 consequences: Vec::new(),
             },
             ChallengeBox {
-                dice_type: Some(AttributeType::Strength),
+                dice_type: Some(DiceColour::Strength),
                 total_value: 5,
                 single_dice: true,
                 priority: true,
                 consequences: Vec::new(),
             },
             ChallengeBox {
-                dice_type: Some(AttributeType::Strength),
+                dice_type: Some(DiceColour::Strength),
                 total_value: 3,
                 single_dice: true,
                 priority: false,
-                consequences: vec![Attribute {
-                    attribute: AttributeType::Time,
-                    quantity: Some(2),
-                    ..Default::default()
-                }],
+                consequences: vec![Attribute::Resource(Resource::Time, 2)],
             },
             ChallengeBox {
-                dice_type: Some(AttributeType::Strength),
+                dice_type: Some(DiceColour::Strength),
                 total_value: 5,
                 single_dice: true,
                 priority: false,
-                consequences: vec![Attribute {
-                    attribute: AttributeType::Health,
-                    quantity: Some(2),
-                    ..Default::default()
-                }],
+                consequences: vec![Attribute::Resource(Resource::Health, 2)],
             },
             ChallengeBox {
-                dice_type: Some(AttributeType::Magic),
+                dice_type: Some(DiceColour::Magic),
                 total_value: 6,
                 single_dice: true,
                 priority: false,
                 consequences: vec![
-                    Attribute {
-                        attribute: AttributeType::Health,
-                        quantity: Some(1),
-                        ..Default::default()
-                    },
-                    Attribute {
-                        attribute: AttributeType::Time,
-                        quantity: Some(1),
-                        ..Default::default()
-                    },
+                    Attribute::Resource(Resource::Health, 1),
+                    Attribute::Resource(Resource::Time, 1),
                 ],
             },
         ],
         xp_reward: 3,
-        item_reward: vec![Attribute {
-            attribute: AttributeType::Strength,
-            quantity: Some(1),
-            ..Default::default()
-        }],
+        item_reward: vec![Attribute::DiceCount(DiceColour::Strength, 1)],
         skill_reward,
     }
 }
@@ -0,0 +1,158 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{dungeon::Dungeon, hero::Hero};
+
+/// How hard the player wants the game to be, separate from each dungeon's own
+/// difficulty number.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Difficulty {
+    Novice,
+    #[default]
+    Standard,
+    Veteran,
+    Heroic,
+    Epic,
+}
+
+impl Display for Difficulty {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl Difficulty {
+    /// Looks a difficulty up by name, ignoring case.
+    pub fn from_name(name: &str) -> Option<Difficulty> {
+        match name.to_ascii_lowercase().as_str() {
+            "novice" => Some(Difficulty::Novice),
+            "standard" => Some(Difficulty::Standard),
+            "veteran" => Some(Difficulty::Veteran),
+            "heroic" => Some(Difficulty::Heroic),
+            "epic" => Some(Difficulty::Epic),
+            _ => None,
+        }
+    }
+
+    /// The next harder difficulty, wrapping back round to novice after epic.
+    pub fn next(&self) -> Difficulty {
+        match self {
+            Difficulty::Novice => Difficulty::Standard,
+            Difficulty::Standard => Difficulty::Veteran,
+            Difficulty::Veteran => Difficulty::Heroic,
+            Difficulty::Heroic => Difficulty::Epic,
+            Difficulty::Epic => Difficulty::Novice,
+        }
+    }
+
+    pub fn health_bonus(&self) -> i8 {
+        match self {
+            Difficulty::Novice => 2,
+            Difficulty::Standard | Difficulty::Veteran => 0,
+            Difficulty::Heroic => -1,
+            Difficulty::Epic => -2,
+        }
+    }
+
+    pub fn potion_bonus(&self) -> i8 {
+        match self {
+            Difficulty::Novice => 1,
+            Difficulty::Standard | Difficulty::Veteran | Difficulty::Heroic => 0,
+            Difficulty::Epic => -1,
+        }
+    }
+
+    /// Time spent on top of the usual costs whenever a peril or combat starts.
+    pub fn encounter_time_cost(&self) -> usize {
+        match self {
+            Difficulty::Novice | Difficulty::Standard => 0,
+            Difficulty::Veteran | Difficulty::Heroic | Difficulty::Epic => 1,
+        }
+    }
+
+    pub fn boss_health_bonus(&self) -> i8 {
+        match self {
+            Difficulty::Novice => -1,
+            Difficulty::Standard => 0,
+            Difficulty::Veteran | Difficulty::Heroic => 1,
+            Difficulty::Epic => 2,
+        }
+    }
+
+    /// Added to the score of a won game.
+    pub fn score_bonus(&self) -> i32 {
+        match self {
+            Difficulty::Novice => -5,
+            Difficulty::Standard => 0,
+            Difficulty::Veteran => 5,
+            Difficulty::Heroic => 10,
+            Difficulty::Epic => 15,
+        }
+    }
+
+    /// Applies the starting health and potion changes, stopping at zero.
+    /// Heroes with no health, like Caliana, lose the game on their first
+    /// damage whatever the difficulty, so their health is left alone.
+    pub fn adjust_hero(&self, hero: &mut Hero) {
+        if hero.health > 0 {
+            hero.health = hero
+                .health
+                .saturating_add_signed(self.health_bonus() as isize);
+        }
+        hero.potions = (hero.potions + self.potion_bonus()).max(0);
+    }
+
+    /// Applies the boss changes. Bosses always keep at least one health.
+    pub fn adjust_dungeon(&self, dungeon: &mut Dungeon) {
+        let health = dungeon.boss.health as i8 + self.boss_health_bonus();
+        dungeon.boss.health = health.max(1) as usize;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        game_setup::{find_dungeon, get_all_dungeons, promo::get_caliana},
+        hero::PlayerCount,
+    };
+
+    #[test]
+    fn hero_health_and_potions_stop_at_zero() {
+        let mut hero = Hero::get_mage(PlayerCount::Solo);
+        hero.health = 1;
+        hero.potions = 0;
+        Difficulty::Epic.adjust_hero(&mut hero);
+        assert_eq!(hero.health, 0);
+        assert_eq!(hero.potions, 0);
+
+        let mut hero = Hero::get_mage(PlayerCount::Solo);
+        let (health, potions) = (hero.health, hero.potions);
+        Difficulty::Novice.adjust_hero(&mut hero);
+        assert_eq!(hero.health, health + 2);
+        assert_eq!(hero.potions, potions + 1);
+    }
+
+    #[test]
+    fn caliana_keeps_no_health() {
+        for difficulty in [Difficulty::Novice, Difficulty::Standard, Difficulty::Epic] {
+            let mut caliana = get_caliana(PlayerCount::Solo);
+            difficulty.adjust_hero(&mut caliana);
+            assert_eq!(caliana.health, 0, "{}", difficulty);
+        }
+    }
+
+    #[test]
+    fn bosses_keep_at_least_one_health() {
+        let mut dungeon = get_all_dungeons().remove(0);
+        dungeon.boss.health = 1;
+        Difficulty::Novice.adjust_dungeon(&mut dungeon);
+        assert_eq!(dungeon.boss.health, 1);
+
+        let mut dungeon = find_dungeon(&dungeon.name).unwrap();
+        let health = dungeon.boss.health;
+        Difficulty::Epic.adjust_dungeon(&mut dungeon);
+        assert_eq!(dungeon.boss.health, health + 2);
+    }
+}
@@ -1,12 +1,176 @@
-use crate::encounter::{Combat, Peril};
+use crate::{
+    boss::Boss,
+    dungeon::Dungeon,
+    encounter::{Combat, Peril},
+    error::DataError,
+    hero::{Hero, PlayerCount, Skill},
+};
 
 pub mod combat;
+pub mod difficulty;
+pub mod pack;
 pub mod peril;
+pub mod promo;
 
+/// Every peril in every content pack.
 pub fn get_all_perils() -> Vec<Peril> {
-    peril::get_all_perils()
+    pack::get_all_packs(PlayerCount::Solo)
+        .into_iter()
+        .flat_map(|pack| pack.perils)
+        .collect()
 }
 
+/// Every combat in every content pack.
 pub fn get_all_combats() -> Vec<Combat> {
-    combat::get_all_combats()
+    pack::get_all_packs(PlayerCount::Solo)
+        .into_iter()
+        .flat_map(|pack| pack.combats)
+        .collect()
+}
+
+/// Every hero in every content pack, each on the side of its card for `players`.
+pub fn get_all_heroes(players: PlayerCount) -> Vec<Hero> {
+    pack::get_all_packs(players)
+        .into_iter()
+        .flat_map(|pack| pack.heroes)
+        .collect()
+}
+
+/// Every dungeon in every content pack.
+pub fn get_all_dungeons() -> Vec<Dungeon> {
+    pack::get_all_packs(PlayerCount::Solo)
+        .into_iter()
+        .flat_map(|pack| pack.dungeons)
+        .collect()
+}
+
+/// Every boss in every content pack.
+pub fn get_all_bosses() -> Vec<Boss> {
+    pack::get_all_packs(PlayerCount::Solo)
+        .into_iter()
+        .flat_map(|pack| pack.bosses)
+        .collect()
+}
+
+/// Every skill on a built-in hero or offered as a card reward, without repeats.
+pub fn get_all_skills() -> Vec<Skill> {
+    let mut skills: Vec<Skill> = Vec::new();
+    let candidates = get_all_heroes(PlayerCount::Solo)
+        .into_iter()
+        .flat_map(|hero| hero.skills)
+        .chain(get_all_perils().into_iter().map(|peril| peril.skill_reward))
+        .chain(
+            get_all_combats()
+                .into_iter()
+                .map(|combat| combat.skill_reward),
+        );
+    for skill in candidates {
+        if !skills.iter().any(|known| known.name == skill.name) {
+            skills.push(skill);
+        }
+    }
+    skills
+}
+
+/// Looks up a built-in hero by name, on the side of its card for `players`.
+pub fn find_hero(name: &str, players: PlayerCount) -> Result<Hero, DataError> {
+    get_all_heroes(players)
+        .into_iter()
+        .find(|hero| same_name(&hero.name, name))
+        .ok_or(DataError::NotFound {
+            kind: "hero",
+            name: name.to_string(),
+        })
+}
+
+/// Looks up a built-in dungeon by name.
+pub fn find_dungeon(name: &str) -> Result<Dungeon, DataError> {
+    get_all_dungeons()
+        .into_iter()
+        .find(|dungeon| same_name(&dungeon.name, name))
+        .ok_or(DataError::NotFound {
+            kind: "dungeon",
+            name: name.to_string(),
+        })
+}
+
+/// Looks up a built-in boss by name.
+pub fn find_boss(name: &str) -> Result<Boss, DataError> {
+    get_all_bosses()
+        .into_iter()
+        .find(|boss| same_name(&boss.name, name))
+        .ok_or(DataError::NotFound {
+            kind: "boss",
+            name: name.to_string(),
+        })
+}
+
+/// Compares names ignoring case, spaces and punctuation, so "dragons-cave"
+/// finds "Dragon's Cave".
+fn same_name(card: &str, wanted: &str) -> bool {
+    let normalise = |name: &str| -> String {
+        name.chars()
+            .filter(char::is_ascii_alphanumeric)
+            .map(|c| c.to_ascii_lowercase())
+            .collect()
+    };
+    normalise(card) == normalise(wanted)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hero::{Attribute, DiceColour, Effect};
+
+    /// The dice each built-in skill gains, as written in its description.
+    fn described_gains(skill: &str) -> Option<Vec<(DiceColour, i8)>> {
+        let gains = match skill {
+            "BRUTE FORCE" | "CRUSHING BLOW" => vec![(DiceColour::Strength, 6)],
+            "CRUSHING FIST" => vec![(DiceColour::Strength, 6), (DiceColour::Strength, 6)],
+            "ACCURACY" | "ARMOR CRUSH" => vec![(DiceColour::Heroic, 6)],
+            "TRIPLE STRIKE" => vec![
+                (DiceColour::Strength, 5),
+                (DiceColour::Agility, 5),
+                (DiceColour::Magic, 5),
+            ],
+            "STATIC BURST" => vec![(DiceColour::Strength, 4), (DiceColour::Agility, 4)],
+            "FLAMEWEAVE" => vec![(DiceColour::Magic, 5)],
+            "DEXTERITY" => vec![(DiceColour::Agility, 6)],
+            _ => return None,
+        };
+        Some(gains)
+    }
+
+    #[test]
+    fn gained_dice_match_descriptions() {
+        let skills = get_all_perils()
+            .into_iter()
+            .map(|peril| peril.skill_reward)
+            .chain(
+                get_all_combats()
+                    .into_iter()
+                    .map(|combat| combat.skill_reward),
+            );
+        let mut checked = 0;
+        for skill in skills {
+            let Effect::Gain(attributes) = &skill.effect else {
+                continue;
+            };
+            let gained: Vec<(DiceColour, i8)> = attributes
+                .iter()
+                .filter_map(|attribute| match attribute {
+                    Attribute::DieOfValue(colour, value) => Some((*colour, *value)),
+                    _ => None,
+                })
+                .collect();
+            if gained.is_empty() {
+                continue;
+            }
+            let described = described_gains(&skill.name)
+                .unwrap_or_else(|| panic!("{} is missing from the table", skill.name));
+            assert_eq!(gained, described, "{}", skill.name);
+            checked += 1;
+        }
+        assert!(checked > 0);
+    }
 }
@@ -0,0 +1,91 @@
+use serde::Serialize;
+
+use crate::{
+    boss::Boss,
+    dungeon::Dungeon,
+    encounter::{Combat, Peril},
+    error::DataError,
+    game::Card,
+    hero::{Hero, PlayerCount},
+};
+
+use super::{combat, peril, promo, same_name};
+
+pub const CORE_PACK: &str = "Core";
+pub const PROMO_PACK: &str = "Promo";
+
+/// A named set of cards that can be added to or left out of a game. Heroes are
+/// built on the side of their cards for the player count the pack was made for.
+#[derive(Debug, Clone, Serialize)]
+pub struct ContentPack {
+    pub name: String,
+    pub heroes: Vec<Hero>,
+    pub dungeons: Vec<Dungeon>,
+    pub perils: Vec<Peril>,
+    pub combats: Vec<Combat>,
+    pub bosses: Vec<Boss>,
+}
+
+impl ContentPack {
+    /// The perils and combats the pack shuffles into the deck.
+    pub fn cards(&self) -> Vec<Card> {
+        self.perils
+            .iter()
+            .cloned()
+            .map(Card::Peril)
+            .chain(self.combats.iter().cloned().map(Card::Combat))
+            .collect()
+    }
+}
+
+/// Everything in the base game.
+pub fn get_core_pack(players: PlayerCount) -> ContentPack {
+    ContentPack {
+        name: String::from(CORE_PACK),
+        heroes: vec![
+            Hero::get_mage(players),
+            Hero::get_warrior(players),
+            Hero::get_rogue(players),
+            Hero::get_archer(players),
+            Hero::get_paladin(players),
+        ],
+        dungeons: vec![
+            Dungeon::get_dragons_cave(),
+            Dungeon::get_phoenix_den(),
+            Dungeon::get_hydras_reef(),
+            Dungeon::get_yetis_cavern(),
+            Dungeon::get_lichs_tomb(),
+            Dungeon::get_minotaurs_maze(),
+        ],
+        perils: peril::get_all_perils(),
+        combats: combat::get_all_combats(),
+        bosses: vec![
+            Boss::get_dragon(),
+            Boss::get_phoenix(),
+            Boss::get_hydra(),
+            Boss::get_yeti(),
+            Boss::get_lich(),
+            Boss::get_minotaur(),
+        ],
+    }
+}
+
+pub fn get_all_packs(players: PlayerCount) -> Vec<ContentPack> {
+    vec![get_core_pack(players), promo::get_promo_pack(players)]
+}
+
+/// Looks up a pack by name, ignoring case.
+pub fn find_pack(name: &str, players: PlayerCount) -> Result<ContentPack, DataError> {
+    get_all_packs(players)
+        .into_iter()
+        .find(|pack| same_name(&pack.name, name))
+        .ok_or(DataError::NotFound {
+            kind: "content pack",
+            name: name.to_string(),
+        })
+}
+
+/// The deck made from every card in `packs`, in pack order.
+pub fn deck(packs: &[ContentPack]) -> Vec<Card> {
+    packs.iter().flat_map(ContentPack::cards).collect()
+}
@@ -1,7 +1,7 @@
 use crate::{
     dungeon::ChallengeBox,
     encounter::{Encounter, Peril},
-    hero::{Attribute, AttributeType, Effect, Skill},
+    hero::{Attribute, AttributeType, DiceColour, Effect, Resource, Skill},
 };
 
 pub fn get_all_perils() -> Vec<Peril> {
@@ -31,17 +31,9 @@ pub fn get_all_perils() -> Vec<Peril> {
 
 fn get_rune_puzzle(is_option_one: bool) -> Peril {
     let item_reward = if is_option_one {
-        vec![Attribute {
-            attribute: AttributeType::Strength,
-            quantity: Some(1),
-            ..Default::default()
-        }]
+        vec![Attribute::DiceCount(DiceColour::Strength, 1)]
     } else {
-        vec![Attribute {
-            attribute: AttributeType::Agility,
-            quantity: Some(1),
-            ..Default::default()
-        }]
+        vec![Attribute::DiceCount(DiceColour::Agility, 1)]
     };
 
     let skill_reward = if is_option_one {
@@ -50,11 +42,7 @@ fn get_rune_puzzle(is_option_one: bool) -> Peril {
             description: Some(String::from(
                 "Reroll all your 1s and 2s. Roll 1 x HEROIC DICE",
             )),
-            requirements: Some(Attribute {
-                attribute: AttributeType::Potion,
-                quantity: Some(1),
-                ..Default::default()
-            }),
+            requirements: Some(Attribute::Resource(Resource::Potion, 1)),
             effect: Effect::Reroll(AttributeType::Value(vec![1, 2])),
             encounters: vec![Encounter::Combat, Encounter::Peril, Encounter::Boss],
         }
@@ -62,22 +50,10 @@ fn get_rune_puzzle(is_option_one: bool) -> Peril {
         Skill {
             name: String::from("PERSISTENCE"),
             description: Some(String::from("Roll 1 x STRENGTH DICE and 1 x HEROIC DICE.")),
-            requirements: Some(Attribute {
-                attribute: AttributeType::Strength,
-                quantity: Some(2),
-                ..Default::default()
-            }),
+            requirements: Some(Attribute::DiceCount(DiceColour::Strength, 2)),
             effect: Effect::Roll(vec![
-                Attribute {
-                    attribute: AttributeType::Strength,
-                    quantity: Some(1),
-                    ..Default::default()
-                },
-                Attribute {
-                    attribute: AttributeType::Heroic,
-                    quantity: Some(1),
-                    ..Default::default()
-                },
+                Attribute::DiceCount(DiceColour::Strength, 1),
+                Attribute::DiceCount(DiceColour::Heroic, 1),
             ]),
             encounters: vec![Encounter::Combat, Encounter::Peril, Encounter::Boss],
         }
@@ -86,40 +62,24 @@ fn get_rune_puzzle(is_option_one: bool) -> Peril {
     Peril {
         name: String::from("Rune Puzzle"),
         choice_one: vec![ChallengeBox {
-            dice_type: Some(AttributeType::Magic),
+            dice_type: Some(DiceColour::Magic),
             total_value: 6,
             single_dice: false,
             priority: false,
             consequences: vec![
-                Attribute {
-                    attribute: AttributeType::Health,
-                    quantity: Some(1),
-                    ..Default::default()
-                },
-                Attribute {
-                    attribute: AttributeType::Time,
-                    quantity: Some(3),
-                    ..Default::default()
-                },
+                Attribute::Resource(Resource::Health, 1),
+                Attribute::Resource(Resource::Time, 3),
             ],
         }],
         choice_one_time_cost: Some(2),
         choice_two: vec![ChallengeBox {
-            dice_type: Some(AttributeType::Strength),
+            dice_type: Some(DiceColour::Strength),
             total_value: 11,
             single_dice: false,
             priority: false,
             consequences: vec![
-                Attribute {
-                    attribute: AttributeType::Health,
-                    quantity: Some(3),
-                    ..Default::default()
-                },
-                Attribute {
-                    attribute: AttributeType::Time,
-                    quantity: Some(2),
-                    ..Default::default()
-                },
+                Attribute::Resource(Resource::Health, 3),
+                Attribute::Resource(Resource::Time, 2),
             ],
         }],
         choice_two_time_cost: None,
@@ -131,49 +91,25 @@ fn get_rune_puzzle(is_option_one: bool) -> Peril {
 
 fn get_locked_door(is_option_one: bool) -> Peril {
     let item_reward = if is_option_one {
-        vec![Attribute {
-            attribute: AttributeType::Strength,
-            quantity: Some(1),
-            ..Default::default()
-        }]
+        vec![Attribute::DiceCount(DiceColour::Strength, 1)]
     } else {
-        vec![Attribute {
-            attribute: AttributeType::Magic,
-            quantity: Some(1),
-            ..Default::default()
-        }]
+        vec![Attribute::DiceCount(DiceColour::Magic, 1)]
     };
 
     let skill_reward = if is_option_one {
         Skill {
             name: String::from("SHIMMERBLAST"),
             description: Some(String::from("Roll 1 x HEROIC DICE")),
-            requirements: Some(Attribute {
-                attribute: AttributeType::Magic,
-                value: Some(3),
-                ..Default::default()
-            }),
-            effect: Effect::Roll(vec![Attribute {
-                attribute: AttributeType::Heroic,
-                quantity: Some(1),
-                ..Default::default()
-            }]),
+            requirements: Some(Attribute::DieOfValue(DiceColour::Magic, 3)),
+            effect: Effect::Roll(vec![Attribute::DiceCount(DiceColour::Heroic, 1)]),
             encounters: vec![Encounter::Combat, Encounter::Boss],
         }
     } else {
         Skill {
             name: String::from("ACCURACY"),
             description: Some(String::from("Gain a HEROIC DICE 6")),
-            requirements: Some(Attribute {
-                attribute: AttributeType::Agility,
-                quantity: Some(3),
-                ..Default::default()
-            }),
-            effect: Effect::Gain(vec![Attribute {
-                attribute: AttributeType::Heroic,
-                value: Some(6),
-                quantity: Some(1),
-            }]),
+            requirements: Some(Attribute::DiceCount(DiceColour::Agility, 3)),
+            effect: Effect::Gain(vec![Attribute::DieOfValue(DiceColour::Heroic, 6)]),
             encounters: vec![Encounter::Combat, Encounter::Peril, Encounter::Boss],
         }
     };
@@ -181,41 +117,24 @@ fn get_locked_door(is_option_one: bool) -> Peril {
     Peril {
         name: String::from("Locked Door"),
         choice_one: vec![ChallengeBox {
-            dice_type: Some(AttributeType::Agility),
+            dice_type: Some(DiceColour::Agility),
             total_value: 8,
             single_dice: false,
             priority: false,
             consequences: vec![
-                Attribute {
-                    attribute: AttributeType::Health,
-                    quantity: Some(1),
-
-                    ..Default::default()
-                },
-                Attribute {
-                    attribute: AttributeType::Time,
-                    quantity: Some(4),
-                    ..Default::default()
-                },
+                Attribute::Resource(Resource::Health, 1),
+                Attribute::Resource(Resource::Time, 4),
             ],
         }],
         choice_one_time_cost: Some(1),
         choice_two: vec![ChallengeBox {
-            dice_type: Some(AttributeType::Strength),
+            dice_type: Some(DiceColour::Strength),
             total_value: 11,
             single_dice: false,
             priority: false,
             consequences: vec![
-                Attribute {
-                    attribute: AttributeType::Health,
-                    quantity: Some(2),
-                    ..Default::default()
-                },
-                Attribute {
-                    attribute: AttributeType::Time,
-                    quantity: Some(2),
-                    ..Default::default()
-                },
+                Attribute::Resource(Resource::Health, 2),
+                Attribute::Resource(Resource::Time, 2),
             ],
         }],
         choice_two_time_cost: None,
@@ -227,48 +146,24 @@ fn get_locked_door(is_option_one: bool) -> Peril {
 
 fn get_cave_in(is_option_one: bool) -> Peril {
     let item_reward = if is_option_one {
-        vec![Attribute {
-            attribute: AttributeType::Magic,
-            quantity: Some(1),
-            ..Default::default()
-        }]
+        vec![Attribute::DiceCount(DiceColour::Magic, 1)]
     } else {
-        vec![Attribute {
-            attribute: AttributeType::Agility,
-            quantity: Some(1),
-            ..Default::default()
-        }]
+        vec![Attribute::DiceCount(DiceColour::Agility, 1)]
     };
     let skill_reward = if is_option_one {
         Skill {
             name: String::from("CRUSHING BLOW"),
             description: Some(String::from("Gain a STRENGTH value 6")),
-            requirements: Some(Attribute {
-                attribute: AttributeType::Strength,
-                quantity: Some(1),
-                ..Default::default()
-            }),
-            effect: Effect::Gain(vec![Attribute {
-                attribute: AttributeType::Strength,
-                quantity: Some(1),
-                value: Some(6),
-            }]),
+            requirements: Some(Attribute::DiceCount(DiceColour::Strength, 1)),
+            effect: Effect::Gain(vec![Attribute::DieOfValue(DiceColour::Strength, 6)]),
             encounters: vec![Encounter::Combat, Encounter::Boss],
         }
     } else {
         Skill {
             name: String::from("FLAMEWEAVE"),
             description: Some(String::from("Gain a MAGIC value 5")),
-            requirements: Some(Attribute {
-                attribute: AttributeType::Magic,
-                quantity: Some(1),
-                value: Some(2),
-            }),
-            effect: Effect::Gain(vec![Attribute {
-                attribute: AttributeType::Magic,
-                quantity: Some(1),
-                value: Some(5),
-            }]),
+            requirements: Some(Attribute::DieOfValue(DiceColour::Magic, 2)),
+            effect: Effect::Gain(vec![Attribute::DieOfValue(DiceColour::Magic, 5)]),
             encounters: vec![Encounter::Combat, Encounter::Peril, Encounter::Boss],
         }
     };
@@ -276,40 +171,24 @@ fn get_cave_in(is_option_one: bool) -> Peril {
     Peril {
         name: String::from("Cave-in"),
         choice_one: vec![ChallengeBox {
-            dice_type: Some(AttributeType::Strength),
+            dice_type: Some(DiceColour::Strength),
             single_dice: false,
             total_value: 6,
             priority: false,
             consequences: vec![
-                Attribute {
-                    attribute: AttributeType::Health,
-                    quantity: Some(1),
-                    ..Default::default()
-                },
-                Attribute {
-                    attribute: AttributeType::Time,
-                    quantity: Some(3),
-                    ..Default::default()
-                },
+                Attribute::Resource(Resource::Health, 1),
+                Attribute::Resource(Resource::Time, 3),
             ],
         }],
         choice_one_time_cost: Some(2),
         choice_two: vec![ChallengeBox {
-            dice_type: Some(AttributeType::Agility),
+            dice_type: Some(DiceColour::Agility),
             single_dice: false,
             total_value: 11,
             priority: false,
             consequences: vec![
-                Attribute {
-                    attribute: AttributeType::Health,
-                    quantity: Some(2),
-                    ..Default::default()
-                },
-                Attribute {
-                    attribute: AttributeType::Time,
-                    quantity: Some(2),
-                    ..Default::default()
-                },
+                Attribute::Resource(Resource::Health, 2),
+                Attribute::Resource(Resource::Time, 2),
             ],
         }],
         choice_two_time_cost: None,
@@ -322,29 +201,13 @@ fn get_cave_in(is_option_one: bool) -> Peril {
 fn get_boulder(is_option_one: bool) -> Peril {
     let item_reward = if is_option_one {
         vec![
-            Attribute {
-                attribute: AttributeType::Strength,
-                quantity: Some(1),
-                ..Default::default()
-            },
-            Attribute {
-                attribute: AttributeType::Health,
-                quantity: Some(1),
-                ..Default::default()
-            },
+            Attribute::DiceCount(DiceColour::Strength, 1),
+            Attribute::Resource(Resource::Health, 1),
         ]
     } else {
         vec![
-            Attribute {
-                attribute: AttributeType::Magic,
-                quantity: Some(1),
-                ..Default::default()
-            },
-            Attribute {
-                attribute: AttributeType::Health,
-                quantity: Some(1),
-                ..Default::default()
-            },
+            Attribute::DiceCount(DiceColour::Magic, 1),
+            Attribute::Resource(Resource::Health, 1),
         ]
     };
     let skill_reward = if is_option_one {
@@ -365,11 +228,7 @@ fn get_boulder(is_option_one: bool) -> Peril {
             name: String::from("VALOR"),
             description: Some(String::from("Roll an HEROIC dice.")),
             requirements: None,
-            effect: Effect::Roll(vec![Attribute {
-                attribute: AttributeType::Heroic,
-                quantity: Some(1),
-                ..Default::default()
-            }]),
+            effect: Effect::Roll(vec![Attribute::DiceCount(DiceColour::Heroic, 1)]),
             encounters: vec![Encounter::Combat, Encounter::Boss],
         }
     };
@@ -377,40 +236,24 @@ fn get_boulder(is_option_one: bool) -> Peril {
     Peril {
         name: String::from("Boulder"),
         choice_one: vec![ChallengeBox {
-            dice_type: Some(AttributeType::Magic),
+            dice_type: Some(DiceColour::Magic),
             single_dice: false,
             total_value: 11,
             priority: false,
             consequences: vec![
-                Attribute {
-                    attribute: AttributeType::Health,
-                    quantity: Some(3),
-                    ..Default::default()
-                },
-                Attribute {
-                    attribute: AttributeType::Time,
-                    quantity: Some(2),
-                    ..Default::default()
-                },
+                Attribute::Resource(Resource::Health, 3),
+                Attribute::Resource(Resource::Time, 2),
             ],
         }],
         choice_one_time_cost: Some(3),
         choice_two: vec![ChallengeBox {
-            dice_type: Some(AttributeType::Agility),
+            dice_type: Some(DiceColour::Agility),
             single_dice: false,
             total_value: 14,
             priority: false,
             consequences: vec![
-                Attribute {
-                    attribute: AttributeType::Health,
-                    quantity: Some(4),
-                    ..Default::default()
-                },
-                Attribute {
-                    attribute: AttributeType::Time,
-                    quantity: Some(1),
-                    ..Default::default()
-                },
+                Attribute::Resource(Resource::Health, 4),
+                Attribute::Resource(Resource::Time, 1),
             ],
         }],
         choice_two_time_cost: None,
@@ -422,32 +265,16 @@ fn get_boulder(is_option_one: bool) -> Peril {
 
 fn get_arrow_wall(is_option_one: bool) -> Peril {
     let item_reward = if is_option_one {
-        vec![Attribute {
-            attribute: AttributeType::Magic,
-            quantity: Some(1),
-            ..Default::default()
-        }]
+        vec![Attribute::DiceCount(DiceColour::Magic, 1)]
     } else {
-        vec![Attribute {
-            attribute: AttributeType::Strength,
-            quantity: Some(1),
-            ..Default::default()
-        }]
+        vec![Attribute::DiceCount(DiceColour::Strength, 1)]
     };
     let skill_reward = if is_option_one {
         Skill {
             name: String::from("MANA"),
             description: Some(String::from("Roll 3 x MAGIC dice")),
-            requirements: Some(Attribute {
-                attribute: AttributeType::Potion,
-                quantity: Some(1),
-                ..Default::default()
-            }),
-            effect: Effect::Roll(vec![Attribute {
-                attribute: AttributeType::Magic,
-                quantity: Some(3),
-                ..Default::default()
-            }]),
+            requirements: Some(Attribute::Resource(Resource::Potion, 1)),
+            effect: Effect::Roll(vec![Attribute::DiceCount(DiceColour::Magic, 3)]),
             encounters: vec![Encounter::Combat, Encounter::Peril, Encounter::Boss],
         }
     } else {
@@ -456,12 +283,8 @@ fn get_arrow_wall(is_option_one: bool) -> Peril {
             description: Some(String::from(
                 "Prevent 1 x HEALTH. In a boss fight prevent 2 x HEALTH.",
             )),
-            requirements: Some(Attribute {
-                attribute: AttributeType::Agility,
-                quantity: Some(1),
-                ..Default::default()
-            }),
-            effect: Effect::Prevent(AttributeType::Health),
+            requirements: Some(Attribute::DiceCount(DiceColour::Agility, 1)),
+            effect: Effect::Prevent(Resource::Health),
             encounters: vec![Encounter::Combat, Encounter::Boss],
         }
     };
@@ -469,40 +292,24 @@ fn get_arrow_wall(is_option_one: bool) -> Peril {
     Peril {
         name: String::from("Arrow Wall"),
         choice_one: vec![ChallengeBox {
-            dice_type: Some(AttributeType::Magic),
+            dice_type: Some(DiceColour::Magic),
             single_dice: false,
             total_value: 6,
             priority: false,
             consequences: vec![
-                Attribute {
-                    attribute: AttributeType::Health,
-                    quantity: Some(2),
-                    ..Default::default()
-                },
-                Attribute {
-                    attribute: AttributeType::Time,
-                    quantity: Some(3),
-                    ..Default::default()
-                },
+                Attribute::Resource(Resource::Health, 2),
+                Attribute::Resource(Resource::Time, 3),
             ],
         }],
         choice_one_time_cost: Some(1),
         choice_two: vec![ChallengeBox {
-            dice_type: Some(AttributeType::Agility),
+            dice_type: Some(DiceColour::Agility),
             single_dice: false,
             total_value: 11,
             priority: false,
             consequences: vec![
-                Attribute {
-                    attribute: AttributeType::Health,
-                    quantity: Some(3),
-                    ..Default::default()
-                },
-                Attribute {
-                    attribute: AttributeType::Time,
-                    quantity: Some(2),
-                    ..Default::default()
-                },
+                Attribute::Resource(Resource::Health, 3),
+                Attribute::Resource(Resource::Time, 2),
             ],
         }],
         choice_two_time_cost: None,
@@ -514,48 +321,24 @@ fn get_arrow_wall(is_option_one: bool) -> Peril {
 
 fn get_flame_statues(is_option_one: bool) -> Peril {
     let item_reward = if is_option_one {
-        vec![Attribute {
-            attribute: AttributeType::Magic,
-            quantity: Some(1),
-            ..Default::default()
-        }]
+        vec![Attribute::DiceCount(DiceColour::Magic, 1)]
     } else {
-        vec![Attribute {
-            attribute: AttributeType::Strength,
-            quantity: Some(1),
-            ..Default::default()
-        }]
+        vec![Attribute::DiceCount(DiceColour::Strength, 1)]
     };
     let skill_reward = if is_option_one {
         Skill {
             name: String::from("BACKSTAB"),
             description: Some(String::from("Roll 2 x STRENGTH dice.")),
-            requirements: Some(Attribute {
-                attribute: AttributeType::Agility,
-                quantity: Some(1),
-                ..Default::default()
-            }),
-            effect: Effect::Roll(vec![Attribute {
-                attribute: AttributeType::Strength,
-                quantity: Some(2),
-                ..Default::default()
-            }]),
+            requirements: Some(Attribute::DiceCount(DiceColour::Agility, 1)),
+            effect: Effect::Roll(vec![Attribute::DiceCount(DiceColour::Strength, 2)]),
             encounters: vec![Encounter::Combat, Encounter::Boss],
         }
     } else {
         Skill {
             name: String::from("HASTE"),
             description: Some(String::from("Roll 2 x AGILITY dice.")),
-            requirements: Some(Attribute {
-                attribute: AttributeType::Magic,
-                quantity: Some(1),
-                value: Some(3),
-            }),
-            effect: Effect::Gain(vec![Attribute {
-                attribute: AttributeType::Agility,
-                quantity: Some(2),
-                ..Default::default()
-            }]),
+            requirements: Some(Attribute::DieOfValue(DiceColour::Magic, 3)),
+            effect: Effect::Gain(vec![Attribute::DiceCount(DiceColour::Agility, 2)]),
             encounters: vec![Encounter::Combat, Encounter::Boss],
         }
     };
@@ -563,40 +346,24 @@ fn get_flame_statues(is_option_one: bool) -> Peril {
     Peril {
         name: String::from("Flame Statues"),
         choice_one: vec![ChallengeBox {
-            dice_type: Some(AttributeType::Magic),
+            dice_type: Some(DiceColour::Magic),
             single_dice: false,
             total_value: 8,
             priority: false,
             consequences: vec![
-                Attribute {
-                    attribute: AttributeType::Health,
-                    quantity: Some(2),
-                    ..Default::default()
-                },
-                Attribute {
-                    attribute: AttributeType::Time,
-                    quantity: Some(3),
-                    ..Default::default()
-                },
+                Attribute::Resource(Resource::Health, 2),
+                Attribute::Resource(Resource::Time, 3),
             ],
         }],
         choice_one_time_cost: Some(3),
         choice_two: vec![ChallengeBox {
-            dice_type: Some(AttributeType::Agility),
+            dice_type: Some(DiceColour::Agility),
             single_dice: false,
             total_value: 14,
             priority: false,
             consequences: vec![
-                Attribute {
-                    attribute: AttributeType::Health,
-                    quantity: Some(3),
-                    ..Default::default()
-                },
-                Attribute {
-                    attribute: AttributeType::Time,
-                    quantity: Some(1),
-                    ..Default::default()
-                },
+                Attribute::Resource(Resource::Health, 3),
+                Attribute::Resource(Resource::Time, 1),
             ],
         }],
         choice_two_time_cost: None,
@@ -611,11 +378,7 @@ fn get_spiked_log(is_option_one: bool) -> Peril {
         Skill {
             name: String::from("CLEAVE"),
             description: Some(String::from("Increase up to four of your dice by 1 each.")),
-            requirements: Some(Attribute {
-                attribute: AttributeType::Strength,
-                quantity: Some(1),
-                ..Default::default()
-            }),
+            requirements: Some(Attribute::DiceCount(DiceColour::Strength, 1)),
             effect: Effect::Increase(4),
             encounters: vec![Encounter::Combat, Encounter::Boss],
         }
@@ -623,20 +386,8 @@ fn get_spiked_log(is_option_one: bool) -> Peril {
         Skill {
             name: String::from("STATIC BURST"),
             description: Some(String::from("Gain a value 4 STRENGTH and a value 4 AGILITY. Then, increase one of your dice by 1.")),
-            requirements: Some(Attribute {
-                attribute: AttributeType::Magic,
-                quantity: Some(1),
-                value: Some(4),
-            }),
-            effect: Effect::Gain(vec![Attribute {
-                attribute: AttributeType::Strength,
-                quantity: Some(1),
-                value: Some(4),
-            }, Attribute {
-                attribute: AttributeType::Agility,
-                quantity: Some(1),
-                value: Some(4),
-            }]),
+            requirements: Some(Attribute::DieOfValue(DiceColour::Magic, 4)),
+            effect: Effect::Gain(vec![Attribute::DieOfValue(DiceColour::Strength, 4), Attribute::DieOfValue(DiceColour::Agility, 4)]),
             encounters: vec![Encounter::Combat, Encounter::Boss],
         }
     };
@@ -644,81 +395,45 @@ fn get_spiked_log(is_option_one: bool) -> Peril {
     Peril {
         name: String::from("Spiked Log"),
         choice_one: vec![ChallengeBox {
-            dice_type: Some(AttributeType::Strength),
+            dice_type: Some(DiceColour::Strength),
             single_dice: false,
             total_value: 8,
             priority: false,
             consequences: vec![
-                Attribute {
-                    attribute: AttributeType::Health,
-                    quantity: Some(3),
-                    ..Default::default()
-                },
-                Attribute {
-                    attribute: AttributeType::Time,
-                    quantity: Some(1),
-                    ..Default::default()
-                },
+                Attribute::Resource(Resource::Health, 3),
+                Attribute::Resource(Resource::Time, 1),
             ],
         }],
         choice_one_time_cost: Some(2),
         choice_two: vec![ChallengeBox {
-            dice_type: Some(AttributeType::Agility),
+            dice_type: Some(DiceColour::Agility),
             single_dice: false,
             total_value: 14,
             priority: false,
             consequences: vec![
-                Attribute {
-                    attribute: AttributeType::Health,
-                    quantity: Some(3),
-                    ..Default::default()
-                },
-                Attribute {
-                    attribute: AttributeType::Time,
-                    quantity: Some(2),
-                    ..Default::default()
-                },
+                Attribute::Resource(Resource::Health, 3),
+                Attribute::Resource(Resource::Time, 2),
             ],
         }],
         choice_two_time_cost: None,
         xp_reward: 3,
-        item_reward: vec![Attribute {
-            attribute: AttributeType::Agility,
-            quantity: Some(1),
-            ..Default::default()
-        }],
+        item_reward: vec![Attribute::DiceCount(DiceColour::Agility, 1)],
         skill_reward,
     }
 }
 
 fn get_bear_traps(is_option_one: bool) -> Peril {
     let item_reward = if is_option_one {
-        vec![Attribute {
-            attribute: AttributeType::Magic,
-            quantity: Some(1),
-            ..Default::default()
-        }]
+        vec![Attribute::DiceCount(DiceColour::Magic, 1)]
     } else {
-        vec![Attribute {
-            attribute: AttributeType::Agility,
-            quantity: Some(1),
-            ..Default::default()
-        }]
+        vec![Attribute::DiceCount(DiceColour::Agility, 1)]
     };
     let skill_reward = if is_option_one {
         Skill {
             name: String::from("MANA"),
             description: Some(String::from("Roll 3 x MAGIC dice.")),
-            requirements: Some(Attribute {
-                attribute: AttributeType::Potion,
-                quantity: Some(1),
-                ..Default::default()
-            }),
-            effect: Effect::Roll(vec![Attribute {
-                attribute: AttributeType::Magic,
-                quantity: Some(3),
-                ..Default::default()
-            }]),
+            requirements: Some(Attribute::Resource(Resource::Potion, 1)),
+            effect: Effect::Roll(vec![Attribute::DiceCount(DiceColour::Magic, 3)]),
             encounters: vec![Encounter::Combat, Encounter::Peril, Encounter::Boss],
         }
     } else {
@@ -727,16 +442,8 @@ fn get_bear_traps(is_option_one: bool) -> Peril {
             description: Some(String::from(
                 "Spend 2 x TIME before an encounter. Skip to the Claim Loot phase.",
             )),
-            requirements: Some(Attribute {
-                attribute: AttributeType::Potion,
-                quantity: Some(1),
-                ..Default::default()
-            }),
-            effect: Effect::Skip(Attribute {
-                attribute: AttributeType::Time,
-                quantity: Some(2),
-                ..Default::default()
-            }),
+            requirements: Some(Attribute::Resource(Resource::Potion, 1)),
+            effect: Effect::Skip(Attribute::Resource(Resource::Time, 2)),
             encounters: vec![Encounter::Combat, Encounter::Peril, Encounter::Boss],
         }
     };
@@ -744,40 +451,24 @@ fn get_bear_traps(is_option_one: bool) -> Peril {
     Peril {
         name: String::from("Bear Traps"),
         choice_one: vec![ChallengeBox {
-            dice_type: Some(AttributeType::Agility),
+            dice_type: Some(DiceColour::Agility),
             single_dice: false,
             total_value: 6,
             priority: false,
             consequences: vec![
-                Attribute {
-                    attribute: AttributeType::Health,
-                    quantity: Some(2),
-                    ..Default::default()
-                },
-                Attribute {
-                    attribute: AttributeType::Time,
-                    quantity: Some(1),
-                    ..Default::default()
-                },
+                Attribute::Resource(Resource::Health, 2),
+                Attribute::Resource(Resource::Time, 1),
             ],
         }],
         choice_one_time_cost: Some(3),
         choice_two: vec![ChallengeBox {
-            dice_type: Some(AttributeType::Agility),
+            dice_type: Some(DiceColour::Agility),
             single_dice: false,
             total_value: 11,
             priority: false,
             consequences: vec![
-                Attribute {
-                    attribute: AttributeType::Health,
-                    quantity: Some(3),
-                    ..Default::default()
-                },
-                Attribute {
-                    attribute: AttributeType::Time,
-                    quantity: Some(2),
-                    ..Default::default()
-                },
+                Attribute::Resource(Resource::Health, 3),
+                Attribute::Resource(Resource::Time, 2),
             ],
         }],
         choice_two_time_cost: None,
@@ -789,32 +480,16 @@ fn get_bear_traps(is_option_one: bool) -> Peril {
 
 fn get_pit_of_spikes(is_option_one: bool) -> Peril {
     let item_reward = if is_option_one {
-        vec![Attribute {
-            attribute: AttributeType::Strength,
-            quantity: Some(1),
-            ..Default::default()
-        }]
+        vec![Attribute::DiceCount(DiceColour::Strength, 1)]
     } else {
-        vec![Attribute {
-            attribute: AttributeType::Magic,
-            quantity: Some(1),
-            ..Default::default()
-        }]
+        vec![Attribute::DiceCount(DiceColour::Magic, 1)]
     };
     let skill_reward = if is_option_one {
         Skill {
             name: String::from("DEXTERITY"),
             description: Some(String::from("Gain a value 6 AGILITY dice")),
-            requirements: Some(Attribute {
-                attribute: AttributeType::Agility,
-                quantity: Some(1),
-                ..Default::default()
-            }),
-            effect: Effect::Gain(vec![Attribute {
-                attribute: AttributeType::Agility,
-                value: Some(6),
-                quantity: Some(1),
-            }]),
+            requirements: Some(Attribute::DiceCount(DiceColour::Agility, 1)),
+            effect: Effect::Gain(vec![Attribute::DieOfValue(DiceColour::Agility, 6)]),
             encounters: vec![Encounter::Combat, Encounter::Boss],
         }
     } else {
@@ -823,16 +498,8 @@ fn get_pit_of_spikes(is_option_one: bool) -> Peril {
             description: Some(String::from(
                 "Gain a value 6 HEROIC dice. You can only use it to cover a box with PRIORITY.",
             )),
-            requirements: Some(Attribute {
-                attribute: AttributeType::Strength,
-                quantity: Some(1),
-                ..Default::default()
-            }),
-            effect: Effect::Gain(vec![Attribute {
-                attribute: AttributeType::Heroic,
-                quantity: Some(1),
-                value: Some(6),
-            }]),
+            requirements: Some(Attribute::DiceCount(DiceColour::Strength, 1)),
+            effect: Effect::Gain(vec![Attribute::DieOfValue(DiceColour::Heroic, 6)]),
             encounters: vec![Encounter::Combat, Encounter::Boss],
         }
     };
@@ -840,40 +507,24 @@ fn get_pit_of_spikes(is_option_one: bool) -> Peril {
     Peril {
         name: String::from("Pit of Spikes"),
         choice_one: vec![ChallengeBox {
-            dice_type: Some(AttributeType::Strength),
+            dice_type: Some(DiceColour::Strength),
             single_dice: false,
             total_value: 8,
             priority: false,
             consequences: vec![
-                Attribute {
-                    attribute: AttributeType::Health,
-                    quantity: Some(2),
-                    ..Default::default()
-                },
-                Attribute {
-                    attribute: AttributeType::Time,
-                    quantity: Some(2),
-                    ..Default::default()
-                },
+                Attribute::Resource(Resource::Health, 2),
+                Attribute::Resource(Resource::Time, 2),
             ],
         }],
         choice_one_time_cost: Some(3),
         choice_two: vec![ChallengeBox {
-            dice_type: Some(AttributeType::Agility),
+            dice_type: Some(DiceColour::Agility),
             single_dice: false,
             total_value: 14,
             priority: false,
             consequences: vec![
-                Attribute {
-                    attribute: AttributeType::Health,
-                    quantity: Some(3),
-                    ..Default::default()
-                },
-                Attribute {
-                    attribute: AttributeType::Time,
-                    quantity: Some(2),
-                    ..Default::default()
-                },
+                Attribute::Resource(Resource::Health, 3),
+                Attribute::Resource(Resource::Time, 2),
             ],
         }],
         choice_two_time_cost: None,
@@ -886,57 +537,32 @@ fn get_pit_of_spikes(is_option_one: bool) -> Peril {
 fn get_force_wall(is_option_one: bool) -> Peril {
     let item_reward = if is_option_one {
         vec![
-            Attribute {
-                attribute: AttributeType::Strength,
-                quantity: Some(1),
-                ..Default::default()
-            },
-            Attribute {
-                attribute: AttributeType::Health,
-                quantity: Some(1),
-                ..Default::default()
-            },
+            Attribute::DiceCount(DiceColour::Strength, 1),
+            Attribute::Resource(Resource::Health, 1),
         ]
     } else {
         vec![
-            Attribute {
-                attribute: AttributeType::Magic,
-                quantity: Some(1),
-                ..Default::default()
-            },
-            Attribute {
-                attribute: AttributeType::Health,
-                quantity: Some(1),
-                ..Default::default()
-            },
+            Attribute::DiceCount(DiceColour::Magic, 1),
+            Attribute::Resource(Resource::Health, 1),
         ]
     };
     let skill_reward = if is_option_one {
         Skill {
             name: String::from("CRUSHING FIST"),
             description: Some(String::from("Gain 2 x STRENGTH with value of 6.")),
-            requirements: Some(Attribute {
-                attribute: AttributeType::Magic,
-                quantity: Some(1),
-                value: Some(6),
-            }),
-            effect: Effect::Gain(vec![Attribute {
-                attribute: AttributeType::Strength,
-                quantity: Some(2),
-                value: Some(6),
-            }]),
+            requirements: Some(Attribute::DieOfValue(DiceColour::Magic, 6)),
+            effect: Effect::Gain(vec![
+                Attribute::DieOfValue(DiceColour::Strength, 6),
+                Attribute::DieOfValue(DiceColour::Strength, 6),
+            ]),
             encounters: vec![Encounter::Combat, Encounter::Boss],
         }
     } else {
         Skill {
             name: String::from("POISON"),
             description: Some(String::from("Prevent up to 2 x TIME.")),
-            requirements: Some(Attribute {
-                attribute: AttributeType::Agility,
-                quantity: Some(1),
-                ..Default::default()
-            }),
-            effect: Effect::Prevent(AttributeType::Time),
+            requirements: Some(Attribute::DiceCount(DiceColour::Agility, 1)),
+            effect: Effect::Prevent(Resource::Time),
             encounters: vec![Encounter::Combat, Encounter::Boss],
         }
     };
@@ -944,40 +570,24 @@ fn get_force_wall(is_option_one: bool) -> Peril {
     Peril {
         name: String::from("Force Wall"),
         choice_one: vec![ChallengeBox {
-            dice_type: Some(AttributeType::Agility),
+            dice_type: Some(DiceColour::Agility),
             single_dice: false,
             total_value: 11,
             priority: false,
             consequences: vec![
-                Attribute {
-                    attribute: AttributeType::Health,
-                    quantity: Some(2),
-                    ..Default::default()
-                },
-                Attribute {
-                    attribute: AttributeType::Time,
-                    quantity: Some(4),
-                    ..Default::default()
-                },
+                Attribute::Resource(Resource::Health, 2),
+                Attribute::Resource(Resource::Time, 4),
             ],
         }],
         choice_one_time_cost: Some(3),
         choice_two: vec![ChallengeBox {
-            dice_type: Some(AttributeType::Magic),
+            dice_type: Some(DiceColour::Magic),
             single_dice: false,
             total_value: 14,
             priority: false,
             consequences: vec![
-                Attribute {
-                    attribute: AttributeType::Health,
-                    quantity: Some(4),
-                    ..Default::default()
-                },
-                Attribute {
-                    attribute: AttributeType::Time,
-                    quantity: Some(2),
-                    ..Default::default()
-                },
+                Attribute::Resource(Resource::Health, 4),
+                Attribute::Resource(Resource::Time, 2),
             ],
         }],
         choice_two_time_cost: None,
@@ -0,0 +1,57 @@
+use std::collections::BTreeMap;
+
+use crate::{
+    encounter::Encounter,
+    hero::{Attribute, DiceColour, Effect, Hero, HeroicFeat, PlayerCount, Skill},
+};
+
+use super::pack::{ContentPack, PROMO_PACK};
+
+/// Promotional cards handed out outside the core box. Only a hero so far.
+pub fn get_promo_pack(players: PlayerCount) -> ContentPack {
+    ContentPack {
+        name: String::from(PROMO_PACK),
+        heroes: vec![get_caliana(players)],
+        dungeons: Vec::new(),
+        perils: Vec::new(),
+        combats: Vec::new(),
+        bosses: Vec::new(),
+    }
+}
+
+pub fn get_caliana(players: PlayerCount) -> Hero {
+    let (magic, health) = match players {
+        PlayerCount::Solo => (5, 0),
+        PlayerCount::Coop => (4, 0),
+    };
+    Hero {
+        name: String::from("Caliana"),
+        attributes: BTreeMap::from([
+            (DiceColour::Strength, Attribute::DiceCount(DiceColour::Strength, 1)),
+            (DiceColour::Agility, Attribute::DiceCount(DiceColour::Agility, 1)),
+            (DiceColour::Magic, Attribute::DiceCount(DiceColour::Magic, magic)),
+        ]),
+        health,
+        players,
+        heroic_feat: HeroicFeat {
+            name: String::from("WHIMSICALITY"),
+            description: String::from("Convert 3 damage to time each turn (prevent 4 per boss round). If Caliana would take damage, the game ends."),
+            encounters: vec![
+                Encounter::Combat, Encounter::Peril, Encounter::Boss,
+            ]
+        },
+        skills: vec![Skill {
+            name: String::from("FAERIE FIRE"),
+            description: Some(String::from("Add X x STRENGTH and X x AGILITY.")),
+            requirements: Some(Attribute::DiceCount(DiceColour::Magic, 1)),
+            effect: Effect::Gain(vec![Attribute::DiceCount(DiceColour::Strength, 1), Attribute::DiceCount(DiceColour::Agility, 1)]),
+            encounters: vec![Encounter::Combat],
+        }],
+        levels: Hero::get_default_levels(),
+        current_level: 1,
+        potions: 1,
+        encounter_bonus: 0,
+        xp: 0,
+        items: Vec::new(),
+    }
+}
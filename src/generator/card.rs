@@ -0,0 +1,414 @@
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+use serde::Serialize;
+
+use crate::{
+    dungeon::ChallengeBox,
+    encounter::{Combat, Peril},
+    error::DataError,
+    game::Card,
+    game_setup,
+    hero::{Attribute, DiceColour, Effect, Hero, Resource, Skill},
+    lint::{self, LintIssue},
+    odds::{self, OddsOptions},
+};
+
+use super::{rate, reference_heroes, DifficultyBand, GeneratorError, Result, DEFAULT_MAX_ATTEMPTS};
+
+const COLOURS: [DiceColour; 3] = [DiceColour::Strength, DiceColour::Agility, DiceColour::Magic];
+
+const MONSTER_KINDS: [&str; 8] = [
+    "Cave", "Bone", "Frost", "Ember", "Rot", "Storm", "Shade", "Iron",
+];
+const MONSTERS: [&str; 8] = [
+    "Troll", "Bat", "Spider", "Golem", "Wisp", "Lurker", "Crawler", "Imp",
+];
+const HAZARDS: [&str; 6] = [
+    "Collapsing",
+    "Poisoned",
+    "Flooded",
+    "Crumbling",
+    "Haunted",
+    "Rusted",
+];
+const PLACES: [&str; 6] = ["Bridge", "Stairs", "Gate", "Corridor", "Chasm", "Altar"];
+
+/// The spread of ratings on the built-in combats, from Goblin to Ice Elemental.
+pub const COMBAT_BAND: DifficultyBand = DifficultyBand { min: 2.0, max: 7.5 };
+/// The spread of ratings on the easier option of the built-in perils.
+pub const PERIL_BAND: DifficultyBand = DifficultyBand { min: 0.4, max: 2.4 };
+
+/// A made-up card and the rating it was given, which for a peril is the rating
+/// of its easier option.
+#[derive(Debug, Clone, Serialize)]
+pub struct GeneratedCard {
+    pub card: Card,
+    pub rating: f64,
+}
+
+/// Makes up new combats and perils. Boxes are laid out the way the built-in
+/// cards lay them out and are redrawn until [`rate`] puts the card inside the
+/// band for its kind; the XP reward then follows from the rating the same way
+/// it does on the built-in cards. Combats only borrow the special abilities
+/// that [`odds::accounts_for`], so the rating covers what they do.
+#[derive(Debug, Clone)]
+pub struct CardGenerator {
+    rng: StdRng,
+    combat_band: DifficultyBand,
+    peril_band: DifficultyBand,
+    special_abilities: Vec<Effect>,
+    skills: Vec<Skill>,
+    heroes: Vec<Hero>,
+    max_attempts: usize,
+    options: OddsOptions,
+}
+
+impl CardGenerator {
+    pub fn new(seed: u64) -> CardGenerator {
+        let mut special_abilities: Vec<Effect> = Vec::new();
+        for combat in game_setup::get_all_combats() {
+            if odds::accounts_for(&combat.special_ability)
+                && !special_abilities.contains(&combat.special_ability)
+            {
+                special_abilities.push(combat.special_ability);
+            }
+        }
+        CardGenerator {
+            rng: StdRng::seed_from_u64(seed),
+            combat_band: COMBAT_BAND,
+            peril_band: PERIL_BAND,
+            special_abilities,
+            skills: game_setup::get_all_skills(),
+            heroes: reference_heroes(),
+            max_attempts: DEFAULT_MAX_ATTEMPTS,
+            options: OddsOptions::default(),
+        }
+    }
+
+    pub fn with_combat_band(mut self, band: DifficultyBand) -> CardGenerator {
+        self.combat_band = band;
+        self
+    }
+
+    pub fn with_peril_band(mut self, band: DifficultyBand) -> CardGenerator {
+        self.peril_band = band;
+        self
+    }
+
+    /// The skills rewards are drawn from, every skill in the game by default.
+    pub fn with_skills(mut self, skills: Vec<Skill>) -> CardGenerator {
+        self.skills = skills;
+        self
+    }
+
+    /// The heroes candidates are rated against.
+    pub fn with_heroes(mut self, heroes: Vec<Hero>) -> CardGenerator {
+        self.heroes = heroes;
+        self
+    }
+
+    pub fn with_max_attempts(mut self, max_attempts: usize) -> CardGenerator {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    pub fn generate_combat(&mut self) -> Result<GeneratedCard> {
+        for _ in 0..self.max_attempts {
+            let special_ability = self
+                .special_abilities
+                .choose(&mut self.rng)
+                .cloned()
+                .unwrap_or(Effect::None);
+            let challenges = random_combat_boxes(&mut self.rng);
+            let rating = rate(
+                &self.heroes,
+                &special_ability,
+                &challenges,
+                0,
+                &self.options,
+            )?;
+            if !self.combat_band.contains(rating) {
+                continue;
+            }
+            let xp_reward = match rating {
+                rating if rating < 3.0 => 2,
+                rating if rating < 5.0 => 3,
+                _ => 4,
+            };
+            let combat = Combat {
+                name: random_name(&mut self.rng, &MONSTER_KINDS, &MONSTERS),
+                special_ability,
+                challenges,
+                xp_reward,
+                item_reward: self.random_item(xp_reward),
+                skill_reward: self.random_skill()?,
+            };
+            check(&combat.name, lint::lint_combat(&combat))?;
+            return Ok(GeneratedCard {
+                card: Card::Combat(combat),
+                rating,
+            });
+        }
+        Err(GeneratorError::OutOfBand {
+            part: String::from("combat"),
+            band: self.combat_band,
+            attempts: self.max_attempts,
+        })
+    }
+
+    /// Perils have no special ability; they offer two options instead, a short
+    /// one that costs time up front and a longer one that does not.
+    pub fn generate_peril(&mut self) -> Result<GeneratedCard> {
+        for _ in 0..self.max_attempts {
+            let (first, second) = two_colours(&mut self.rng);
+            let choice_one = vec![ChallengeBox {
+                dice_type: Some(first),
+                total_value: *[6, 8, 11].choose(&mut self.rng).unwrap(),
+                single_dice: false,
+                priority: false,
+                consequences: vec![
+                    Attribute::Resource(Resource::Health, self.rng.gen_range(1..=3)),
+                    Attribute::Resource(Resource::Time, self.rng.gen_range(1..=4)),
+                ],
+            }];
+            let choice_one_time_cost = self.rng.gen_range(1..=3);
+            let choice_two = vec![ChallengeBox {
+                dice_type: Some(second),
+                total_value: *[11, 14].choose(&mut self.rng).unwrap(),
+                single_dice: false,
+                priority: false,
+                consequences: vec![
+                    Attribute::Resource(Resource::Health, self.rng.gen_range(2..=4)),
+                    Attribute::Resource(Resource::Time, self.rng.gen_range(1..=2)),
+                ],
+            }];
+            let rating = rate(
+                &self.heroes,
+                &Effect::None,
+                &choice_one,
+                choice_one_time_cost as usize,
+                &self.options,
+            )?
+            .min(rate(
+                &self.heroes,
+                &Effect::None,
+                &choice_two,
+                0,
+                &self.options,
+            )?);
+            if !self.peril_band.contains(rating) {
+                continue;
+            }
+            let xp_reward = match rating {
+                rating if rating < 0.8 => 2,
+                rating if rating < 1.9 => 3,
+                _ => 4,
+            };
+            let peril = Peril {
+                name: random_name(&mut self.rng, &HAZARDS, &PLACES),
+                choice_one,
+                choice_one_time_cost: Some(choice_one_time_cost),
+                choice_two,
+                choice_two_time_cost: None,
+                xp_reward,
+                item_reward: self.random_item(xp_reward),
+                skill_reward: self.random_skill()?,
+            };
+            check(&peril.name, lint::lint_peril(&peril))?;
+            return Ok(GeneratedCard {
+                card: Card::Peril(peril),
+                rating,
+            });
+        }
+        Err(GeneratorError::OutOfBand {
+            part: String::from("peril"),
+            band: self.peril_band,
+            attempts: self.max_attempts,
+        })
+    }
+
+    /// A die of any colour, with a point of health added now and then on the
+    /// cards worth the most XP.
+    fn random_item(&mut self, xp_reward: i8) -> Vec<Attribute> {
+        let colour = *COLOURS.choose(&mut self.rng).unwrap();
+        let mut item = vec![Attribute::DiceCount(colour, 1)];
+        if xp_reward >= 4 && self.rng.gen_bool(0.5) {
+            item.push(Attribute::Resource(Resource::Health, 1));
+        }
+        item
+    }
+
+    /// Any skill, drawn evenly whatever the card's rating. Beating a card wins
+    /// its skill, its item or its XP but never more than one, and the built-in
+    /// cards pair skills with easy and hard cards alike, so the skill is left
+    /// out of the card's balance.
+    fn random_skill(&mut self) -> Result<Skill> {
+        self.skills
+            .choose(&mut self.rng)
+            .cloned()
+            .ok_or(GeneratorError::NoSkills)
+    }
+}
+
+/// A combat's boxes: up to two priority boxes with nothing to lose, then three
+/// or four ordinary boxes that climb in value, all in two colours, with a big
+/// box that takes many dice now and then.
+fn random_combat_boxes(rng: &mut StdRng) -> Vec<ChallengeBox> {
+    let (main, other) = two_colours(rng);
+    let colour = |rng: &mut StdRng| {
+        if rng.gen_bool(0.6) {
+            main
+        } else {
+            other
+        }
+    };
+    let mut boxes = Vec::new();
+    for _ in 0..rng.gen_range(0..=2) {
+        let dice_type = Some(colour(rng));
+        boxes.push(if rng.gen_bool(0.3) {
+            ChallengeBox {
+                dice_type,
+                total_value: rng.gen_range(4..=11),
+                single_dice: false,
+                priority: true,
+                consequences: Vec::new(),
+            }
+        } else {
+            ChallengeBox {
+                dice_type,
+                total_value: rng.gen_range(2..=5),
+                single_dice: true,
+                priority: true,
+                consequences: Vec::new(),
+            }
+        });
+    }
+    let mut values: Vec<i8> = (0..rng.gen_range(3..=4))
+        .map(|_| rng.gen_range(3..=6))
+        .collect();
+    values.sort_unstable();
+    for total_value in values {
+        let dice_type = Some(colour(rng));
+        boxes.push(if rng.gen_bool(0.15) {
+            ChallengeBox {
+                dice_type,
+                total_value: total_value * 2,
+                single_dice: false,
+                priority: false,
+                consequences: vec![Attribute::Resource(Resource::Health, rng.gen_range(1..=3))],
+            }
+        } else {
+            ChallengeBox {
+                dice_type,
+                total_value,
+                single_dice: true,
+                priority: false,
+                consequences: random_consequences(rng),
+            }
+        });
+    }
+    boxes
+}
+
+fn random_consequences(rng: &mut StdRng) -> Vec<Attribute> {
+    match rng.gen_range(0..5) {
+        0 => vec![Attribute::Resource(Resource::Health, 1)],
+        1 => vec![Attribute::Resource(Resource::Health, 2)],
+        2 => vec![Attribute::Resource(Resource::Time, rng.gen_range(1..=2))],
+        _ => vec![
+            Attribute::Resource(Resource::Health, 1),
+            Attribute::Resource(Resource::Time, 1),
+        ],
+    }
+}
+
+fn two_colours(rng: &mut StdRng) -> (DiceColour, DiceColour) {
+    let mut colours = COLOURS.to_vec();
+    colours.shuffle(rng);
+    (colours[0], colours[1])
+}
+
+fn random_name(rng: &mut StdRng, first: &[&str], second: &[&str]) -> String {
+    format!(
+        "{} {}",
+        first.choose(rng).unwrap(),
+        second.choose(rng).unwrap()
+    )
+}
+
+fn check(card: &str, issues: Vec<LintIssue>) -> Result<()> {
+    if issues.is_empty() {
+        return Ok(());
+    }
+    Err(GeneratorError::Data(DataError::Invalid {
+        source: card.to_string(),
+        issues: issues.into_iter().map(|issue| issue.message).collect(),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hero_builder::HeroBuilder;
+
+    #[test]
+    fn only_borrows_rated_special_abilities() {
+        let generator = CardGenerator::new(1);
+        assert!(!generator.special_abilities.is_empty());
+        assert!(generator.special_abilities.iter().all(odds::accounts_for));
+        assert!(!generator.special_abilities.contains(&Effect::Swarm));
+    }
+
+    /// Rates against a single small hero so the exact odds stay quick.
+    fn generator(seed: u64) -> CardGenerator {
+        CardGenerator::new(seed).with_heroes(vec![HeroBuilder::new("Tester").hero().clone()])
+    }
+
+    fn cards(seed: u64) -> Vec<GeneratedCard> {
+        let mut generator = generator(seed);
+        let mut cards = Vec::new();
+        for _ in 0..2 {
+            cards.push(generator.generate_combat().unwrap());
+            cards.push(generator.generate_peril().unwrap());
+        }
+        cards
+    }
+
+    #[test]
+    fn same_seed_same_cards() {
+        assert_eq!(
+            serde_json::to_value(cards(4)).unwrap(),
+            serde_json::to_value(cards(4)).unwrap()
+        );
+    }
+
+    #[test]
+    fn cards_are_rated_inside_their_bands() {
+        let combat_band = DifficultyBand::new(3.0, 6.0);
+        let peril_band = DifficultyBand::new(0.8, 2.0);
+        let mut generator = generator(9)
+            .with_combat_band(combat_band)
+            .with_peril_band(peril_band);
+        for _ in 0..2 {
+            let combat = generator.generate_combat().unwrap();
+            assert!(combat_band.contains(combat.rating), "{}", combat.rating);
+            let peril = generator.generate_peril().unwrap();
+            assert!(peril_band.contains(peril.rating), "{}", peril.rating);
+        }
+        for card in cards(10) {
+            let band = match card.card {
+                Card::Combat(_) => COMBAT_BAND,
+                Card::Peril(_) => PERIL_BAND,
+            };
+            assert!(band.contains(card.rating), "{}", card.rating);
+        }
+    }
+
+    #[test]
+    fn generated_combats_have_rated_special_abilities() {
+        for card in cards(12) {
+            if let Card::Combat(combat) = card.card {
+                assert!(odds::accounts_for(&combat.special_ability));
+            }
+        }
+    }
+}
@@ -0,0 +1,326 @@
+use std::collections::BTreeMap;
+
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+use serde::Serialize;
+
+use crate::{
+    boss::Boss,
+    dungeon::{ChallengeBox, Dungeon},
+    dungeon_builder::DungeonBuilder,
+    encounter::Encounter,
+    game::{Dice, FINAL_FLOOR},
+    game_setup,
+    hero::{Attribute, DiceColour, Effect, Hero, Resource},
+    odds::OddsOptions,
+    solver::{self, SolverOptions},
+};
+
+use super::{rate, reference_heroes, DifficultyBand, GeneratorError, Result, DEFAULT_MAX_ATTEMPTS};
+
+const PLACES: [&str; 10] = [
+    "Lair",
+    "Crypt",
+    "Warren",
+    "Catacombs",
+    "Grotto",
+    "Sanctum",
+    "Hollow",
+    "Vault",
+    "Pit",
+    "Labyrinth",
+];
+
+const COMBAT_DICE: [DiceColour; 3] = [DiceColour::Strength, DiceColour::Agility, DiceColour::Magic];
+
+/// Random rolls per hero used to screen out candidate floors before rating the
+/// rest exactly.
+const SCREEN_SAMPLES: usize = 500;
+/// How far outside the band a screened rating may land and still be rated exactly.
+const SCREEN_MARGIN: f64 = 0.05;
+
+/// Built-in dungeons rate between nothing and about one lost health or time per
+/// encounter, climbing as the floors get deeper.
+pub fn default_band(floor: i8) -> DifficultyBand {
+    match floor {
+        1 => DifficultyBand::new(0.1, 0.4),
+        2 => DifficultyBand::new(0.25, 0.6),
+        _ => DifficultyBand::new(0.4, 0.9),
+    }
+}
+
+/// How one floor's peril or combat boxes were rated.
+#[derive(Debug, Clone, Serialize)]
+pub struct FloorRating {
+    pub floor: i8,
+    pub encounter: Encounter,
+    pub rating: f64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct GeneratedDungeon {
+    pub dungeon: Dungeon,
+    pub ratings: Vec<FloorRating>,
+}
+
+/// Makes up new dungeons. Every floor's peril and combat boxes are drawn at
+/// random and redrawn until [`rate`] puts them inside that floor's band, so the
+/// same seed and settings always give the same dungeon. Candidates are first
+/// rated from a sample of rolls, and only those that land near the band are
+/// rated exactly.
+#[derive(Debug, Clone)]
+pub struct DungeonGenerator {
+    rng: StdRng,
+    name: Option<String>,
+    boss: Option<Boss>,
+    bands: BTreeMap<i8, DifficultyBand>,
+    heroes: Vec<Hero>,
+    max_attempts: usize,
+    options: OddsOptions,
+    /// Exact ratings already worked out, since the same floor is often drawn twice.
+    rated: Vec<(Vec<ChallengeBox>, f64)>,
+}
+
+impl DungeonGenerator {
+    pub fn new(seed: u64) -> DungeonGenerator {
+        DungeonGenerator {
+            rng: StdRng::seed_from_u64(seed),
+            name: None,
+            boss: None,
+            bands: (1..=FINAL_FLOOR)
+                .map(|floor| (floor, default_band(floor)))
+                .collect(),
+            heroes: reference_heroes(),
+            max_attempts: DEFAULT_MAX_ATTEMPTS,
+            options: OddsOptions::default(),
+            rated: Vec::new(),
+        }
+    }
+
+    /// Left unset, the dungeon is named after its boss.
+    pub fn with_name(mut self, name: &str) -> DungeonGenerator {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    /// Left unset, a built-in boss is picked at random.
+    pub fn with_boss(mut self, boss: Boss) -> DungeonGenerator {
+        self.boss = Some(boss);
+        self
+    }
+
+    pub fn with_band(mut self, floor: i8, band: DifficultyBand) -> DungeonGenerator {
+        self.bands.insert(floor, band);
+        self
+    }
+
+    /// Uses `band` for every floor.
+    pub fn with_bands(mut self, band: DifficultyBand) -> DungeonGenerator {
+        for floor_band in self.bands.values_mut() {
+            *floor_band = band;
+        }
+        self
+    }
+
+    /// The heroes candidates are rated against.
+    pub fn with_heroes(mut self, heroes: Vec<Hero>) -> DungeonGenerator {
+        self.heroes = heroes;
+        self.rated.clear();
+        self
+    }
+
+    pub fn with_max_attempts(mut self, max_attempts: usize) -> DungeonGenerator {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    pub fn generate(&mut self) -> Result<GeneratedDungeon> {
+        let boss = match self.boss.clone() {
+            Some(boss) => boss,
+            None => game_setup::get_all_bosses()
+                .choose(&mut self.rng)
+                .cloned()
+                .expect("there are built-in bosses"),
+        };
+        let name = match self.name.clone() {
+            Some(name) => name,
+            None => format!("{}'s {}", boss.name, PLACES.choose(&mut self.rng).unwrap()),
+        };
+        let mut builder = DungeonBuilder::new(&name).with_boss(boss);
+        let mut ratings = Vec::new();
+        for (floor, band) in self.bands.clone() {
+            for encounter in [Encounter::Peril, Encounter::Combat] {
+                let (boxes, rating) = self.floor_boxes(floor, encounter, band)?;
+                builder = builder.with_floor(floor, encounter, boxes);
+                ratings.push(FloorRating {
+                    floor,
+                    encounter,
+                    rating,
+                });
+            }
+        }
+        let average =
+            ratings.iter().map(|rating| rating.rating).sum::<f64>() / ratings.len().max(1) as f64;
+        let difficulty = match average {
+            average if average < 0.3 => 1,
+            average if average < 0.6 => 2,
+            _ => 3,
+        };
+        let dungeon = builder.with_difficulty(difficulty).build()?;
+        Ok(GeneratedDungeon { dungeon, ratings })
+    }
+
+    fn floor_boxes(
+        &mut self,
+        floor: i8,
+        encounter: Encounter,
+        band: DifficultyBand,
+    ) -> Result<(Vec<ChallengeBox>, f64)> {
+        for _ in 0..self.max_attempts {
+            let boxes = random_floor(&mut self.rng, encounter);
+            let known = self.rated.iter().find(|(rated, _)| *rated == boxes);
+            if let Some((_, rating)) = known {
+                if band.contains(*rating) {
+                    return Ok((boxes, *rating));
+                }
+                continue;
+            }
+            let estimate = self.estimate(&boxes);
+            let near = DifficultyBand::new(band.min - SCREEN_MARGIN, band.max + SCREEN_MARGIN);
+            if !near.contains(estimate) {
+                continue;
+            }
+            let rating = rate(&self.heroes, &Effect::None, &boxes, 0, &self.options)?;
+            self.rated.push((boxes.clone(), rating));
+            if band.contains(rating) {
+                return Ok((boxes, rating));
+            }
+        }
+        Err(GeneratorError::OutOfBand {
+            part: format!(
+                "floor {} {} boxes",
+                floor,
+                encounter.to_string().to_lowercase()
+            ),
+            band,
+            attempts: self.max_attempts,
+        })
+    }
+
+    /// A rough [`rate`] for floor boxes from `SCREEN_SAMPLES` random rolls per
+    /// hero, placed by the solver the same way the odds place them.
+    fn estimate(&mut self, boxes: &[ChallengeBox]) -> f64 {
+        if self.heroes.is_empty() {
+            return 0.0;
+        }
+        let options = SolverOptions {
+            weights: self.options.weights,
+            heroic_cost: Some(2),
+        };
+        let mut total = 0;
+        for hero in self.heroes.iter() {
+            let mut dice: Vec<DiceColour> = COMBAT_DICE
+                .iter()
+                .flat_map(|colour| std::iter::repeat_n(*colour, hero.dice_count(colour)))
+                .collect();
+            dice.extend(std::iter::repeat_n(
+                DiceColour::Heroic,
+                hero.encounter_bonus.max(0) as usize,
+            ));
+            for _ in 0..SCREEN_SAMPLES {
+                let pool: Vec<Dice> = dice
+                    .iter()
+                    .map(|dice_type| Dice::roll(*dice_type, &mut self.rng))
+                    .collect();
+                let assignment = solver::solve(&pool, boxes, &options);
+                total += assignment.health + assignment.time;
+            }
+        }
+        total as f64 / (SCREEN_SAMPLES * self.heroes.len()) as f64
+    }
+}
+
+/// One or two boxes laid out like the built-in floors: perils take any dice,
+/// combats want a colour, and a single big box stands in for a pair now and
+/// then. Every box costs something when left open, so the rating sees it.
+fn random_floor(rng: &mut StdRng, encounter: Encounter) -> Vec<ChallengeBox> {
+    let dice_type = match encounter {
+        Encounter::Peril => None,
+        _ => COMBAT_DICE.choose(rng).cloned(),
+    };
+    if rng.gen_bool(0.15) {
+        return vec![ChallengeBox {
+            dice_type,
+            total_value: rng.gen_range(8..=12),
+            single_dice: false,
+            priority: rng.gen_bool(0.3),
+            consequences: random_consequences(rng),
+        }];
+    }
+    let count = if rng.gen_bool(0.3) { 2 } else { 1 };
+    (0..count)
+        .map(|index| ChallengeBox {
+            dice_type: match (&dice_type, index) {
+                (Some(_), 1) if rng.gen_bool(0.5) => COMBAT_DICE.choose(rng).cloned(),
+                _ => dice_type,
+            },
+            total_value: rng.gen_range(2..=6),
+            single_dice: true,
+            priority: rng.gen_bool(0.3),
+            consequences: random_consequences(rng),
+        })
+        .collect()
+}
+
+fn random_consequences(rng: &mut StdRng) -> Vec<Attribute> {
+    match rng.gen_range(0..4) {
+        0 => vec![Attribute::Resource(Resource::Time, rng.gen_range(1..=2))],
+        1 => vec![
+            Attribute::Resource(Resource::Health, 1),
+            Attribute::Resource(Resource::Time, 1),
+        ],
+        _ => vec![Attribute::Resource(Resource::Health, rng.gen_range(1..=2))],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn generator(seed: u64) -> DungeonGenerator {
+        // One hero keeps the exact ratings quick enough for a debug build.
+        DungeonGenerator::new(seed).with_heroes(vec![reference_heroes().remove(0)])
+    }
+
+    #[test]
+    fn same_seed_same_dungeon() {
+        let first = generator(3).generate().unwrap();
+        let second = generator(3).generate().unwrap();
+        assert_eq!(
+            serde_json::to_value(&first).unwrap(),
+            serde_json::to_value(&second).unwrap()
+        );
+    }
+
+    #[test]
+    fn floors_are_rated_inside_their_bands() {
+        let band = DifficultyBand::new(0.2, 0.6);
+        for (mut generator, band_for) in [
+            (generator(5), None),
+            (generator(6).with_bands(band), Some(band)),
+        ] {
+            let generated = generator.generate().unwrap();
+            assert_eq!(generated.ratings.len(), FINAL_FLOOR as usize * 2);
+            for rating in generated.ratings.iter() {
+                let band = band_for.unwrap_or_else(|| default_band(rating.floor));
+                assert!(
+                    band.contains(rating.rating),
+                    "{:?} outside {:?}",
+                    rating,
+                    band
+                );
+            }
+            let issues = DungeonBuilder::from_dungeon(generated.dungeon).validate();
+            assert!(issues.is_empty(), "{:?}", issues);
+        }
+    }
+}
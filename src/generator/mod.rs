@@ -0,0 +1,129 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    dungeon::ChallengeBox,
+    error::DataError,
+    game_setup::pack,
+    hero::{Effect, Hero, PlayerCount},
+    odds::{self, OddsError, OddsOptions},
+};
+
+pub mod card;
+pub mod dungeon;
+
+/// Candidates tried for each floor or card before giving up on the band.
+pub const DEFAULT_MAX_ATTEMPTS: usize = 200;
+
+#[derive(Debug)]
+pub enum GeneratorError {
+    /// None of the `attempts` candidates for `part` were rated inside `band`.
+    OutOfBand {
+        part: String,
+        band: DifficultyBand,
+        attempts: usize,
+    },
+    /// A card needs a skill reward but there were no skills to draw from.
+    NoSkills,
+    Odds(OddsError),
+    Data(DataError),
+}
+
+impl Display for GeneratorError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GeneratorError::OutOfBand {
+                part,
+                band,
+                attempts,
+            } => write!(
+                f,
+                "no {} out of {} tries was rated within {}",
+                part, attempts, band
+            ),
+            GeneratorError::NoSkills => write!(f, "there are no skills to reward"),
+            GeneratorError::Odds(err) => write!(f, "could not rate a candidate: {}", err),
+            GeneratorError::Data(err) => write!(f, "generated an invalid card: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for GeneratorError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            GeneratorError::OutOfBand { .. } | GeneratorError::NoSkills => None,
+            GeneratorError::Odds(err) => Some(err),
+            GeneratorError::Data(err) => Some(err),
+        }
+    }
+}
+
+impl From<OddsError> for GeneratorError {
+    fn from(err: OddsError) -> Self {
+        GeneratorError::Odds(err)
+    }
+}
+
+impl From<DataError> for GeneratorError {
+    fn from(err: DataError) -> Self {
+        GeneratorError::Data(err)
+    }
+}
+
+pub type Result<T> = std::result::Result<T, GeneratorError>;
+
+/// An inclusive range of ratings, as worked out by [`rate`].
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct DifficultyBand {
+    pub min: f64,
+    pub max: f64,
+}
+
+impl DifficultyBand {
+    pub fn new(min: f64, max: f64) -> DifficultyBand {
+        DifficultyBand { min, max }
+    }
+
+    /// Reads a band written as `MIN-MAX`, e.g. `0.2-0.5`.
+    pub fn parse(range: &str) -> Option<DifficultyBand> {
+        let (min, max) = range.split_once('-')?;
+        let band = DifficultyBand::new(min.trim().parse().ok()?, max.trim().parse().ok()?);
+        (band.min >= 0.0 && band.min <= band.max).then_some(band)
+    }
+
+    pub fn contains(&self, rating: f64) -> bool {
+        self.min <= rating && rating <= self.max
+    }
+}
+
+impl Display for DifficultyBand {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:.2}-{:.2}", self.min, self.max)
+    }
+}
+
+/// The heroes ratings are averaged over: every core hero, solo side, at level 1.
+pub fn reference_heroes() -> Vec<Hero> {
+    pack::get_core_pack(PlayerCount::Solo).heroes
+}
+
+/// Rates `boxes` as the health plus time a hero expects to lose to them, on top
+/// of `time_cost`, averaged over `heroes`. Zero means nothing is ever lost.
+pub fn rate(
+    heroes: &[Hero],
+    special_ability: &Effect,
+    boxes: &[ChallengeBox],
+    time_cost: usize,
+    options: &OddsOptions,
+) -> odds::Result<f64> {
+    if heroes.is_empty() {
+        return Ok(0.0);
+    }
+    let mut total = 0.0;
+    for hero in heroes {
+        let odds = odds::encounter_odds(hero, special_ability, boxes, time_cost, options)?;
+        total += odds.expected_health() + odds.expected_time();
+    }
+    Ok(total / heroes.len() as f64)
+}
@@ -1,27 +1,58 @@
-use std::{
-    cmp::Ordering,
-    collections::{BTreeMap, HashMap},
-    fmt::{Display, Error},
-};
+use std::{collections::BTreeMap, fmt::Display};
+
+use serde::{Deserialize, Serialize};
 
 use crate::encounter::Encounter;
 
+/// Hero cards are printed with a solo side and a side for two-player co-op,
+/// which trades some starting dice and health for having a partner.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum PlayerCount {
+    #[default]
+    Solo,
+    Coop,
+}
+
+impl Display for PlayerCount {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PlayerCount::Solo => write!(f, "Solo"),
+            PlayerCount::Coop => write!(f, "Co-op"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HeroicFeat {
     pub name: String,
     pub description: String,
     pub encounters: Vec<Encounter>,
 }
 
-#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+/// The colour of a dice, as rolled, counted on a hero card or asked for by a
+/// challenge box.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Serialize, Deserialize)]
+pub enum DiceColour {
+    Strength,
+    Agility,
+    Magic,
+    Heroic,
+}
+
+impl Display for DiceColour {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+/// The dice a skill's effect picks out: dice of one colour, dice showing one of
+/// the given values, or any dice.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize, Deserialize)]
 pub enum AttributeType {
     Strength,
     Agility,
     Magic,
     Heroic,
-    Health,
-    Time,
-    Door,
-    Potion,
     Value(Vec<i8>),
     Default,
 }
@@ -32,77 +63,57 @@ impl Display for AttributeType {
     }
 }
 
-impl PartialOrd for AttributeType {
-    fn partial_cmp(&self, other: &AttributeType) -> Option<Ordering> {
-        Some(self.cmp(other))
-    }
-}
-
-impl Ord for AttributeType {
-    fn cmp(&self, other: &Self) -> Ordering {
-        if self == other {
-            return Ordering::Equal;
-        }
-        match self {
-            AttributeType::Strength => Ordering::Less,
-            AttributeType::Default => Ordering::Greater,
-            AttributeType::Agility => {
-                if other == &AttributeType::Strength {
-                    Ordering::Greater
-                } else {
-                    Ordering::Less
-                }
-            }
-            AttributeType::Magic => {
-                if other == &AttributeType::Strength || other == &AttributeType::Agility {
-                    Ordering::Greater
-                } else {
-                    Ordering::Less
-                }
-            }
-            AttributeType::Health => {
-                if other == &AttributeType::Strength
-                    || other == &AttributeType::Agility
-                    || other == &AttributeType::Magic
-                {
-                    Ordering::Greater
-                } else {
-                    Ordering::Less
-                }
-            }
-            _ => self.to_string().cmp(&other.to_string()),
+impl From<DiceColour> for AttributeType {
+    fn from(colour: DiceColour) -> AttributeType {
+        match colour {
+            DiceColour::Strength => AttributeType::Strength,
+            DiceColour::Agility => AttributeType::Agility,
+            DiceColour::Magic => AttributeType::Magic,
+            DiceColour::Heroic => AttributeType::Heroic,
         }
     }
 }
 
-pub struct Attribute {
-    pub attribute: AttributeType,
-    pub quantity: Option<usize>,
-    pub value: Option<i8>,
+/// Things that are spent or gained, rather than rolled as dice.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Serialize, Deserialize)]
+pub enum Resource {
+    Health,
+    Time,
+    Potion,
+    Door,
 }
 
-impl Display for Attribute {
+impl Display for Resource {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        if self.quantity.is_some() && self.value.is_none() {
-            write!(f, "{} x {}", self.quantity.unwrap(), self.attribute)
-        } else if self.quantity.is_none() && self.value.is_some() {
-            write!(f, "{} value {}", self.attribute, self.value.unwrap())
-        } else {
-            Err(Error)
-        }
+        write!(f, "{:?}", self)
     }
 }
 
-impl Default for Attribute {
-    fn default() -> Self {
-        Attribute {
-            attribute: AttributeType::Default,
-            quantity: None,
-            value: None,
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+pub enum Attribute {
+    /// A number of dice of the given type, e.g. "2 x Strength".
+    DiceCount(DiceColour, usize),
+    /// A single die of the given type showing the given value, e.g. "Magic value 6".
+    DieOfValue(DiceColour, i8),
+    /// An amount of a resource, e.g. "1 x Health" or "2 x Time".
+    Resource(Resource, usize),
+}
+
+impl Display for Attribute {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Attribute::DiceCount(colour, quantity) => {
+                write!(f, "{} x {}", quantity, colour)
+            }
+            Attribute::DieOfValue(colour, value) => {
+                write!(f, "{} value {}", colour, value)
+            }
+            Attribute::Resource(resource, quantity) => write!(f, "{} x {}", quantity, resource),
         }
     }
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Skill {
     pub name: String,
     pub description: Option<String>,
@@ -111,6 +122,7 @@ pub struct Skill {
     pub encounters: Vec<Encounter>,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Effect {
     Gain(Vec<Attribute>),
     Roll(Vec<Attribute>),
@@ -120,7 +132,7 @@ pub enum Effect {
         attribute_type: AttributeType,
         value: usize,
     },
-    Prevent(AttributeType),
+    Prevent(Resource),
     Discard(AttributeType),
     Heal(usize),
     Skip(Attribute),
@@ -198,117 +210,201 @@ impl Display for Effect {
 
 type Result<T> = std::result::Result<T, HeroError>;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum HeroError {
-    AttributeNotFound,
-    QuantityNegative,
-    ValueNotFound,
-    DuplicateSkill,
-    LevelTooHigh,
+    AttributeNotFound(DiceColour),
+    QuantityNegative { attribute: String, quantity: isize },
+    NotADiceCount(DiceColour),
+    DuplicateSkill(String),
+    LevelTooHigh { level: i8, max_level: i8 },
 }
 
 impl Display for HeroError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "error updating the Hero stats")
+        match self {
+            HeroError::AttributeNotFound(colour) => {
+                write!(f, "the hero has no {} attribute", colour)
+            }
+            HeroError::QuantityNegative {
+                attribute,
+                quantity,
+            } => write!(
+                f,
+                "{} would drop to {}, which is below zero",
+                attribute, quantity
+            ),
+            HeroError::NotADiceCount(colour) => {
+                write!(f, "the hero's {} attribute is not a dice count", colour)
+            }
+            HeroError::DuplicateSkill(name) => {
+                write!(f, "the hero already has the skill {}", name)
+            }
+            HeroError::LevelTooHigh { level, max_level } => write!(
+                f,
+                "cannot descend to level {}, the deepest level is {}",
+                level, max_level
+            ),
+        }
     }
 }
 
-// #[derive(Debug, Clone)]
-// pub struct AttributeNotFoundError;
-// #[derive(Debug, Clone)]
-// pub struct QuantityNegativeError;
-// #[derive(Debug, Clone)]
-// pub struct ValueNotFoundError;
+impl std::error::Error for HeroError {}
+
+/// An encounter card kept as loot for the dice and health it grants.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Item {
+    pub name: String,
+    pub attributes: Vec<Attribute>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LevelStats {
+    pub items: i8,
+    pub skills: i8,
+    pub potions: i8,
+    pub encounter_bonus: i8,
+    pub xp_to_next: i8,
+}
+
+pub type LevelTable = BTreeMap<i8, LevelStats>;
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Hero {
     pub name: String,
-    pub attributes: BTreeMap<AttributeType, Attribute>,
+    pub attributes: BTreeMap<DiceColour, Attribute>,
+    pub health: usize,
+    /// Which side of the hero card the dice, health and levels come from.
+    pub players: PlayerCount,
     pub heroic_feat: HeroicFeat,
     pub skills: Vec<Skill>,
-    pub levels: HashMap<i8, HashMap<String, i8>>,
+    pub levels: LevelTable,
     pub current_level: i8,
     pub potions: i8,
     pub encounter_bonus: i8,
+    pub xp: i8,
+    pub items: Vec<Item>,
 }
 
 impl Hero {
     pub fn change_attribute_quantity(
         &mut self,
-        attribute: AttributeType,
+        colour: DiceColour,
         change_by: i8,
     ) -> Result<usize> {
         let attr = self
             .attributes
-            .get(&attribute)
-            .ok_or(HeroError::AttributeNotFound)?;
-        let quantity = attr.quantity.unwrap_or(0) as i8 + change_by;
-        if quantity < 0 {
-            return Err(HeroError::QuantityNegative);
-        }
-        let quantity = quantity as usize;
-        let new_attr = Attribute {
-            attribute: attribute.clone(),
-            quantity: Some(quantity),
-            value: attr.value,
+            .get(&colour)
+            .ok_or(HeroError::AttributeNotFound(colour))?;
+        let current = match attr {
+            Attribute::DiceCount(_, quantity) => *quantity,
+            _ => return Err(HeroError::NotADiceCount(colour)),
         };
+        let quantity =
+            current
+                .checked_add_signed(change_by as isize)
+                .ok_or(HeroError::QuantityNegative {
+                    attribute: colour.to_string(),
+                    quantity: current as isize + change_by as isize,
+                })?;
         self.attributes
-            .insert(attribute.clone(), new_attr)
-            .ok_or(HeroError::AttributeNotFound)?;
+            .insert(colour, Attribute::DiceCount(colour, quantity));
 
         Ok(quantity)
     }
 
+    pub fn change_health(&mut self, change_by: i8) -> Result<usize> {
+        self.health = self.health.checked_add_signed(change_by as isize).ok_or(
+            HeroError::QuantityNegative {
+                attribute: Resource::Health.to_string(),
+                quantity: self.health as isize + change_by as isize,
+            },
+        )?;
+        Ok(self.health)
+    }
+
     pub fn add_skill(&mut self, new_skill: Skill) -> Result<()> {
         for existing_skill in self.skills.iter() {
             if existing_skill.name == new_skill.name {
-                return Err(HeroError::DuplicateSkill);
+                return Err(HeroError::DuplicateSkill(new_skill.name));
             }
         }
         self.skills.push(new_skill);
         Ok(())
     }
 
-    pub fn descend_level(&mut self) -> Result<i8> {
-        if self.current_level + 1 > *self.levels.keys().max().unwrap_or(&1) {
-            return Err(HeroError::LevelTooHigh);
-        } else {
-            self.current_level += 1;
-            self.potions += 1;
-            self.encounter_bonus = *self
-                .levels
-                .get(&self.current_level)
-                .ok_or(HeroError::AttributeNotFound)?
-                .get("Encounter Bonus")
-                .ok_or(HeroError::AttributeNotFound)?;
+    pub fn add_item(&mut self, item: Item) -> Result<()> {
+        for attribute in item.attributes.iter() {
+            match attribute {
+                Attribute::DiceCount(colour, quantity) => {
+                    self.change_attribute_quantity(*colour, *quantity as i8)?;
+                }
+                Attribute::Resource(Resource::Health, quantity) => {
+                    self.change_health(*quantity as i8)?;
+                }
+                Attribute::Resource(Resource::Potion, quantity) => {
+                    self.potions += *quantity as i8;
+                }
+                _ => {}
+            }
+        }
+        self.items.push(item);
+        Ok(())
+    }
+
+    /// Returns how many dice of the given colour the hero rolls.
+    pub fn dice_count(&self, colour: &DiceColour) -> usize {
+        match self.attributes.get(colour) {
+            Some(Attribute::DiceCount(_, quantity)) => *quantity,
+            _ => 0,
         }
+    }
+
+    pub fn descend_level(&mut self) -> Result<i8> {
+        let next_level = self.current_level + 1;
+        let stats = *self
+            .levels
+            .get(&next_level)
+            .ok_or(HeroError::LevelTooHigh {
+                level: next_level,
+                max_level: *self.levels.keys().max().unwrap_or(&self.current_level),
+            })?;
+        self.current_level = next_level;
+        self.potions += 1;
+        self.encounter_bonus = stats.encounter_bonus;
         Ok(self.current_level)
     }
 
-    pub fn get_mage() -> Hero {
+    /// Returns the level stats for the hero's current level.
+    pub fn level_stats(&self) -> Option<&LevelStats> {
+        self.levels.get(&self.current_level)
+    }
+
+    /// Replaces the stats for a single level.
+    pub fn with_level_override(mut self, level: i8, stats: LevelStats) -> Hero {
+        self.levels.insert(level, stats);
+        self
+    }
+
+    /// Replaces the whole level table for this hero.
+    pub fn with_levels(mut self, levels: LevelTable) -> Hero {
+        self.levels = levels;
+        self
+    }
+
+    pub fn get_mage(players: PlayerCount) -> Hero {
+        let (magic, health) = match players {
+            PlayerCount::Solo => (4, 5),
+            PlayerCount::Coop => (3, 4),
+        };
         Hero {
             name: String::from("Mage"),
             attributes: BTreeMap::from([
-                (AttributeType::Strength, Attribute {
-                    attribute: AttributeType::Strength,
-                    quantity: Some(1),
-                    ..Default::default()
-                }),
-                (AttributeType::Agility, Attribute{
-                    attribute: AttributeType::Agility,
-                    quantity: Some(2),
-                    ..Default::default()
-                }),
-                (AttributeType::Magic, Attribute{
-                    attribute: AttributeType::Magic,
-                    quantity: Some(4),
-                    ..Default::default()
-                }),
-                (AttributeType::Health, Attribute{
-                    attribute: AttributeType::Health,
-                    quantity: Some(5),
-                    ..Default::default()
-                }),
+                (DiceColour::Strength, Attribute::DiceCount(DiceColour::Strength, 1)),
+                (DiceColour::Agility, Attribute::DiceCount(DiceColour::Agility, 2)),
+                (DiceColour::Magic, Attribute::DiceCount(DiceColour::Magic, magic)),
             ]),
+            health,
+            players,
             heroic_feat: HeroicFeat {
                 name: String::from("MANA CHARGE"),
                 description: String::from("Roll any or all of your dice stored here.\nStore a HEROIC DICE here when you explore or flee. You may store up to two dice at a time."),
@@ -320,77 +416,32 @@ impl Hero {
                 name: String::from("SHIELD AURA"),
                 description: Some(String::from("Prevent HEALTH.")),
                 requirements: None,
-                effect: Effect::Prevent(AttributeType::Health),
+                effect: Effect::Prevent(Resource::Health),
                 encounters: vec![Encounter::Peril]
             },],
             levels: Self::get_default_levels(),
             current_level: 1,
             potions: 1,
             encounter_bonus: 0,
+            xp: 0,
+            items: Vec::new(),
         }
     }
 
-    pub fn get_caliana() -> Hero {
-        Hero {
-            name: String::from("Caliana"),
-            attributes: BTreeMap::from([
-                (AttributeType::Strength, Attribute {
-                    attribute: AttributeType::Strength,
-                    quantity: Some(1),
-                    ..Default::default()
-                }),
-                (AttributeType::Agility, Attribute{
-                    attribute: AttributeType::Agility,
-                    quantity: Some(1),
-                    ..Default::default()
-                }),
-                (AttributeType::Magic, Attribute{
-                    attribute: AttributeType::Magic,
-                    quantity: Some(5),
-                    ..Default::default()
-                }),
-                (AttributeType::Health, Attribute{
-                    attribute: AttributeType::Health,
-                    quantity: Some(0),
-                    ..Default::default()
-                }),
-            ]),
-            heroic_feat: HeroicFeat {
-                name: String::from("WHIMSICALITY"),
-                description: String::from("Convert 3 damage to time each turn (prevent 4 per boss round). If Caliana would take damage, the game ends."),
-                encounters: vec![
-                    Encounter::Combat, Encounter::Peril, Encounter::Boss,
-                ]
-            },
-            skills: vec![Skill {
-                name: String::from("FAERIE FIRE"),
-                description: Some(String::from("Add X x STRENGTH and X x AGILITY.")),
-                requirements: Some(Attribute { attribute: AttributeType::Magic, quantity: Some(1), ..Default::default() }),
-                effect: Effect::Gain(vec![Attribute{attribute: AttributeType::Strength, quantity: Some(1),
-                    ..Default::default()}, Attribute{attribute: AttributeType::Agility, quantity: Some(1),
-                        ..Default::default()}]),
-                encounters: vec![Encounter::Combat],
-            }],
-            levels: Self::get_default_levels(),
-            current_level: 1,
-            potions: 1,
-            encounter_bonus: 0,
-        }
-    }
-
-    pub fn get_paladin() -> Hero {
+    pub fn get_paladin(players: PlayerCount) -> Hero {
+        let (strength, health) = match players {
+            PlayerCount::Solo => (3, 5),
+            PlayerCount::Coop => (2, 4),
+        };
         Hero {
             name: String::from("Paladin"),
             attributes: BTreeMap::from([
-                (AttributeType::Strength, Attribute {attribute: AttributeType::Strength, quantity: Some(3),
-                    ..Default::default()}),
-                (AttributeType::Agility, Attribute {attribute: AttributeType::Agility, quantity: Some(1),
-                    ..Default::default()}),
-                (AttributeType::Magic, Attribute {attribute: AttributeType::Magic, quantity: Some(3),
-                    ..Default::default()}),
-                (AttributeType::Health, Attribute {attribute: AttributeType::Health, quantity: Some(5),
-                    ..Default::default()}),
+                (DiceColour::Strength, Attribute::DiceCount(DiceColour::Strength, strength)),
+                (DiceColour::Agility, Attribute::DiceCount(DiceColour::Agility, 1)),
+                (DiceColour::Magic, Attribute::DiceCount(DiceColour::Magic, 3)),
             ]),
+            health,
+            players,
             heroic_feat: HeroicFeat {
                 name: String::from("VALIANT"),
                 description: String::from("Roll any or all of your dice stored here.\nStore a HEROIC DICE here when you open a door with 4+ XP. You may store up to two dice at a time."),
@@ -401,7 +452,7 @@ impl Hero {
                     name: String::from("ARMOR"),
                     description: Some(String::from("For every 2 x HEALTH you would lose, prevent 1 x HEALTH. You cannot prevent damage otherwise.")),
                     requirements: None,
-                    effect: Effect::Prevent(AttributeType::Health),
+                    effect: Effect::Prevent(Resource::Health),
                     encounters: vec![Encounter::Combat, Encounter::Peril],
                 }
             ],
@@ -409,22 +460,25 @@ impl Hero {
             current_level: 1,
             potions: 1,
             encounter_bonus: 0,
+            xp: 0,
+            items: Vec::new(),
         }
     }
 
-    pub fn get_warrior() -> Hero {
+    pub fn get_warrior(players: PlayerCount) -> Hero {
+        let (strength, health) = match players {
+            PlayerCount::Solo => (4, 6),
+            PlayerCount::Coop => (3, 5),
+        };
         Hero {
             name: String::from("Warrior"),
             attributes: BTreeMap::from([
-                (AttributeType::Strength, Attribute {attribute: AttributeType::Strength, quantity: Some(4),
-                    ..Default::default()}),
-                (AttributeType::Agility, Attribute {attribute: AttributeType::Agility, quantity: Some(2),
-                    ..Default::default()}),
-                (AttributeType::Magic, Attribute {attribute: AttributeType::Magic, quantity: Some(1),
-                    ..Default::default()}),
-                (AttributeType::Health, Attribute {attribute: AttributeType::Health, quantity: Some(6),
-                    ..Default::default()}),
+                (DiceColour::Strength, Attribute::DiceCount(DiceColour::Strength, strength)),
+                (DiceColour::Agility, Attribute::DiceCount(DiceColour::Agility, 2)),
+                (DiceColour::Magic, Attribute::DiceCount(DiceColour::Magic, 1)),
             ]),
+            health,
+            players,
             heroic_feat: HeroicFeat {
                 name: String::from("FRENZY"),
                 description: String::from("Roll any or all of your dice stored here.\nStore a HEROIC DICE here for each damage you take. You may store up to two dice at a time."),
@@ -443,22 +497,25 @@ impl Hero {
             current_level: 1,
             potions: 1,
             encounter_bonus: 0,
+            xp: 0,
+            items: Vec::new(),
         }
     }
 
-    pub fn get_rogue() -> Hero {
+    pub fn get_rogue(players: PlayerCount) -> Hero {
+        let (agility, health) = match players {
+            PlayerCount::Solo => (4, 5),
+            PlayerCount::Coop => (3, 4),
+        };
         Hero {
             name: String::from("Rogue"),
             attributes: BTreeMap::from([
-                (AttributeType::Strength, Attribute {attribute: AttributeType::Strength, quantity: Some(1),
-                    ..Default::default()}),
-                (AttributeType::Agility, Attribute {attribute: AttributeType::Agility, quantity: Some(4),
-                    ..Default::default()}),
-                (AttributeType::Magic, Attribute {attribute: AttributeType::Magic, quantity: Some(2),
-                    ..Default::default()}),
-                (AttributeType::Health, Attribute {attribute: AttributeType::Health, quantity: Some(5),
-                    ..Default::default()}),
+                (DiceColour::Strength, Attribute::DiceCount(DiceColour::Strength, 1)),
+                (DiceColour::Agility, Attribute::DiceCount(DiceColour::Agility, agility)),
+                (DiceColour::Magic, Attribute::DiceCount(DiceColour::Magic, 2)),
             ]),
+            health,
+            players,
             heroic_feat: HeroicFeat {
                 name: String::from("DARING GAMBLE"),
                 description: String::from("Roll one or two MAGIC DICE. If either is a 1, lose 1 x HEALTH and 3 x TIME. Do this before checking any other effects."),
@@ -469,8 +526,7 @@ impl Hero {
                     name: String::from("STEALTH"),
                     description: Some(String::from("When you flee you may add one door to the dungeon, if under the door limit.")),
                     requirements: None,
-                    effect: Effect::Gain(vec![Attribute {attribute: AttributeType::Door, quantity: Some(1),
-                        ..Default::default()}]),
+                    effect: Effect::Gain(vec![Attribute::Resource(Resource::Door, 1)]),
                     encounters: vec![Encounter::Combat, Encounter::Peril],
                 }
             ],
@@ -478,22 +534,25 @@ impl Hero {
             current_level: 1,
             potions: 1,
             encounter_bonus: 0,
+            xp: 0,
+            items: Vec::new(),
         }
     }
 
-    pub fn get_archer() -> Hero {
+    pub fn get_archer(players: PlayerCount) -> Hero {
+        let (agility, health) = match players {
+            PlayerCount::Solo => (3, 5),
+            PlayerCount::Coop => (2, 4),
+        };
         Hero {
             name: String::from("Archer"),
             attributes: BTreeMap::from([
-                (AttributeType::Strength, Attribute {attribute: AttributeType::Strength, quantity: Some(2),
-                    ..Default::default()}),
-                (AttributeType::Agility, Attribute {attribute: AttributeType::Agility, quantity: Some(3),
-                    ..Default::default()}),
-                (AttributeType::Magic, Attribute {attribute: AttributeType::Magic, quantity: Some(2),
-                    ..Default::default()}),
-                (AttributeType::Health, Attribute {attribute: AttributeType::Health, quantity: Some(5),
-                    ..Default::default()}),
+                (DiceColour::Strength, Attribute::DiceCount(DiceColour::Strength, 2)),
+                (DiceColour::Agility, Attribute::DiceCount(DiceColour::Agility, agility)),
+                (DiceColour::Magic, Attribute::DiceCount(DiceColour::Magic, 2)),
             ]),
+            health,
+            players,
             heroic_feat: HeroicFeat {
                 name: String::from("EAGLE EYE"),
                 description: String::from("Spend 2 x TIME to roll 2 x HEROIC DICE or 4 x TIME to roll 3 x HEROIC DICE. Before checking any other effects, discard one of the dice rolled."),
@@ -504,7 +563,7 @@ impl Hero {
                     name: String::from("KITING"),
                     description: Some(String::from("If you would lose only one HEALTH, spend TIME instead. Prevent one HEALTH in each boss round")),
                     requirements: None,
-                    effect: Effect::Prevent(AttributeType::Health),
+                    effect: Effect::Prevent(Resource::Health),
                     encounters: vec![Encounter::Combat, Encounter::Boss],
                 }
             ],
@@ -512,51 +571,87 @@ impl Hero {
             current_level: 1,
             potions: 1,
             encounter_bonus: 0,
+            xp: 0,
+            items: Vec::new(),
         }
     }
 
-    fn get_default_levels() -> HashMap<i8, HashMap<String, i8>> {
-        HashMap::from([
+    /// The level table both sides of every built-in hero share.
+    pub fn get_default_levels() -> LevelTable {
+        BTreeMap::from([
             (
                 1,
-                HashMap::from([
-                    (String::from("Items"), 1),
-                    (String::from("Skills"), 2),
-                    (String::from("Potions"), 1),
-                    (String::from("Encounter Bonus"), 0),
-                    (String::from("XP to level up"), 6),
-                ]),
+                LevelStats {
+                    items: 1,
+                    skills: 2,
+                    potions: 1,
+                    encounter_bonus: 0,
+                    xp_to_next: 6,
+                },
             ),
             (
                 2,
-                HashMap::from([
-                    (String::from("Items"), 3),
-                    (String::from("Skills"), 3),
-                    (String::from("Potions"), 1),
-                    (String::from("Encounter Bonus"), 1),
-                    (String::from("XP to level up"), 8),
-                ]),
+                LevelStats {
+                    items: 3,
+                    skills: 3,
+                    potions: 1,
+                    encounter_bonus: 1,
+                    xp_to_next: 8,
+                },
             ),
             (
                 3,
-                HashMap::from([
-                    (String::from("Items"), 5),
-                    (String::from("Skills"), 4),
-                    (String::from("Potions"), 1),
-                    (String::from("Encounter Bonus"), 1),
-                    (String::from("XP to level up"), 10),
-                ]),
+                LevelStats {
+                    items: 5,
+                    skills: 4,
+                    potions: 1,
+                    encounter_bonus: 1,
+                    xp_to_next: 10,
+                },
             ),
             (
                 4,
-                HashMap::from([
-                    (String::from("Items"), 7),
-                    (String::from("Skills"), 5),
-                    (String::from("Potions"), 1),
-                    (String::from("Encounter Bonus"), 2),
-                    (String::from("XP to level up"), 5),
-                ]),
+                LevelStats {
+                    items: 7,
+                    skills: 5,
+                    potions: 1,
+                    encounter_bonus: 2,
+                    xp_to_next: 5,
+                },
             ),
         ])
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dice_counts_stop_at_zero() {
+        let mut hero = Hero::get_mage(PlayerCount::Solo);
+        assert_eq!(
+            hero.change_attribute_quantity(DiceColour::Strength, -1),
+            Ok(0)
+        );
+        assert_eq!(
+            hero.change_attribute_quantity(DiceColour::Strength, -1),
+            Err(HeroError::QuantityNegative {
+                attribute: String::from("Strength"),
+                quantity: -1,
+            })
+        );
+        assert_eq!(
+            hero.change_attribute_quantity(DiceColour::Heroic, 1),
+            Err(HeroError::AttributeNotFound(DiceColour::Heroic))
+        );
+        hero.attributes.insert(
+            DiceColour::Magic,
+            Attribute::DieOfValue(DiceColour::Magic, 6),
+        );
+        assert_eq!(
+            hero.change_attribute_quantity(DiceColour::Magic, 1),
+            Err(HeroError::NotADiceCount(DiceColour::Magic))
+        );
+    }
+}
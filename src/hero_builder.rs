@@ -0,0 +1,227 @@
+use std::{
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::Path,
+};
+
+use crate::{
+    error::DataError,
+    hero::{Attribute, DiceColour, Hero, HeroicFeat, LevelTable, PlayerCount, Skill},
+    lint::{self, LintIssue},
+};
+
+/// Assembles a homebrew hero. Start from scratch with [`HeroBuilder::new`] or
+/// from an existing hero with [`HeroBuilder::from_hero`]; `build` only hands the
+/// hero over once [`validate`] finds nothing wrong with it.
+#[derive(Debug, Clone)]
+pub struct HeroBuilder {
+    hero: Hero,
+}
+
+impl HeroBuilder {
+    /// Two dice of each colour, 5 health, one potion, the default level table
+    /// and no heroic feat or skills yet.
+    pub fn new(name: &str) -> HeroBuilder {
+        let players = PlayerCount::Solo;
+        HeroBuilder {
+            hero: Hero {
+                name: name.to_string(),
+                attributes: [DiceColour::Strength, DiceColour::Agility, DiceColour::Magic]
+                    .into_iter()
+                    .map(|colour| (colour, Attribute::DiceCount(colour, 2)))
+                    .collect(),
+                health: 5,
+                players,
+                heroic_feat: HeroicFeat {
+                    name: String::new(),
+                    description: String::new(),
+                    encounters: Vec::new(),
+                },
+                skills: Vec::new(),
+                levels: Hero::get_default_levels(),
+                current_level: 1,
+                potions: 1,
+                encounter_bonus: 0,
+                xp: 0,
+                items: Vec::new(),
+            },
+        }
+    }
+
+    pub fn from_hero(hero: Hero) -> HeroBuilder {
+        HeroBuilder { hero }
+    }
+
+    pub fn with_name(mut self, name: &str) -> HeroBuilder {
+        self.hero.name = name.to_string();
+        self
+    }
+
+    /// Sets how many Strength, Agility or Magic dice the hero rolls.
+    pub fn with_dice(mut self, colour: DiceColour, count: usize) -> HeroBuilder {
+        self.hero
+            .attributes
+            .insert(colour, Attribute::DiceCount(colour, count));
+        self
+    }
+
+    pub fn with_health(mut self, health: usize) -> HeroBuilder {
+        self.hero.health = health;
+        self
+    }
+
+    pub fn with_potions(mut self, potions: i8) -> HeroBuilder {
+        self.hero.potions = potions;
+        self
+    }
+
+    pub fn with_players(mut self, players: PlayerCount) -> HeroBuilder {
+        self.hero.players = players;
+        self
+    }
+
+    pub fn with_heroic_feat(mut self, heroic_feat: HeroicFeat) -> HeroBuilder {
+        self.hero.heroic_feat = heroic_feat;
+        self
+    }
+
+    /// Adds a starting skill.
+    pub fn with_skill(mut self, skill: Skill) -> HeroBuilder {
+        self.hero.skills.push(skill);
+        self
+    }
+
+    pub fn with_levels(mut self, levels: LevelTable) -> HeroBuilder {
+        self.hero.levels = levels;
+        self
+    }
+
+    pub fn hero(&self) -> &Hero {
+        &self.hero
+    }
+
+    pub fn validate(&self) -> Vec<LintIssue> {
+        validate(&self.hero)
+    }
+
+    pub fn build(self) -> Result<Hero, DataError> {
+        let issues = self.validate();
+        if !issues.is_empty() {
+            return Err(DataError::Invalid {
+                source: self.hero.name,
+                issues: issues.into_iter().map(|issue| issue.message).collect(),
+            });
+        }
+        Ok(self.hero)
+    }
+}
+
+/// Everything [`lint::lint_hero`] checks, plus the mistakes a hand-made hero is
+/// prone to: a missing name or heroic feat, no dice at all, skills listed twice
+/// and more starting skills than the first level allows.
+pub fn validate(hero: &Hero) -> Vec<LintIssue> {
+    let mut issues = lint::lint_hero(hero);
+    let mut report = |message: &str| {
+        issues.push(LintIssue {
+            card: hero.name.clone(),
+            message: message.to_string(),
+        })
+    };
+    if hero.name.trim().is_empty() {
+        report("has no name");
+    }
+    if hero.heroic_feat.name.trim().is_empty() {
+        report("has no heroic feat");
+    }
+    let dice: usize = [DiceColour::Strength, DiceColour::Agility, DiceColour::Magic]
+        .iter()
+        .map(|colour| hero.dice_count(colour))
+        .sum();
+    if dice == 0 {
+        report("rolls no dice");
+    }
+    for (index, skill) in hero.skills.iter().enumerate() {
+        if hero.skills[..index]
+            .iter()
+            .any(|known| known.name == skill.name)
+        {
+            report(&format!("has {} twice", skill.name));
+        }
+    }
+    if let Some(stats) = hero.level_stats() {
+        if hero.skills.len() > stats.skills.max(0) as usize {
+            report(&format!(
+                "starts with {} skills but level {} only has room for {}",
+                hero.skills.len(),
+                hero.current_level,
+                stats.skills
+            ));
+        }
+    }
+    issues
+}
+
+/// Reads a hero saved as JSON, shaped like each side `show hero --json` prints,
+/// and checks it with [`validate`].
+pub fn load_hero(path: &Path) -> Result<Hero, DataError> {
+    let json = fs::read_to_string(path)?;
+    let hero = serde_json::from_str(&json).map_err(|err| DataError::Parse {
+        source: path.display().to_string(),
+        message: err.to_string(),
+    })?;
+    HeroBuilder::from_hero(hero).build()
+}
+
+/// The side of a homebrew hero played in a co-op game. A hero saved with its
+/// solo side is turned over; its stats and levels are kept as they are.
+pub fn coop_side(mut hero: Hero) -> Hero {
+    hero.players = PlayerCount::Coop;
+    hero
+}
+
+/// Saves `hero` as JSON, refusing to overwrite a file that is already there.
+pub fn save_hero(hero: &Hero, path: &Path) -> Result<(), DataError> {
+    let json = serde_json::to_string_pretty(hero).map_err(io::Error::from)?;
+    let mut file = OpenOptions::new().write(true).create_new(true).open(path)?;
+    file.write_all(json.as_bytes())?;
+    Ok(())
+}
+
+/// A file name for `hero`: its name in lower case with anything other than
+/// letters and digits turned into dashes, e.g. "Storm Caller" becomes
+/// "storm-caller.json".
+pub fn file_name(hero: &Hero) -> String {
+    let slug: String = hero
+        .name
+        .trim()
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_lowercase()
+            } else {
+                '-'
+            }
+        })
+        .collect();
+    format!("{}.json", slug)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn saving_never_overwrites() {
+        let hero = HeroBuilder::new("Saved Twice").hero().clone();
+        let path = std::env::temp_dir().join(format!("odd-hero-{}.json", std::process::id()));
+        let _ = fs::remove_file(&path);
+        save_hero(&hero, &path).unwrap();
+        let again = save_hero(&hero, &path);
+        let saved = fs::read_to_string(&path);
+        fs::remove_file(&path).unwrap();
+        assert!(
+            matches!(again, Err(DataError::Io(err)) if err.kind() == io::ErrorKind::AlreadyExists)
+        );
+        assert_eq!(saved.unwrap(), serde_json::to_string_pretty(&hero).unwrap());
+    }
+}
@@ -0,0 +1,384 @@
+use std::{
+    collections::BTreeMap,
+    fmt::Display,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::{Path, PathBuf},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{game::Game, simulator::GameSummary};
+
+/// Where the history is kept unless another file is asked for: in the home
+/// directory when there is one, otherwise the current directory.
+pub const HISTORY_FILE: &str = ".one_deck_dungeon_history.jsonl";
+
+#[derive(Debug)]
+pub enum HistoryError {
+    Io(io::Error),
+    Parse { line: usize, message: String },
+}
+
+impl Display for HistoryError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HistoryError::Io(err) => write!(f, "could not access the history file: {}", err),
+            HistoryError::Parse { line, message } => {
+                write!(
+                    f,
+                    "could not read line {} of the history: {}",
+                    line, message
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for HistoryError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            HistoryError::Io(err) => Some(err),
+            HistoryError::Parse { .. } => None,
+        }
+    }
+}
+
+impl From<io::Error> for HistoryError {
+    fn from(err: io::Error) -> Self {
+        HistoryError::Io(err)
+    }
+}
+
+pub type Result<T> = std::result::Result<T, HistoryError>;
+
+pub fn default_path() -> PathBuf {
+    std::env::var_os("HOME")
+        .map_or(PathBuf::new(), PathBuf::from)
+        .join(HISTORY_FILE)
+}
+
+/// One finished game: who played where, and how it went.
+/// Campaign files store their runs in this shape too, so new fields need a
+/// `#[serde(default)]` for older files to keep loading.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RunRecord {
+    pub hero: String,
+    pub dungeon: String,
+    pub seed: u64,
+    pub summary: GameSummary,
+}
+
+impl RunRecord {
+    pub fn from_game(game: &Game) -> RunRecord {
+        RunRecord {
+            hero: game.hero_names(),
+            dungeon: game.dungeon.name.clone(),
+            seed: game.seed,
+            summary: GameSummary::from_game(game),
+        }
+    }
+}
+
+impl Display for RunRecord {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let result = match &self.summary.death_cause {
+            None => String::from("won"),
+            Some(cause) => format!("lost to {}", cause),
+        };
+        write!(
+            f,
+            "{} in {} ({}, seed {}): {}, floor {}, score {}, {} turns",
+            self.hero,
+            self.dungeon,
+            self.summary.difficulty,
+            self.seed,
+            result,
+            self.summary.floor,
+            self.summary.score,
+            self.summary.turns
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct HeroStats {
+    pub hero: String,
+    pub games: usize,
+    pub wins: usize,
+}
+
+impl HeroStats {
+    pub fn win_rate(&self) -> f64 {
+        if self.games == 0 {
+            0.0
+        } else {
+            self.wins as f64 / self.games as f64
+        }
+    }
+}
+
+/// A run of games in a row that were all won or all lost.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Streak {
+    pub won: bool,
+    pub length: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct HistoryReport {
+    pub games: usize,
+    pub wins: usize,
+    pub heroes: Vec<HeroStats>,
+    /// The highest scoring run in each dungeon.
+    pub best_scores: Vec<RunRecord>,
+    pub current_streak: Option<Streak>,
+    pub longest_winning_streak: usize,
+    pub longest_losing_streak: usize,
+}
+
+impl Display for HistoryReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{} game(s), {} won", self.games, self.wins)?;
+        writeln!(f, "Win rate per hero:")?;
+        for stats in self.heroes.iter() {
+            writeln!(
+                f,
+                "  {: <10}{:>4.0}% ({}/{})",
+                stats.hero,
+                stats.win_rate() * 100.0,
+                stats.wins,
+                stats.games
+            )?;
+        }
+        writeln!(f, "Best score per dungeon:")?;
+        for run in self.best_scores.iter() {
+            writeln!(
+                f,
+                "  {: <16}{:>4} by {} ({})",
+                run.dungeon, run.summary.score, run.hero, run.summary.difficulty
+            )?;
+        }
+        match self.current_streak {
+            Some(streak) => writeln!(
+                f,
+                "Current streak: {} {}",
+                streak.length,
+                if streak.won { "win(s)" } else { "loss(es)" }
+            )?,
+            None => writeln!(f, "Current streak: none")?,
+        }
+        write!(
+            f,
+            "Longest streaks: {} win(s), {} loss(es)",
+            self.longest_winning_streak, self.longest_losing_streak
+        )
+    }
+}
+
+/// Every finished game, oldest first, stored as one line of JSON per game so new
+/// games are only ever appended.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct History {
+    pub runs: Vec<RunRecord>,
+}
+
+impl History {
+    /// Reads the history at `path`; a missing file is an empty history.
+    pub fn load(path: &Path) -> Result<History> {
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(History::default()),
+            Err(err) => return Err(err.into()),
+        };
+        let mut runs = Vec::new();
+        for (index, line) in contents.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            let run = serde_json::from_str(line).map_err(|err| HistoryError::Parse {
+                line: index + 1,
+                message: err.to_string(),
+            })?;
+            runs.push(run);
+        }
+        Ok(History { runs })
+    }
+
+    /// Adds `run` to the end of the history at `path`, creating the file if needed.
+    pub fn append(path: &Path, run: &RunRecord) -> Result<()> {
+        let line = serde_json::to_string(run).map_err(io::Error::from)?;
+        let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+        writeln!(file, "{}", line)?;
+        Ok(())
+    }
+
+    /// The runs `keep` accepts, still in order.
+    pub fn filter(&self, keep: impl Fn(&RunRecord) -> bool) -> History {
+        History {
+            runs: self.runs.iter().filter(|run| keep(run)).cloned().collect(),
+        }
+    }
+
+    pub fn wins(&self) -> usize {
+        self.runs.iter().filter(|run| run.summary.won).count()
+    }
+
+    /// Games and wins for every hero, most played first.
+    pub fn hero_stats(&self) -> Vec<HeroStats> {
+        let mut heroes: BTreeMap<&str, HeroStats> = BTreeMap::new();
+        for run in self.runs.iter() {
+            let stats = heroes.entry(&run.hero).or_insert(HeroStats {
+                hero: run.hero.clone(),
+                games: 0,
+                wins: 0,
+            });
+            stats.games += 1;
+            if run.summary.won {
+                stats.wins += 1;
+            }
+        }
+        let mut heroes: Vec<HeroStats> = heroes.into_values().collect();
+        heroes.sort_by_key(|stats| std::cmp::Reverse(stats.games));
+        heroes
+    }
+
+    /// The highest scoring run in each dungeon, the earliest one on a tie.
+    pub fn best_scores(&self) -> Vec<RunRecord> {
+        let mut best: BTreeMap<&str, &RunRecord> = BTreeMap::new();
+        for run in self.runs.iter() {
+            let entry = best.entry(&run.dungeon).or_insert(run);
+            if run.summary.score > entry.summary.score {
+                *entry = run;
+            }
+        }
+        best.into_values().cloned().collect()
+    }
+
+    /// How many of the most recent games in a row went the same way.
+    pub fn current_streak(&self) -> Option<Streak> {
+        let won = self.runs.last()?.summary.won;
+        let length = self
+            .runs
+            .iter()
+            .rev()
+            .take_while(|run| run.summary.won == won)
+            .count();
+        Some(Streak { won, length })
+    }
+
+    pub fn longest_streak(&self, won: bool) -> usize {
+        let mut longest = 0;
+        let mut current = 0;
+        for run in self.runs.iter() {
+            if run.summary.won == won {
+                current += 1;
+                longest = longest.max(current);
+            } else {
+                current = 0;
+            }
+        }
+        longest
+    }
+
+    pub fn report(&self) -> HistoryReport {
+        HistoryReport {
+            games: self.runs.len(),
+            wins: self.wins(),
+            heroes: self.hero_stats(),
+            best_scores: self.best_scores(),
+            current_streak: self.current_streak(),
+            longest_winning_streak: self.longest_streak(true),
+            longest_losing_streak: self.longest_streak(false),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game_setup::difficulty::Difficulty;
+
+    fn run(hero: &str, dungeon: &str, won: bool, score: i32) -> RunRecord {
+        RunRecord {
+            hero: hero.to_string(),
+            dungeon: dungeon.to_string(),
+            seed: 1,
+            summary: GameSummary {
+                difficulty: Difficulty::Standard,
+                won,
+                floor: 2,
+                remaining_health: 1,
+                turns: 10,
+                score,
+                death_cause: (!won).then(|| String::from("Goblin")),
+            },
+        }
+    }
+
+    fn history() -> History {
+        History {
+            runs: vec![
+                run("Mage", "Dragon's Cave", true, 30),
+                run("Mage", "Dragon's Cave", true, 42),
+                run("Rogue", "Lich's Tomb", false, 12),
+                run("Rogue", "Dragon's Cave", true, 42),
+                run("Mage", "Lich's Tomb", false, 20),
+                run("Rogue", "Lich's Tomb", false, 8),
+            ],
+        }
+    }
+
+    #[test]
+    fn streaks() {
+        let history = history();
+        assert_eq!(
+            history.current_streak(),
+            Some(Streak {
+                won: false,
+                length: 2
+            })
+        );
+        assert_eq!(history.longest_streak(true), 2);
+        assert_eq!(history.longest_streak(false), 2);
+        assert_eq!(History::default().current_streak(), None);
+        assert_eq!(History::default().longest_streak(true), 0);
+    }
+
+    #[test]
+    fn best_score_per_dungeon_keeps_the_earliest_on_a_tie() {
+        let best = history().best_scores();
+        assert_eq!(best.len(), 2);
+        assert_eq!(best[0].dungeon, "Dragon's Cave");
+        assert_eq!((best[0].hero.as_str(), best[0].summary.score), ("Mage", 42));
+        assert_eq!(best[1].dungeon, "Lich's Tomb");
+        assert_eq!(best[1].summary.score, 20);
+    }
+
+    #[test]
+    fn filtering_keeps_order_and_feeds_the_stats() {
+        let rogue = history().filter(|run| run.hero == "Rogue");
+        let scores: Vec<i32> = rogue.runs.iter().map(|run| run.summary.score).collect();
+        assert_eq!(scores, vec![12, 42, 8]);
+        assert_eq!(rogue.wins(), 1);
+        let stats = history().hero_stats();
+        assert_eq!(stats.len(), 2);
+        assert!(stats.iter().all(|stats| stats.games == 3));
+        let report = rogue.report();
+        assert_eq!((report.games, report.wins), (3, 1));
+        assert_eq!(report.longest_winning_streak, 1);
+    }
+
+    #[test]
+    fn appends_and_loads_lines() {
+        let path = std::env::temp_dir().join(format!("odd-history-{}.jsonl", std::process::id()));
+        let _ = fs::remove_file(&path);
+        assert_eq!(History::load(&path).unwrap(), History::default());
+        for run in history().runs.iter() {
+            History::append(&path, run).unwrap();
+        }
+        let loaded = History::load(&path);
+        fs::remove_file(&path).unwrap();
+        assert_eq!(loaded.unwrap(), history());
+    }
+}
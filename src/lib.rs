@@ -0,0 +1,23 @@
+pub mod boss;
+pub mod campaign;
+pub mod dungeon;
+pub mod dungeon_builder;
+pub mod encounter;
+pub mod env;
+pub mod error;
+pub mod game;
+pub mod game_setup;
+pub mod generator;
+pub mod hero;
+pub mod hero_builder;
+pub mod history;
+pub mod lint;
+pub mod mcts;
+pub mod odds;
+pub mod policy;
+pub mod print_helper;
+pub mod protocol;
+pub mod server;
+pub mod simulator;
+pub mod solver;
+pub mod tui;
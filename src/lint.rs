@@ -0,0 +1,297 @@
+use std::{collections::HashMap, fmt::Display};
+
+use serde::Serialize;
+
+use crate::{
+    boss::Boss,
+    dungeon::{ChallengeBox, Dungeon},
+    encounter::{Combat, Peril},
+    game_setup,
+    hero::{Attribute, DiceColour, Hero, PlayerCount, Resource, Skill},
+};
+
+/// Floors every dungeon needs peril and combat boxes for.
+const FLOORS: [i8; 3] = [1, 2, 3];
+
+/// Something wrong with a card, e.g. a box nobody could ever fill.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct LintIssue {
+    pub card: String,
+    pub message: String,
+}
+
+impl Display for LintIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.card, self.message)
+    }
+}
+
+/// Checks every built-in hero, dungeon and encounter card.
+pub fn lint_all() -> Vec<LintIssue> {
+    let mut issues = Vec::new();
+    for players in [PlayerCount::Solo, PlayerCount::Coop] {
+        for hero in game_setup::get_all_heroes(players) {
+            issues.extend(lint_hero(&hero));
+        }
+    }
+    for dungeon in game_setup::get_all_dungeons() {
+        issues.extend(lint_dungeon(&dungeon));
+    }
+    for peril in game_setup::get_all_perils() {
+        issues.extend(lint_peril(&peril));
+    }
+    for combat in game_setup::get_all_combats() {
+        issues.extend(lint_combat(&combat));
+    }
+    issues
+}
+
+pub fn lint_hero(hero: &Hero) -> Vec<LintIssue> {
+    let mut issues = Vec::new();
+    let mut report = |message: String| {
+        issues.push(LintIssue {
+            card: hero.name.clone(),
+            message,
+        })
+    };
+    for colour in [DiceColour::Strength, DiceColour::Agility, DiceColour::Magic] {
+        match hero.attributes.get(&colour) {
+            Some(Attribute::DiceCount(dice_type, _)) if *dice_type != colour => {
+                report(format!("{} is stored as {} dice", colour, dice_type))
+            }
+            Some(Attribute::DiceCount(_, _)) => {}
+            Some(attribute) => report(format!(
+                "{} should be a dice count, not {}",
+                colour, attribute
+            )),
+            None => report(format!("has no {} dice", colour)),
+        }
+    }
+    if !hero.levels.contains_key(&hero.current_level) {
+        report(format!(
+            "starts on level {}, which is not in the level table",
+            hero.current_level
+        ));
+    }
+    let levels: Vec<i8> = hero.levels.keys().copied().collect();
+    if levels.windows(2).any(|pair| pair[1] != pair[0] + 1) {
+        report(String::from("level table has gaps"));
+    }
+    for skill in hero.skills.iter() {
+        issues.extend(lint_skill(&hero.name, skill));
+    }
+    issues
+}
+
+pub fn lint_dungeon(dungeon: &Dungeon) -> Vec<LintIssue> {
+    let mut issues = lint_floors(
+        &dungeon.name,
+        &dungeon.peril_challenges,
+        &dungeon.combat_challenges,
+    );
+    issues.extend(lint_boss(&dungeon.boss));
+    issues
+}
+
+/// Checks a dungeon's boxes: every floor needs its own, and boxes for floors the
+/// game never reaches are probably a typo.
+pub fn lint_floors(
+    card: &str,
+    peril_challenges: &HashMap<i8, Vec<ChallengeBox>>,
+    combat_challenges: &HashMap<i8, Vec<ChallengeBox>>,
+) -> Vec<LintIssue> {
+    let mut issues = Vec::new();
+    for (kind, challenges) in [("peril", peril_challenges), ("combat", combat_challenges)] {
+        for floor in FLOORS {
+            match challenges.get(&floor) {
+                Some(boxes) => issues.extend(lint_boxes(card, boxes)),
+                None => issues.push(LintIssue {
+                    card: card.to_string(),
+                    message: format!("has no {} boxes for floor {}", kind, floor),
+                }),
+            }
+        }
+        let mut unused: Vec<i8> = challenges
+            .keys()
+            .filter(|floor| !FLOORS.contains(floor))
+            .copied()
+            .collect();
+        unused.sort_unstable();
+        for floor in unused {
+            issues.push(LintIssue {
+                card: card.to_string(),
+                message: format!(
+                    "has {} boxes for floor {}, which is never reached",
+                    kind, floor
+                ),
+            });
+        }
+    }
+    issues
+}
+
+pub fn lint_boss(boss: &Boss) -> Vec<LintIssue> {
+    let mut issues = Vec::new();
+    if boss.health == 0 {
+        issues.push(LintIssue {
+            card: boss.name.clone(),
+            message: String::from("boss has no health"),
+        });
+    }
+    if boss.challenges.is_empty() {
+        issues.push(LintIssue {
+            card: boss.name.clone(),
+            message: String::from("boss has no challenge boxes"),
+        });
+    }
+    issues.extend(lint_boxes(&boss.name, &boss.challenges));
+    issues
+}
+
+pub fn lint_peril(peril: &Peril) -> Vec<LintIssue> {
+    let mut issues = Vec::new();
+    for (index, (choice, time_cost)) in [
+        (&peril.choice_one, peril.choice_one_time_cost),
+        (&peril.choice_two, peril.choice_two_time_cost),
+    ]
+    .into_iter()
+    .enumerate()
+    {
+        if choice.is_empty() {
+            issues.push(LintIssue {
+                card: peril.name.clone(),
+                message: format!("option {} has no boxes", index + 1),
+            });
+        }
+        if time_cost.is_some_and(|time_cost| time_cost <= 0) {
+            issues.push(LintIssue {
+                card: peril.name.clone(),
+                message: format!("option {} has a time cost that is not positive", index + 1),
+            });
+        }
+        issues.extend(lint_boxes(&peril.name, choice));
+    }
+    issues.extend(lint_rewards(
+        &peril.name,
+        peril.xp_reward,
+        &peril.item_reward,
+        &peril.skill_reward,
+    ));
+    issues
+}
+
+pub fn lint_combat(combat: &Combat) -> Vec<LintIssue> {
+    let mut issues = Vec::new();
+    if combat.challenges.is_empty() {
+        issues.push(LintIssue {
+            card: combat.name.clone(),
+            message: String::from("has no boxes"),
+        });
+    }
+    issues.extend(lint_boxes(&combat.name, &combat.challenges));
+    issues.extend(lint_rewards(
+        &combat.name,
+        combat.xp_reward,
+        &combat.item_reward,
+        &combat.skill_reward,
+    ));
+    issues
+}
+
+fn lint_rewards(
+    card: &str,
+    xp_reward: i8,
+    item_reward: &[Attribute],
+    skill: &Skill,
+) -> Vec<LintIssue> {
+    let mut issues = Vec::new();
+    if xp_reward <= 0 {
+        issues.push(LintIssue {
+            card: card.to_string(),
+            message: String::from("gives no XP"),
+        });
+    }
+    if item_reward.is_empty() {
+        issues.push(LintIssue {
+            card: card.to_string(),
+            message: String::from("has no item reward"),
+        });
+    }
+    for attribute in item_reward {
+        if let Attribute::DieOfValue(_, _) = attribute {
+            issues.push(LintIssue {
+                card: card.to_string(),
+                message: format!("item reward {} is not a dice count or resource", attribute),
+            });
+        }
+    }
+    issues.extend(lint_skill(card, skill));
+    issues
+}
+
+fn lint_skill(card: &str, skill: &Skill) -> Vec<LintIssue> {
+    let mut issues = Vec::new();
+    let mut report = |message: String| {
+        issues.push(LintIssue {
+            card: card.to_string(),
+            message: format!("skill {}: {}", skill.name, message),
+        })
+    };
+    if skill.name.trim().is_empty() {
+        report(String::from("has no name"));
+    }
+    if skill.encounters.is_empty() {
+        report(String::from("cannot be used in any encounter"));
+    }
+    match &skill.requirements {
+        Some(Attribute::DiceCount(_, 0) | Attribute::Resource(_, 0)) => {
+            report(String::from("has a requirement of nothing"))
+        }
+        Some(Attribute::DieOfValue(_, value)) if !(1..=6).contains(value) => {
+            report(format!("requires a dice showing {}", value))
+        }
+        _ => {}
+    }
+    issues
+}
+
+fn lint_boxes(card: &str, boxes: &[ChallengeBox]) -> Vec<LintIssue> {
+    let mut issues = Vec::new();
+    for challenge in boxes {
+        let mut report = |message: &str| {
+            issues.push(LintIssue {
+                card: card.to_string(),
+                message: format!("box \"{}\" {}", challenge, message),
+            })
+        };
+        if challenge.total_value <= 0 {
+            report("needs no dice to complete");
+        }
+        if challenge.single_dice && challenge.total_value > 6 {
+            report("can never be completed with a single dice");
+        }
+        match &challenge.dice_type {
+            Some(DiceColour::Strength | DiceColour::Agility | DiceColour::Magic) | None => {}
+            Some(_) => report("has a dice type no hero can roll"),
+        }
+        for consequence in challenge.consequences.iter() {
+            match consequence {
+                Attribute::Resource(Resource::Health | Resource::Time, quantity)
+                    if *quantity > 0 => {}
+                _ => report("has a consequence that is not a positive health or time cost"),
+            }
+        }
+    }
+    issues
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn built_in_cards_are_clean() {
+        let issues = lint_all();
+        assert!(issues.is_empty(), "{:?}", issues);
+    }
+}
@@ -0,0 +1,276 @@
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use crate::{
+    game::{Action, Game, Observation, Phase, FINAL_FLOOR},
+    policy::{self, GreedyPolicy, Policy},
+    simulator,
+    solver::{self, Weights},
+};
+
+pub const DEFAULT_ITERATIONS: usize = 100;
+pub const DEFAULT_EXPLORATION: f64 = 0.7;
+/// Rollouts sample each fight this many times; fewer than the greedy default
+/// keeps them cheap.
+pub const ROLLOUT_SAMPLES: usize = 4;
+
+/// Dice placements searched during encounters, one per weighting: the solver's
+/// next move when health matters most, when it is balanced and when time is short.
+const ENCOUNTER_WEIGHTS: [Weights; 3] = [
+    Weights { health: 6, time: 1 },
+    Weights { health: 3, time: 1 },
+    Weights { health: 1, time: 1 },
+];
+
+/// Information-set Monte Carlo Tree Search. Every iteration deals the deck, the
+/// closed doors and future rolls afresh with [`Observation::determinize`], walks
+/// the shared tree with UCB1 over the actions that are legal in that deal and
+/// finishes the game with a quick greedy rollout.
+#[derive(Debug, Clone)]
+pub struct MctsPolicy {
+    rng: StdRng,
+    iterations: usize,
+    exploration: f64,
+}
+
+impl MctsPolicy {
+    pub fn new(seed: u64) -> MctsPolicy {
+        MctsPolicy {
+            rng: StdRng::seed_from_u64(seed),
+            iterations: DEFAULT_ITERATIONS,
+            exploration: DEFAULT_EXPLORATION,
+        }
+    }
+
+    pub fn with_iterations(mut self, iterations: usize) -> MctsPolicy {
+        self.iterations = iterations.max(1);
+        self
+    }
+
+    pub fn with_exploration(mut self, exploration: f64) -> MctsPolicy {
+        self.exploration = exploration;
+        self
+    }
+
+    fn iterate(&mut self, tree: &mut Vec<Node>, mut game: Game) {
+        let mut path = vec![0];
+        let mut node = 0;
+        while !game.is_over() {
+            let actions = candidates(&game);
+            let untried: Vec<&Action> = actions
+                .iter()
+                .filter(|action| tree[node].child(action).is_none())
+                .collect();
+            for action in actions.iter() {
+                if let Some(child) = tree[node].child(action) {
+                    tree[child].available += 1;
+                }
+            }
+            if !untried.is_empty() {
+                let action = untried[self.rng.gen_range(0..untried.len())].clone();
+                let child = tree.len();
+                tree.push(Node::default());
+                tree[child].available = 1;
+                tree[node].children.push((action.clone(), child));
+                path.push(child);
+                if game.apply(action).is_ok() {
+                    self.rollout(&mut game);
+                }
+                break;
+            }
+
+            let Some((action, child)) = self.select(tree, node, &actions) else {
+                break;
+            };
+            path.push(child);
+            node = child;
+            if game.apply(action).is_err() {
+                break;
+            }
+        }
+
+        let value = value(&game);
+        for node in path {
+            tree[node].visits += 1;
+            tree[node].total += value;
+        }
+    }
+
+    /// The child with the best UCB1 score among those legal in this deal,
+    /// explored against how often each one was available rather than how often
+    /// its parent was visited.
+    fn select(&self, tree: &[Node], node: usize, actions: &[Action]) -> Option<(Action, usize)> {
+        tree[node]
+            .children
+            .iter()
+            .filter(|(action, _)| actions.contains(action))
+            .map(|(action, child)| {
+                let child_node = &tree[*child];
+                let visits = child_node.visits.max(1) as f64;
+                let score = child_node.total / visits
+                    + self.exploration * ((child_node.available as f64).ln() / visits).sqrt();
+                (score, action, *child)
+            })
+            .max_by(|a, b| a.0.total_cmp(&b.0))
+            .map(|(_, action, child)| (action.clone(), child))
+    }
+
+    fn rollout(&mut self, game: &mut Game) {
+        let mut policy = GreedyPolicy::new(self.rng.gen()).with_samples(ROLLOUT_SAMPLES);
+        simulator::play_game(game, &mut policy, simulator::DEFAULT_MAX_STEPS);
+    }
+}
+
+impl Policy for MctsPolicy {
+    fn name(&self) -> &str {
+        "mcts"
+    }
+
+    fn decide(&mut self, observation: &Observation) -> Action {
+        let game = observation.determinize(0);
+        let actions = candidates(&game);
+        if actions.len() <= 1 {
+            return actions
+                .into_iter()
+                .next()
+                .unwrap_or(Action::FinishEncounter);
+        }
+
+        let mut tree = vec![Node::default()];
+        for _ in 0..self.iterations {
+            let game = observation.determinize(self.rng.gen());
+            self.iterate(&mut tree, game);
+        }
+        tree[0]
+            .children
+            .iter()
+            .filter(|(action, _)| actions.contains(action))
+            .max_by_key(|(_, child)| tree[*child].visits)
+            .map(|(action, _)| action.clone())
+            .unwrap_or_else(|| actions[0].clone())
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+struct Node {
+    children: Vec<(Action, usize)>,
+    visits: usize,
+    available: usize,
+    total: f64,
+}
+
+impl Node {
+    fn child(&self, action: &Action) -> Option<usize> {
+        self.children
+            .iter()
+            .find(|(known, _)| known == action)
+            .map(|(_, child)| *child)
+    }
+}
+
+/// The actions worth searching. Outside encounters that is every legal action;
+/// inside one the dice are too many to search one by one, so only the solver's
+/// next move under each weighting, a free skill and giving up are tried.
+fn candidates(game: &Game) -> Vec<Action> {
+    let (Phase::Encounter, Some(state)) = (&game.phase, &game.encounter) else {
+        return game.legal_actions();
+    };
+    let mut actions = Vec::new();
+    if let Some(skill) = policy::free_skill(&game.observe(), state) {
+        actions.push(Action::UseSkill { skill, dice: None });
+    }
+    for weights in ENCOUNTER_WEIGHTS {
+        let next = solver::solve_encounter(state, weights)
+            .actions()
+            .into_iter()
+            .next();
+        if let Some(action) = next {
+            if !actions.contains(&action) {
+                actions.push(action);
+            }
+        }
+    }
+    actions.push(Action::FinishEncounter);
+    actions
+}
+
+/// 1 for a win; otherwise up to 0.5 for how far the hero got, counting each
+/// floor cleared and each hit on the boss.
+fn value(game: &Game) -> f64 {
+    if game.phase == Phase::Won {
+        return 1.0;
+    }
+    let floors = (game.floor - 1).clamp(0, FINAL_FLOOR) as f64;
+    let boss = game.boss_damage as f64 / game.dungeon.boss.health.max(1) as f64;
+    0.5 * (floors + boss) / (FINAL_FLOOR as f64 + 1.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        dungeon::Dungeon,
+        game_setup::difficulty::Difficulty,
+        hero::{Hero, PlayerCount},
+    };
+
+    fn game(seed: u64) -> Game {
+        Game::new(
+            Hero::get_warrior(PlayerCount::Solo),
+            Dungeon::get_dragons_cave(),
+            seed,
+        )
+    }
+
+    /// Plays `game` out with `policy`, checking every action it picks is legal.
+    fn play_legally(game: &mut Game, policy: &mut impl Policy) -> Vec<Action> {
+        let mut actions = Vec::new();
+        while !game.is_over() && actions.len() < simulator::DEFAULT_MAX_STEPS {
+            let action = policy.decide(&game.observe());
+            assert!(game.legal_actions().contains(&action), "{:?}", action);
+            game.apply(action.clone()).unwrap();
+            actions.push(action);
+        }
+        actions
+    }
+
+    #[test]
+    fn picks_legal_actions_the_same_way_for_a_seed() {
+        let policy = || MctsPolicy::new(7).with_iterations(4);
+        let first = play_legally(&mut game(1), &mut policy());
+        let second = play_legally(&mut game(1), &mut policy());
+        assert_eq!(first, second);
+    }
+
+    /// Slow in a debug build; run it with `cargo test --release -- --ignored`.
+    #[test]
+    #[ignore]
+    fn does_at_least_as_well_as_greedy() {
+        let game = |seed| {
+            Game::with_difficulty(
+                Hero::get_mage(PlayerCount::Solo),
+                Dungeon::get_dragons_cave(),
+                seed,
+                Difficulty::Epic,
+            )
+        };
+        let mut mcts = 0.0;
+        let mut greedy = 0.0;
+        for seed in 0..2 {
+            let mut played = game(seed);
+            simulator::play_game(
+                &mut played,
+                &mut MctsPolicy::new(seed),
+                simulator::DEFAULT_MAX_STEPS,
+            );
+            mcts += value(&played);
+            let mut played = game(seed);
+            simulator::play_game(
+                &mut played,
+                &mut GreedyPolicy::new(seed),
+                simulator::DEFAULT_MAX_STEPS,
+            );
+            greedy += value(&played);
+        }
+        assert!(mcts >= greedy, "mcts {} against greedy {}", mcts, greedy);
+    }
+}
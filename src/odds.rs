@@ -0,0 +1,426 @@
+use std::{collections::BTreeMap, fmt::Display};
+
+use crate::{
+    dungeon::{ChallengeBox, Dungeon},
+    encounter::Encounter,
+    game::{Card, Dice},
+    hero::{DiceColour, Effect, Hero},
+    solver::{self, SolverOptions, Weights},
+};
+
+/// Encounters with more distinct rolls than this are refused rather than enumerated.
+pub const DEFAULT_MAX_ROLLS: usize = 250_000;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OddsError {
+    TooManyRolls { rolls: usize, limit: usize },
+}
+
+impl Display for OddsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OddsError::TooManyRolls { rolls, limit } => write!(
+                f,
+                "{} distinct rolls is more than the limit of {}",
+                rolls, limit
+            ),
+        }
+    }
+}
+
+impl std::error::Error for OddsError {}
+
+pub type Result<T> = std::result::Result<T, OddsError>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OddsOptions {
+    /// Used by the solver to decide which boxes to leave open.
+    pub weights: Weights,
+    pub max_rolls: usize,
+}
+
+impl Default for OddsOptions {
+    fn default() -> Self {
+        OddsOptions {
+            weights: Weights::default(),
+            max_rolls: DEFAULT_MAX_ROLLS,
+        }
+    }
+}
+
+/// The distribution of what an encounter costs, assuming the dice are placed as
+/// well as possible. It is exact unless `approximate` is set.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Odds {
+    /// Probability of each `(health, time)` loss.
+    pub outcomes: BTreeMap<(usize, usize), f64>,
+    /// Probability that every box is filled.
+    pub clear: f64,
+    /// Number of distinct rolls that were evaluated.
+    pub rolls: usize,
+    /// Set when the solver cut its search short on some roll, so those rolls may
+    /// have been scored worse than the best placement.
+    pub approximate: bool,
+}
+
+impl Odds {
+    pub fn expected_health(&self) -> f64 {
+        self.outcomes
+            .iter()
+            .map(|((health, _), probability)| *health as f64 * probability)
+            .sum()
+    }
+
+    pub fn expected_time(&self) -> f64 {
+        self.outcomes
+            .iter()
+            .map(|((_, time), probability)| *time as f64 * probability)
+            .sum()
+    }
+
+    pub fn health_distribution(&self) -> BTreeMap<usize, f64> {
+        let mut distribution = BTreeMap::new();
+        for ((health, _), probability) in self.outcomes.iter() {
+            *distribution.entry(*health).or_insert(0.0) += probability;
+        }
+        distribution
+    }
+
+    pub fn time_distribution(&self) -> BTreeMap<usize, f64> {
+        let mut distribution = BTreeMap::new();
+        for ((_, time), probability) in self.outcomes.iter() {
+            *distribution.entry(*time).or_insert(0.0) += probability;
+        }
+        distribution
+    }
+
+    /// Probability of losing less health than `remaining_health`.
+    pub fn survival_chance(&self, remaining_health: usize) -> f64 {
+        self.outcomes
+            .iter()
+            .filter(|((health, _), _)| *health < remaining_health)
+            .map(|(_, probability)| probability)
+            .sum()
+    }
+}
+
+/// Works out the odds of `hero` facing `boxes`, with `special_ability` applied as
+/// the game does and `time_cost` spent up front.
+pub fn encounter_odds(
+    hero: &Hero,
+    special_ability: &Effect,
+    boxes: &[ChallengeBox],
+    time_cost: usize,
+    options: &OddsOptions,
+) -> Result<Odds> {
+    let mut dice_types: Vec<(DiceColour, usize)> =
+        [DiceColour::Strength, DiceColour::Agility, DiceColour::Magic]
+            .into_iter()
+            .map(|colour| {
+                let count = hero.dice_count(&colour);
+                (colour, count)
+            })
+            .collect();
+    dice_types.push((DiceColour::Heroic, hero.encounter_bonus.max(0) as usize));
+
+    let rolls = dice_types
+        .iter()
+        .map(|(_, count)| multiset_count(*count))
+        .fold(1usize, |total, count| total.saturating_mul(count));
+    if rolls > options.max_rolls {
+        return Err(OddsError::TooManyRolls {
+            rolls,
+            limit: options.max_rolls,
+        });
+    }
+
+    let solver_options = SolverOptions {
+        weights: options.weights,
+        heroic_cost: Some(if *special_ability == Effect::Dodge {
+            3
+        } else {
+            2
+        }),
+    };
+    let per_type: Vec<Vec<(Vec<Dice>, f64)>> = dice_types
+        .iter()
+        .map(|(colour, count)| rolls_of(colour, *count))
+        .collect();
+
+    let mut pools = Vec::with_capacity(rolls);
+    let mut indices = vec![0; per_type.len()];
+    loop {
+        let mut pool = Vec::new();
+        let mut probability = 1.0;
+        for (rolls, index) in per_type.iter().zip(indices.iter()) {
+            let (dice, chance) = &rolls[*index];
+            pool.extend(dice.iter().cloned());
+            probability *= chance;
+        }
+        pools.push((pool, probability));
+
+        // Step to the next combination, odometer style.
+        let mut position = 0;
+        while position < indices.len() {
+            indices[position] += 1;
+            if indices[position] < per_type[position].len() {
+                break;
+            }
+            indices[position] = 0;
+            position += 1;
+        }
+        if position == indices.len() {
+            break;
+        }
+    }
+
+    // Every roll is solved independently, so the work is split across threads.
+    let threads = std::thread::available_parallelism().map_or(1, |threads| threads.get());
+    let chunk_size = pools.len().div_ceil(threads).max(1);
+    let partials: Vec<Odds> = std::thread::scope(|scope| {
+        let handles: Vec<_> = pools
+            .chunks(chunk_size)
+            .map(|chunk| {
+                scope.spawn(move || {
+                    let mut odds = Odds::default();
+                    for (pool, probability) in chunk {
+                        let (health, time, cleared, exact) =
+                            resolve(pool, special_ability, boxes, &solver_options);
+                        *odds
+                            .outcomes
+                            .entry((health, time + time_cost))
+                            .or_insert(0.0) += probability;
+                        if cleared {
+                            odds.clear += probability;
+                        }
+                        odds.approximate |= !exact;
+                    }
+                    odds
+                })
+            })
+            .collect();
+        handles
+            .into_iter()
+            .map(|handle| {
+                handle
+                    .join()
+                    .unwrap_or_else(|panic| std::panic::resume_unwind(panic))
+            })
+            .collect()
+    });
+
+    let mut odds = Odds {
+        rolls,
+        ..Default::default()
+    };
+    for partial in partials {
+        for (outcome, probability) in partial.outcomes {
+            *odds.outcomes.entry(outcome).or_insert(0.0) += probability;
+        }
+        odds.clear += partial.clear;
+        odds.approximate |= partial.approximate;
+    }
+    Ok(odds)
+}
+
+/// Whether the odds account for all `special_ability` does to health and time.
+/// Abilities that only touch loot, items or skills count, since the odds leave
+/// those out anyway; Swarm is neither played by the game nor rated.
+pub fn accounts_for(special_ability: &Effect) -> bool {
+    matches!(
+        special_ability,
+        Effect::None
+            | Effect::Frost
+            | Effect::Flames
+            | Effect::Ethereal
+            | Effect::Split
+            | Effect::Undying
+            | Effect::Dodge
+            | Effect::Drain
+            | Effect::Survivor
+            | Effect::Fade
+    )
+}
+
+/// Health and time lost to one roll, whether every box was filled, and whether
+/// the solver's placement is known to be the best.
+fn resolve(
+    pool: &[Dice],
+    special_ability: &Effect,
+    boxes: &[ChallengeBox],
+    options: &SolverOptions,
+) -> (usize, usize, bool, bool) {
+    let mut pool = pool.to_vec();
+    let mut health = 0;
+    let mut time = 0;
+    match special_ability {
+        Effect::Frost => time += 3,
+        Effect::Flames => health += 1,
+        Effect::Ethereal => pool.retain(|dice| dice.value != 1 && dice.value != 3),
+        Effect::Split => time += pool.iter().filter(|dice| dice.value == 1).count(),
+        _ => {}
+    }
+    let assignment = solver::solve(&pool, boxes, options);
+    let cleared = assignment.completed.iter().all(|completed| *completed);
+    health += assignment.health;
+    time += assignment.time;
+    if *special_ability == Effect::Undying && !cleared {
+        time += 2;
+    }
+    (health, time, cleared, assignment.exact)
+}
+
+/// Odds for fighting `card` on `floor`, including the dungeon's floor boxes. Combats
+/// give one entry and perils one per option, with the option's time cost included.
+pub fn card_odds(
+    hero: &Hero,
+    dungeon: &Dungeon,
+    floor: i8,
+    card: &Card,
+    options: &OddsOptions,
+) -> Result<Vec<Odds>> {
+    match card {
+        Card::Combat(combat) => {
+            let mut boxes = combat.challenges.clone();
+            boxes.extend(dungeon.floor_challenges(floor, Encounter::Combat));
+            Ok(vec![encounter_odds(
+                hero,
+                &combat.special_ability,
+                &boxes,
+                0,
+                options,
+            )?])
+        }
+        Card::Peril(peril) => [
+            (&peril.choice_one, peril.choice_one_time_cost),
+            (&peril.choice_two, peril.choice_two_time_cost),
+        ]
+        .into_iter()
+        .map(|(challenges, time_cost)| {
+            let mut boxes = challenges.clone();
+            boxes.extend(dungeon.floor_challenges(floor, Encounter::Peril));
+            let time_cost = time_cost.unwrap_or(0).max(0) as usize;
+            encounter_odds(hero, &Effect::None, &boxes, time_cost, options)
+        })
+        .collect(),
+    }
+}
+
+/// Number of distinct sorted rolls of `count` six-sided dice.
+fn multiset_count(count: usize) -> usize {
+    (1..=5).fold(1, |total, k| total * (count + k) / k)
+}
+
+/// Every sorted roll of `count` dice of one type with its probability.
+fn rolls_of(dice_type: &DiceColour, count: usize) -> Vec<(Vec<Dice>, f64)> {
+    let mut rolls = Vec::new();
+    let mut values = Vec::with_capacity(count);
+    collect_rolls(dice_type, count, 1, &mut values, &mut rolls);
+    rolls
+}
+
+fn collect_rolls(
+    dice_type: &DiceColour,
+    count: usize,
+    lowest: i8,
+    values: &mut Vec<i8>,
+    rolls: &mut Vec<(Vec<Dice>, f64)>,
+) {
+    if values.len() == count {
+        let dice = values
+            .iter()
+            .map(|value| Dice {
+                dice_type: *dice_type,
+                value: *value,
+            })
+            .collect();
+        rolls.push((dice, roll_probability(values)));
+        return;
+    }
+    for value in lowest..=6 {
+        values.push(value);
+        collect_rolls(dice_type, count, value, values, rolls);
+        values.pop();
+    }
+}
+
+/// Chance of rolling exactly these sorted values, in any order.
+fn roll_probability(values: &[i8]) -> f64 {
+    let mut orderings = (1..=values.len()).map(|n| n as f64).product::<f64>();
+    for value in 1..=6 {
+        let repeats = values.iter().filter(|dice| **dice == value).count();
+        orderings /= (1..=repeats).map(|n| n as f64).product::<f64>();
+    }
+    orderings / 6f64.powi(values.len() as i32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        game_setup::{self, pack},
+        hero::{Attribute, PlayerCount, Resource},
+        hero_builder::HeroBuilder,
+    };
+
+    #[test]
+    fn outcomes_add_up_to_one() {
+        let hero = HeroBuilder::new("Tester").hero().clone();
+        for combat in game_setup::get_all_combats().iter().take(2) {
+            let odds = encounter_odds(
+                &hero,
+                &combat.special_ability,
+                &combat.challenges,
+                0,
+                &OddsOptions::default(),
+            )
+            .unwrap();
+            let total: f64 = odds.outcomes.values().sum();
+            assert!((total - 1.0).abs() < 1e-9, "{}: {}", combat.name, total);
+            assert!(odds.clear <= total + 1e-9);
+            assert!(!odds.approximate);
+        }
+    }
+
+    #[test]
+    fn one_dice_against_one_box_matches_the_odds_of_a_roll() {
+        // A single Strength dice clears a Strength 5 box on a 5 or a 6.
+        let hero = HeroBuilder::new("Tester")
+            .with_dice(DiceColour::Strength, 1)
+            .with_dice(DiceColour::Agility, 0)
+            .with_dice(DiceColour::Magic, 0)
+            .hero()
+            .clone();
+        let boxes = [ChallengeBox {
+            dice_type: Some(DiceColour::Strength),
+            total_value: 5,
+            single_dice: true,
+            priority: false,
+            consequences: vec![
+                Attribute::Resource(Resource::Health, 2),
+                Attribute::Resource(Resource::Time, 1),
+            ],
+        }];
+        let odds =
+            encounter_odds(&hero, &Effect::None, &boxes, 1, &OddsOptions::default()).unwrap();
+        assert_eq!(odds.rolls, 6);
+        assert!((odds.clear - 2.0 / 6.0).abs() < 1e-9);
+        assert!((odds.outcomes[&(0, 1)] - 2.0 / 6.0).abs() < 1e-9);
+        assert!((odds.outcomes[&(2, 2)] - 4.0 / 6.0).abs() < 1e-9);
+        assert!((odds.expected_health() - 8.0 / 6.0).abs() < 1e-9);
+        assert!((odds.survival_chance(2) - 2.0 / 6.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn refuses_too_many_rolls() {
+        let hero = pack::get_core_pack(PlayerCount::Solo).heroes.remove(0);
+        let options = OddsOptions {
+            max_rolls: 1,
+            ..OddsOptions::default()
+        };
+        assert!(matches!(
+            encounter_odds(&hero, &Effect::None, &[], 0, &options),
+            Err(OddsError::TooManyRolls { limit: 1, .. })
+        ));
+    }
+}
@@ -0,0 +1,571 @@
+use rand::{rngs::StdRng, SeedableRng};
+
+use crate::{
+    dungeon::ChallengeBox,
+    encounter::Encounter,
+    game::{
+        Action, Card, Dice, EncounterState, LootChoice, Observation, Phase, PlacedBox, MAX_DOORS,
+        POTION_HEAL,
+    },
+    hero::{DiceColour, Effect, Hero},
+    solver::{self, Weights},
+};
+
+/// Decides the next action for a hero from what the player can see. Used by the
+/// simulator and anything else that plays games without a human at the keyboard.
+pub trait Policy {
+    fn name(&self) -> &str;
+
+    fn decide(&mut self, observation: &Observation) -> Action;
+}
+
+/// Takes the first option that works: fights every door, places each dice in the
+/// first box that takes it and loots items before skills before XP.
+#[derive(Debug, Default, Clone)]
+pub struct FirstFitPolicy;
+
+impl Policy for FirstFitPolicy {
+    fn name(&self) -> &str {
+        "first-fit"
+    }
+
+    fn decide(&mut self, observation: &Observation) -> Action {
+        match observation.phase {
+            Phase::Exploring => {
+                if observation.hero.potions > 0 && observation.damage >= POTION_HEAL {
+                    Action::DrinkPotion
+                } else if let Some(door) = observation.doors.iter().position(Option::is_none) {
+                    Action::OpenDoor(door)
+                } else if !observation.doors.is_empty() {
+                    Action::OpenDoor(0)
+                } else if observation.deck_size == 0 {
+                    Action::Descend
+                } else if observation.doors.len() < MAX_DOORS {
+                    Action::Explore
+                } else {
+                    Action::Descend
+                }
+            }
+            Phase::DoorOpened(_) => Action::Fight,
+            Phase::ChoosingPeril(_) => Action::ChoosePeril(0),
+            Phase::Encounter => {
+                let Some(state) = observation.encounter else {
+                    return Action::FinishEncounter;
+                };
+                let priority = state.priority_outstanding();
+                for (dice_index, dice) in state.pool.iter().enumerate() {
+                    for (box_index, placed) in state.boxes.iter().enumerate() {
+                        if priority && !placed.challenge.priority {
+                            continue;
+                        }
+                        if placed.accepts(dice) {
+                            return Action::PlaceDice {
+                                dice: dice_index,
+                                challenge_box: box_index,
+                            };
+                        }
+                    }
+                }
+                Action::FinishEncounter
+            }
+            Phase::Loot => first_loot(observation),
+            Phase::Won | Phase::Lost(_) => Action::FinishEncounter,
+        }
+    }
+}
+
+fn first_loot(observation: &Observation) -> Action {
+    [LootChoice::Item, LootChoice::Skill]
+        .into_iter()
+        .find(|choice| observation.loot_options.contains(choice))
+        .map_or(Action::Loot(LootChoice::Xp), Action::Loot)
+}
+
+/// Number of dice rolls sampled when estimating how an encounter will go.
+pub const DEFAULT_SAMPLES: usize = 20;
+
+/// Fills priority boxes first and then the boxes that would cost the most, and
+/// flees or picks the cheaper option whenever sampled rolls say a fight is likely
+/// to cost more health than it is worth.
+#[derive(Debug, Clone)]
+pub struct GreedyPolicy {
+    rng: StdRng,
+    samples: usize,
+}
+
+impl GreedyPolicy {
+    pub fn new(seed: u64) -> GreedyPolicy {
+        GreedyPolicy {
+            rng: StdRng::seed_from_u64(seed),
+            samples: DEFAULT_SAMPLES,
+        }
+    }
+
+    pub fn with_samples(mut self, samples: usize) -> GreedyPolicy {
+        self.samples = samples.max(1);
+        self
+    }
+
+    /// Average health lost to the encounter behind a card, counting time as
+    /// health once the deck can no longer pay for it.
+    fn expected_loss(&mut self, observation: &Observation, card: &Card) -> f64 {
+        match card {
+            Card::Combat(combat) => {
+                let mut boxes = combat.challenges.clone();
+                boxes.extend(
+                    observation
+                        .dungeon
+                        .floor_challenges(observation.floor, Encounter::Combat),
+                );
+                self.sample_loss(
+                    observation,
+                    Encounter::Combat,
+                    &combat.special_ability,
+                    &boxes,
+                    0,
+                )
+            }
+            Card::Peril(_) => (0..2)
+                .map(|choice| self.peril_loss(observation, card, choice))
+                .fold(f64::INFINITY, f64::min),
+        }
+    }
+
+    fn peril_loss(&mut self, observation: &Observation, card: &Card, choice: usize) -> f64 {
+        let Card::Peril(peril) = card else {
+            return 0.0;
+        };
+        let (challenges, time_cost) = if choice == 0 {
+            (&peril.choice_one, peril.choice_one_time_cost)
+        } else {
+            (&peril.choice_two, peril.choice_two_time_cost)
+        };
+        let mut boxes = challenges.clone();
+        boxes.extend(
+            observation
+                .dungeon
+                .floor_challenges(observation.floor, Encounter::Peril),
+        );
+        let time_cost = time_cost.unwrap_or(0).max(0) as usize;
+        self.sample_loss(
+            observation,
+            Encounter::Peril,
+            &Effect::None,
+            &boxes,
+            time_cost,
+        )
+    }
+
+    fn sample_loss(
+        &mut self,
+        observation: &Observation,
+        encounter: Encounter,
+        special_ability: &Effect,
+        boxes: &[ChallengeBox],
+        time_cost: usize,
+    ) -> f64 {
+        let mut total = 0.0;
+        for _ in 0..self.samples {
+            let mut pool = roll_pool(observation.hero, &mut self.rng);
+            let mut health = 0;
+            let mut time = time_cost;
+            match special_ability {
+                Effect::Frost => time += 3,
+                Effect::Flames => health += 1,
+                Effect::Ethereal => pool.retain(|dice| dice.value != 1 && dice.value != 3),
+                Effect::Split => time += pool.iter().filter(|dice| dice.value == 1).count(),
+                _ => {}
+            }
+            let mut state = EncounterState {
+                encounter,
+                name: String::new(),
+                special_ability: special_ability.clone(),
+                boxes: boxes.iter().cloned().map(PlacedBox::new).collect(),
+                pool,
+                prevented: Vec::new(),
+                used_skills: Vec::new(),
+            };
+            while let Some(action) = plan_dice(&state) {
+                apply_plan(&mut state, action);
+            }
+            let (box_health, box_time) = unfilled_cost(&state);
+            health += box_health;
+            time += box_time;
+            total += health_equivalent(observation, health, time);
+        }
+        total / self.samples as f64
+    }
+
+    /// Whether a fight is worth taking rather than looking for a better door.
+    fn acceptable(observation: &Observation, loss: f64) -> bool {
+        loss < (observation.remaining_health as f64 / 2.0).max(1.0)
+    }
+
+    fn explore(&mut self, observation: &Observation) -> Action {
+        if observation.hero.potions > 0
+            && (observation.damage >= POTION_HEAL
+                || (observation.damage > 0 && observation.remaining_health <= 2))
+        {
+            return Action::DrinkPotion;
+        }
+        let mut best: Option<(usize, f64)> = None;
+        for (index, door) in observation.doors.iter().enumerate() {
+            if let Some(card) = door {
+                let loss = self.expected_loss(observation, card);
+                if best.is_none_or(|(_, best_loss)| loss < best_loss) {
+                    best = Some((index, loss));
+                }
+            }
+        }
+        if let Some((door, loss)) = best {
+            if Self::acceptable(observation, loss) {
+                return Action::OpenDoor(door);
+            }
+        }
+        if let Some(door) = observation.doors.iter().position(Option::is_none) {
+            return Action::OpenDoor(door);
+        }
+        if observation.deck_size == 0 {
+            return Action::Descend;
+        }
+        if observation.doors.len() < MAX_DOORS {
+            return Action::Explore;
+        }
+        // Every door is open and none of them look good: take the least bad one.
+        best.map_or(Action::Explore, |(door, _)| Action::OpenDoor(door))
+    }
+
+    fn door_opened(&mut self, observation: &Observation, door: usize) -> Action {
+        let Some(Some(card)) = observation.doors.get(door) else {
+            return Action::Fight;
+        };
+        let loss = self.expected_loss(observation, card);
+        if Self::acceptable(observation, loss) {
+            return Action::Fight;
+        }
+        let alternatives = observation.doors.iter().any(Option::is_none)
+            || observation.doors.len() < MAX_DOORS
+            || observation.deck_size == 0;
+        let dying = loss >= observation.remaining_health as f64;
+        if observation.deck_size > 0 && (alternatives || dying) {
+            Action::Flee
+        } else {
+            Action::Fight
+        }
+    }
+
+    fn choose_peril(&mut self, observation: &Observation, door: usize) -> Action {
+        let Some(Some(card)) = observation.doors.get(door) else {
+            return Action::ChoosePeril(0);
+        };
+        let first = self.peril_loss(observation, card, 0);
+        let second = self.peril_loss(observation, card, 1);
+        Action::ChoosePeril(if second < first { 1 } else { 0 })
+    }
+
+    fn encounter(&mut self, observation: &Observation) -> Action {
+        let Some(state) = observation.encounter else {
+            return Action::FinishEncounter;
+        };
+        if let Some(skill) = free_skill(observation, state) {
+            return Action::UseSkill { skill, dice: None };
+        }
+        match plan_dice(state) {
+            Some(DicePlan::Place {
+                dice,
+                challenge_box,
+            }) => Action::PlaceDice {
+                dice,
+                challenge_box,
+            },
+            Some(DicePlan::Heroic(dice)) => Action::MakeHeroic(dice),
+            None => Action::FinishEncounter,
+        }
+    }
+}
+
+impl Policy for GreedyPolicy {
+    fn name(&self) -> &str {
+        "greedy"
+    }
+
+    fn decide(&mut self, observation: &Observation) -> Action {
+        match observation.phase {
+            Phase::Exploring => self.explore(observation),
+            Phase::DoorOpened(door) => self.door_opened(observation, *door),
+            Phase::ChoosingPeril(door) => self.choose_peril(observation, *door),
+            Phase::Encounter => self.encounter(observation),
+            Phase::Loot => first_loot(observation),
+            Phase::Won | Phase::Lost(_) => Action::FinishEncounter,
+        }
+    }
+}
+
+/// Chooses doors like [`GreedyPolicy`] but places dice with the optimal solver.
+#[derive(Debug, Clone)]
+pub struct SolverPolicy {
+    greedy: GreedyPolicy,
+    weights: Weights,
+}
+
+impl SolverPolicy {
+    pub fn new(seed: u64) -> SolverPolicy {
+        SolverPolicy {
+            greedy: GreedyPolicy::new(seed),
+            weights: Weights::default(),
+        }
+    }
+
+    pub fn with_weights(mut self, weights: Weights) -> SolverPolicy {
+        self.weights = weights;
+        self
+    }
+}
+
+impl Policy for SolverPolicy {
+    fn name(&self) -> &str {
+        "solver"
+    }
+
+    fn decide(&mut self, observation: &Observation) -> Action {
+        let (Phase::Encounter, Some(state)) = (observation.phase, observation.encounter) else {
+            return self.greedy.decide(observation);
+        };
+        if let Some(skill) = free_skill(observation, state) {
+            return Action::UseSkill { skill, dice: None };
+        }
+        solver::solve_encounter(state, self.weights)
+            .actions()
+            .into_iter()
+            .next()
+            .unwrap_or(Action::FinishEncounter)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum DicePlan {
+    Place { dice: usize, challenge_box: usize },
+    Heroic(Vec<usize>),
+}
+
+fn roll_pool(hero: &Hero, rng: &mut StdRng) -> Vec<Dice> {
+    let mut pool = Vec::new();
+    for colour in [DiceColour::Strength, DiceColour::Agility, DiceColour::Magic] {
+        for _ in 0..hero.dice_count(&colour) {
+            pool.push(Dice::roll(colour, rng));
+        }
+    }
+    for _ in 0..hero.encounter_bonus.max(0) {
+        pool.push(Dice::roll(DiceColour::Heroic, rng));
+    }
+    pool
+}
+
+fn unfilled_cost(state: &EncounterState) -> (usize, usize) {
+    let mut health = 0;
+    let mut time = 0;
+    for placed in state.boxes.iter().filter(|placed| !placed.is_complete()) {
+        let (box_health, box_time) = placed.challenge.costs();
+        health += box_health;
+        time += box_time;
+    }
+    if state.special_ability == Effect::Undying && state.boxes.iter().any(|b| !b.is_complete()) {
+        time += 2;
+    }
+    (health, time)
+}
+
+/// Time only hurts once the deck runs out, until then it is worth a fraction of a health.
+fn health_equivalent(observation: &Observation, health: usize, time: usize) -> f64 {
+    let covered = time.min(observation.deck_size);
+    health as f64 + (time - covered) as f64 + covered as f64 * 0.25
+}
+
+/// Picks the next dice move: priority boxes first, then the costliest boxes that
+/// can still be completed, spending the smallest dice that does the job.
+fn plan_dice(state: &EncounterState) -> Option<DicePlan> {
+    let priority = state.priority_outstanding();
+    let mut order: Vec<usize> = (0..state.boxes.len())
+        .filter(|index| !priority || state.boxes[*index].challenge.priority)
+        .filter(|index| !state.boxes[*index].is_complete())
+        .collect();
+    order.sort_by_key(|index| {
+        let challenge = &state.boxes[*index].challenge;
+        (
+            !challenge.priority,
+            std::cmp::Reverse(Weights::default().box_cost(challenge)),
+        )
+    });
+
+    for box_index in order.iter().copied() {
+        let placed = &state.boxes[box_index];
+        let fitting: Vec<usize> = (0..state.pool.len())
+            .filter(|dice| placed.accepts(&state.pool[*dice]))
+            .collect();
+        if fitting.is_empty() {
+            continue;
+        }
+        let needed = placed.challenge.total_value - placed.total();
+        let available: i8 = fitting.iter().map(|dice| state.pool[*dice].value).sum();
+        if available < needed {
+            continue;
+        }
+        // Hold heroic dice back when a coloured dice does the same job.
+        let rank = |dice: &usize| {
+            let dice = &state.pool[*dice];
+            (dice.value, dice.dice_type == DiceColour::Heroic)
+        };
+        let dice = fitting
+            .iter()
+            .filter(|dice| state.pool[**dice].value >= needed)
+            .min_by_key(|dice| rank(dice))
+            .or_else(|| fitting.iter().max_by_key(|dice| rank(dice)))
+            .copied()?;
+        return Some(DicePlan::Place {
+            dice,
+            challenge_box: box_index,
+        });
+    }
+
+    // A priority box that cannot be completed still has to soak up the dice it
+    // takes before anything else can be placed, so give it the smallest.
+    if priority {
+        let placement = order
+            .iter()
+            .flat_map(|box_index| (0..state.pool.len()).map(move |dice| (dice, *box_index)))
+            .filter(|(dice, box_index)| state.boxes[*box_index].accepts(&state.pool[*dice]))
+            .min_by_key(|(dice, _)| state.pool[*dice].value);
+        if let Some((dice, challenge_box)) = placement {
+            return Some(DicePlan::Place {
+                dice,
+                challenge_box,
+            });
+        }
+    }
+
+    // Nothing else fits, so every dice left is spare: combine the highest into a
+    // heroic dice if that would go into one of the open boxes.
+    let cost = state.heroic_cost();
+    if state.pool.len() < cost {
+        return None;
+    }
+    let mut by_value: Vec<usize> = (0..state.pool.len()).collect();
+    by_value.sort_by_key(|dice| std::cmp::Reverse(state.pool[*dice].value));
+    let mut chosen: Vec<usize> = by_value.into_iter().take(cost).collect();
+    // In increasing order, the way the game lists heroic dice among its legal actions.
+    chosen.sort_unstable();
+    let heroic = Dice {
+        dice_type: DiceColour::Heroic,
+        value: chosen
+            .iter()
+            .map(|dice| state.pool[*dice].value)
+            .min()
+            .unwrap_or(1),
+    };
+    let useful = order.iter().any(|box_index| {
+        let placed = &state.boxes[*box_index];
+        placed.accepts(&heroic) && placed.total() + heroic.value >= placed.challenge.total_value
+    });
+    useful.then_some(DicePlan::Heroic(chosen))
+}
+
+fn apply_plan(state: &mut EncounterState, plan: DicePlan) {
+    match plan {
+        DicePlan::Place {
+            dice,
+            challenge_box,
+        } => {
+            let dice = state.pool.remove(dice);
+            state.boxes[challenge_box].dice.push(dice);
+        }
+        DicePlan::Heroic(dice) => {
+            let value = dice
+                .iter()
+                .map(|index| state.pool[*index].value)
+                .min()
+                .unwrap_or(1);
+            for index in dice.iter().rev() {
+                state.pool.remove(*index);
+            }
+            state.pool.push(Dice {
+                dice_type: DiceColour::Heroic,
+                value,
+            });
+        }
+    }
+}
+
+/// A skill that costs nothing and only helps, if one is available.
+pub(crate) fn free_skill(observation: &Observation, state: &EncounterState) -> Option<usize> {
+    observation
+        .hero
+        .skills
+        .iter()
+        .enumerate()
+        .find(|(index, skill)| {
+            skill.requirements.is_none()
+                && skill.encounters.contains(&state.encounter)
+                && !state.used_skills.contains(index)
+                && match &skill.effect {
+                    Effect::Gain(_) | Effect::Roll(_) | Effect::Prevent(_) => true,
+                    Effect::Heal(_) => observation.damage > 0,
+                    _ => false,
+                }
+        })
+        .map(|(index, _)| index)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{game::Game, game_setup, hero::PlayerCount, simulator::DEFAULT_MAX_STEPS};
+
+    /// Plays a game for each built-in dungeon with `policy`, checking every
+    /// action is legal and that dice go to priority boxes while any can still
+    /// take one. Returns how many dice were placed on priority boxes.
+    fn check_games(mut make_policy: impl FnMut(u64) -> Box<dyn Policy>) -> usize {
+        let mut priority_placements = 0;
+        for (seed, dungeon) in game_setup::get_all_dungeons().into_iter().enumerate() {
+            let seed = seed as u64;
+            let mut game = Game::new(Hero::get_warrior(PlayerCount::Solo), dungeon, seed);
+            let mut policy = make_policy(seed);
+            let mut steps = 0;
+            while !game.is_over() && steps < DEFAULT_MAX_STEPS {
+                let observation = game.observe();
+                let action = policy.decide(&observation);
+                assert!(
+                    game.legal_actions().contains(&action),
+                    "{} chose {:?}",
+                    policy.name(),
+                    action
+                );
+                if let (Some(state), Action::PlaceDice { challenge_box, .. }) =
+                    (observation.encounter, &action)
+                {
+                    if state.priority_outstanding() {
+                        assert!(state.boxes[*challenge_box].challenge.priority);
+                        priority_placements += 1;
+                    }
+                }
+                game.apply(action).unwrap();
+                steps += 1;
+            }
+        }
+        priority_placements
+    }
+
+    #[test]
+    fn first_fit_plays_legally_and_fills_priority_boxes_first() {
+        assert!(check_games(|_| Box::new(FirstFitPolicy)) > 0);
+    }
+
+    #[test]
+    fn greedy_plays_legally_and_fills_priority_boxes_first() {
+        assert!(check_games(|seed| Box::new(GreedyPolicy::new(seed).with_samples(2))) > 0);
+    }
+
+    #[test]
+    fn solver_plays_legally_and_fills_priority_boxes_first() {
+        assert!(check_games(|seed| Box::new(SolverPolicy::new(seed))) > 0);
+    }
+}
@@ -0,0 +1,102 @@
+use std::io::{self, BufRead, Write};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    game::{Action, Game, Observation},
+    simulator::GameSummary,
+};
+
+/// A line the engine writes for the bot.
+#[derive(Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum EngineMessage<'a> {
+    /// The bot has to reply with one of `legal_actions`.
+    State {
+        observation: Observation<'a>,
+        legal_actions: Vec<Action>,
+        log: Vec<String>,
+    },
+    /// The last reply could not be used; the state that follows is unchanged.
+    Error { message: String },
+    GameOver {
+        summary: GameSummary,
+        log: Vec<String>,
+    },
+}
+
+/// A line the bot writes back: an index into `legal_actions`, or an action in full.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum BotReply {
+    Index(usize),
+    Action(Action),
+}
+
+/// Plays `game` to the end against a bot speaking JSON lines over `input` and
+/// `output`. Fails if the bot hangs up before the game is over.
+pub fn play<R: BufRead, W: Write>(
+    game: &mut Game,
+    mut input: R,
+    mut output: W,
+) -> io::Result<GameSummary> {
+    let mut log = Vec::new();
+    while !game.is_over() {
+        let observation = game.observe();
+        let legal_actions = game.legal_actions();
+        send(
+            &mut output,
+            &EngineMessage::State {
+                observation,
+                legal_actions: legal_actions.clone(),
+                log: std::mem::take(&mut log),
+            },
+        )?;
+
+        let mut line = String::new();
+        if input.read_line(&mut line)? == 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                "the bot left before the game was over",
+            ));
+        }
+        let action = match serde_json::from_str::<BotReply>(line.trim()) {
+            Ok(BotReply::Index(index)) => match legal_actions.get(index) {
+                Some(action) => action.clone(),
+                None => {
+                    send_error(&mut output, format!("there is no legal action {}", index))?;
+                    continue;
+                }
+            },
+            Ok(BotReply::Action(action)) => action,
+            Err(err) => {
+                send_error(&mut output, format!("could not read reply: {}", err))?;
+                continue;
+            }
+        };
+        match game.apply(action) {
+            Ok(messages) => log = messages,
+            Err(err) => send_error(&mut output, err.to_string())?,
+        }
+    }
+    let summary = GameSummary::from_game(game);
+    send(
+        &mut output,
+        &EngineMessage::GameOver {
+            summary: summary.clone(),
+            log,
+        },
+    )?;
+    Ok(summary)
+}
+
+/// Writes one message as a line of JSON.
+pub fn send(output: &mut impl Write, message: &EngineMessage) -> io::Result<()> {
+    serde_json::to_writer(&mut *output, message)?;
+    writeln!(output)?;
+    output.flush()
+}
+
+fn send_error(output: &mut impl Write, message: String) -> io::Result<()> {
+    send(output, &EngineMessage::Error { message })
+}
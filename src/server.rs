@@ -0,0 +1,214 @@
+use std::{
+    io::{self, BufRead, BufReader, Write},
+    net::{TcpListener, TcpStream},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+    thread,
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    dungeon::Dungeon,
+    game::Game,
+    game_setup::{self, difficulty::Difficulty},
+    hero::{Hero, PlayerCount},
+    protocol::{self, EngineMessage},
+};
+
+pub const DEFAULT_ADDRESS: &str = "127.0.0.1:7878";
+
+/// The first line a client sends for each game. Anything left out falls back to
+/// the Warrior alone in the Dragon's Cave on standard difficulty, with a seed
+/// picked by the server. Naming a partner makes it a co-op game.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GameRequest {
+    pub hero: Option<String>,
+    pub partner: Option<String>,
+    pub dungeon: Option<String>,
+    pub difficulty: Option<Difficulty>,
+    pub seed: Option<u64>,
+}
+
+/// Accepts clients on `listener` for as long as it stays open, hosting each one's
+/// games on its own thread. Games without a requested seed count up from `seed`.
+pub fn serve(listener: TcpListener, seed: u64) -> io::Result<()> {
+    let next_seed = Arc::new(AtomicU64::new(seed));
+    for stream in listener.incoming() {
+        let stream = stream?;
+        let next_seed = Arc::clone(&next_seed);
+        thread::spawn(move || {
+            let peer = stream
+                .peer_addr()
+                .map_or(String::from("unknown client"), |addr| addr.to_string());
+            if let Err(err) = handle_stream(stream, &next_seed) {
+                eprintln!("{}: {}", peer, err);
+            }
+        });
+    }
+    Ok(())
+}
+
+fn handle_stream(stream: TcpStream, next_seed: &AtomicU64) -> io::Result<()> {
+    let reader = BufReader::new(stream.try_clone()?);
+    host(reader, stream, || next_seed.fetch_add(1, Ordering::Relaxed))
+}
+
+/// Runs one client's session: a `GameRequest` line starts a game, which is then
+/// played out with the bot protocol. The session ends when the client hangs up.
+pub fn host<R: BufRead, W: Write>(
+    mut input: R,
+    mut output: W,
+    mut next_seed: impl FnMut() -> u64,
+) -> io::Result<()> {
+    let mut line = String::new();
+    loop {
+        line.clear();
+        if input.read_line(&mut line)? == 0 {
+            return Ok(());
+        }
+        if line.trim().is_empty() {
+            continue;
+        }
+        let request = match serde_json::from_str::<GameRequest>(line.trim()) {
+            Ok(request) => request,
+            Err(err) => {
+                let message = format!("could not read game request: {}", err);
+                protocol::send(&mut output, &EngineMessage::Error { message })?;
+                continue;
+            }
+        };
+        let (hero, partner, dungeon) = match choose(&request) {
+            Ok(chosen) => chosen,
+            Err(message) => {
+                protocol::send(&mut output, &EngineMessage::Error { message })?;
+                continue;
+            }
+        };
+        let seed = request.seed.unwrap_or_else(&mut next_seed);
+        let difficulty = request.difficulty.unwrap_or_default();
+        let mut game = match partner {
+            Some(partner) => Game::co_op(hero, partner, dungeon, seed, difficulty),
+            None => Game::with_difficulty(hero, dungeon, seed, difficulty),
+        };
+        protocol::play(&mut game, &mut input, &mut output)?;
+    }
+}
+
+fn choose(request: &GameRequest) -> Result<(Hero, Option<Hero>, Dungeon), String> {
+    let players = match request.partner {
+        Some(_) => PlayerCount::Coop,
+        None => PlayerCount::Solo,
+    };
+    let hero = match &request.hero {
+        Some(name) => game_setup::find_hero(name, players).map_err(|err| err.to_string())?,
+        None => Hero::get_warrior(players),
+    };
+    let partner = match &request.partner {
+        Some(name) => Some(game_setup::find_hero(name, players).map_err(|err| err.to_string())?),
+        None => None,
+    };
+    if partner
+        .as_ref()
+        .is_some_and(|partner| partner.name == hero.name)
+    {
+        return Err(format!(
+            "{} cannot be both the hero and the partner",
+            hero.name
+        ));
+    }
+    let dungeon = match &request.dungeon {
+        Some(name) => game_setup::find_dungeon(name).map_err(|err| err.to_string())?,
+        None => Dungeon::get_dragons_cave(),
+    };
+    Ok((hero, partner, dungeon))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use serde_json::Value;
+
+    /// A loopback client talking to `host` the way a remote bot would.
+    struct Client {
+        reader: BufReader<TcpStream>,
+        writer: TcpStream,
+    }
+
+    impl Client {
+        fn connect() -> (Client, thread::JoinHandle<io::Result<()>>) {
+            let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+            let address = listener.local_addr().unwrap();
+            let server = thread::spawn(move || {
+                let (stream, _) = listener.accept()?;
+                let next_seed = AtomicU64::new(1);
+                handle_stream(stream, &next_seed)
+            });
+            let writer = TcpStream::connect(address).unwrap();
+            let reader = BufReader::new(writer.try_clone().unwrap());
+            (Client { reader, writer }, server)
+        }
+
+        fn send(&mut self, line: &str) {
+            writeln!(self.writer, "{}", line).unwrap();
+        }
+
+        fn receive(&mut self) -> Value {
+            let mut line = String::new();
+            self.reader.read_line(&mut line).unwrap();
+            serde_json::from_str(&line).unwrap()
+        }
+    }
+
+    #[test]
+    fn plays_a_game_to_the_end() {
+        let (mut client, server) = Client::connect();
+        client.send(r#"{"hero":"Mage","dungeon":"Dragon's Cave","seed":3}"#);
+        let mut message = client.receive();
+        assert_eq!(message["type"], "state");
+
+        // Descending is never legal at the start, so the state is sent again.
+        client.send(r#""Descend""#);
+        assert_eq!(client.receive()["type"], "error");
+        let again = client.receive();
+        assert_eq!(again["type"], "state");
+        assert_eq!(again["observation"], message["observation"]);
+
+        client.send("99");
+        assert_eq!(client.receive()["type"], "error");
+        message = client.receive();
+
+        let mut replies = 0;
+        while message["type"] == "state" {
+            assert!(replies < 10_000, "the game never ended");
+            client.send("0");
+            message = client.receive();
+            replies += 1;
+        }
+        assert_eq!(message["type"], "game_over");
+        assert!(message["summary"]["turns"].as_u64().unwrap() > 0);
+
+        client.writer.shutdown(std::net::Shutdown::Both).unwrap();
+        server.join().unwrap().unwrap();
+    }
+
+    #[test]
+    fn rejects_bad_game_requests() {
+        let (mut client, server) = Client::connect();
+        client.send("not json");
+        assert_eq!(client.receive()["type"], "error");
+        client.send(r#"{"hero":"Nobody"}"#);
+        let message = client.receive();
+        assert_eq!(message["type"], "error");
+        assert!(message["message"].as_str().unwrap().contains("Nobody"));
+        client.send(r#"{"hero":"Mage","partner":"mage"}"#);
+        let message = client.receive();
+        assert_eq!(message["type"], "error");
+        assert!(message["message"].as_str().unwrap().contains("both"));
+        client.writer.shutdown(std::net::Shutdown::Both).unwrap();
+        server.join().unwrap().unwrap();
+    }
+}
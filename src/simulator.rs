@@ -0,0 +1,295 @@
+use std::{collections::BTreeMap, fmt::Display};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    dungeon::Dungeon,
+    game::{Game, Phase},
+    game_setup::{self, difficulty::Difficulty},
+    hero::{Hero, PlayerCount},
+    policy::Policy,
+};
+
+/// Games that have not finished after this many actions are recorded as stalled.
+pub const DEFAULT_MAX_STEPS: usize = 5_000;
+pub const STALLED: &str = "Stalled";
+
+#[derive(Debug, Clone)]
+pub struct SimulationConfig {
+    pub games: usize,
+    pub seed: u64,
+    pub max_steps: usize,
+    pub difficulty: Difficulty,
+}
+
+impl Default for SimulationConfig {
+    fn default() -> Self {
+        SimulationConfig {
+            games: 100,
+            seed: 0,
+            max_steps: DEFAULT_MAX_STEPS,
+            difficulty: Difficulty::default(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GameSummary {
+    pub difficulty: Difficulty,
+    pub won: bool,
+    pub floor: i8,
+    pub remaining_health: usize,
+    pub turns: usize,
+    pub score: i32,
+    pub death_cause: Option<String>,
+}
+
+/// Plays one game to the end with `policy`, giving up after `max_steps` actions.
+pub fn play_game(game: &mut Game, policy: &mut impl Policy, max_steps: usize) -> GameSummary {
+    let mut steps = 0;
+    while !game.is_over() && steps < max_steps {
+        let action = policy.decide(&game.observe());
+        // Rejected actions still count as a step so a confused policy cannot loop forever.
+        let _ = game.apply(action);
+        steps += 1;
+    }
+    GameSummary::from_game(game)
+}
+
+impl GameSummary {
+    /// Summarises `game` as it stands; unfinished games count as stalled.
+    pub fn from_game(game: &Game) -> GameSummary {
+        let death_cause = match &game.phase {
+            Phase::Won => None,
+            Phase::Lost(cause) => Some(cause.clone()),
+            _ => Some(String::from(STALLED)),
+        };
+        GameSummary {
+            difficulty: game.difficulty,
+            won: game.phase == Phase::Won,
+            floor: game.floor,
+            remaining_health: game.remaining_health(),
+            turns: game.turns,
+            score: game.score(),
+            death_cause,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct MatchupStats {
+    pub hero: String,
+    pub dungeon: String,
+    pub difficulty: Difficulty,
+    pub games: usize,
+    pub wins: usize,
+    pub total_floor: usize,
+    pub total_remaining_health: usize,
+    pub death_causes: BTreeMap<String, usize>,
+}
+
+impl MatchupStats {
+    pub fn record(&mut self, summary: &GameSummary) {
+        self.games += 1;
+        if summary.won {
+            self.wins += 1;
+        }
+        self.total_floor += summary.floor.max(0) as usize;
+        self.total_remaining_health += summary.remaining_health;
+        if let Some(cause) = &summary.death_cause {
+            *self.death_causes.entry(cause.clone()).or_insert(0) += 1;
+        }
+    }
+
+    pub fn win_rate(&self) -> f64 {
+        ratio(self.wins, self.games)
+    }
+
+    pub fn average_floor(&self) -> f64 {
+        ratio(self.total_floor, self.games)
+    }
+
+    pub fn average_remaining_health(&self) -> f64 {
+        ratio(self.total_remaining_health, self.games)
+    }
+}
+
+fn ratio(total: usize, games: usize) -> f64 {
+    if games == 0 {
+        0.0
+    } else {
+        total as f64 / games as f64
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SimulationReport {
+    pub policy: String,
+    pub difficulty: Difficulty,
+    pub heroes: Vec<String>,
+    pub dungeons: Vec<String>,
+    pub matchups: Vec<MatchupStats>,
+}
+
+impl SimulationReport {
+    pub fn get(&self, hero: &str, dungeon: &str) -> Option<&MatchupStats> {
+        self.matchups
+            .iter()
+            .find(|stats| stats.hero == hero && stats.dungeon == dungeon)
+    }
+}
+
+impl Display for SimulationReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        const NAME_WIDTH: usize = 10;
+        const CELL_WIDTH: usize = 18;
+        writeln!(
+            f,
+            "Policy: {}  Difficulty: {}  (win % / average floor / average health left)",
+            self.policy, self.difficulty
+        )?;
+        write!(f, "{: <NAME_WIDTH$}", "")?;
+        for dungeon in self.dungeons.iter() {
+            write!(f, "{: >CELL_WIDTH$}", dungeon)?;
+        }
+        writeln!(f)?;
+        for hero in self.heroes.iter() {
+            write!(f, "{: <NAME_WIDTH$}", hero)?;
+            for dungeon in self.dungeons.iter() {
+                let cell = self.get(hero, dungeon).map_or(String::from("-"), |stats| {
+                    format!(
+                        "{:.0}% {:.1} {:.1}",
+                        stats.win_rate() * 100.0,
+                        stats.average_floor(),
+                        stats.average_remaining_health()
+                    )
+                });
+                write!(f, "{: >CELL_WIDTH$}", cell)?;
+            }
+            writeln!(f)?;
+        }
+
+        writeln!(f)?;
+        writeln!(f, "Death causes:")?;
+        for hero in self.heroes.iter() {
+            let mut causes: BTreeMap<&str, usize> = BTreeMap::new();
+            for stats in self.matchups.iter().filter(|stats| &stats.hero == hero) {
+                for (cause, count) in stats.death_causes.iter() {
+                    *causes.entry(cause).or_insert(0) += count;
+                }
+            }
+            let mut causes: Vec<(&str, usize)> = causes.into_iter().collect();
+            causes.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(b.0)));
+            let causes = causes
+                .iter()
+                .take(5)
+                .map(|(cause, count)| format!("{} x{}", cause, count))
+                .collect::<Vec<String>>()
+                .join(", ");
+            writeln!(f, "{: <NAME_WIDTH$}{}", hero, causes)?;
+        }
+        Ok(())
+    }
+}
+
+/// Plays `config.games` games for every hero against every dungeon. Each matchup
+/// uses the same run of seeds so results are comparable across heroes.
+pub fn simulate<P: Policy>(
+    heroes: &[Hero],
+    dungeons: &[Dungeon],
+    make_policy: impl Fn(u64) -> P,
+    config: &SimulationConfig,
+) -> SimulationReport {
+    let mut matchups = Vec::new();
+    let mut policy_name = String::new();
+    for hero in heroes {
+        for dungeon in dungeons {
+            let mut stats = MatchupStats {
+                hero: hero.name.clone(),
+                dungeon: dungeon.name.clone(),
+                difficulty: config.difficulty,
+                ..Default::default()
+            };
+            for index in 0..config.games {
+                let seed = config.seed.wrapping_add(index as u64);
+                let mut policy = make_policy(seed);
+                policy_name = policy.name().to_string();
+                let mut game =
+                    Game::with_difficulty(hero.clone(), dungeon.clone(), seed, config.difficulty);
+                stats.record(&play_game(&mut game, &mut policy, config.max_steps));
+            }
+            matchups.push(stats);
+        }
+    }
+    SimulationReport {
+        policy: policy_name,
+        difficulty: config.difficulty,
+        heroes: heroes.iter().map(|hero| hero.name.clone()).collect(),
+        dungeons: dungeons
+            .iter()
+            .map(|dungeon| dungeon.name.clone())
+            .collect(),
+        matchups,
+    }
+}
+
+/// Runs the full matrix of built-in heroes and dungeons.
+pub fn simulate_all<P: Policy>(
+    make_policy: impl Fn(u64) -> P,
+    config: &SimulationConfig,
+) -> SimulationReport {
+    simulate(
+        &game_setup::get_all_heroes(PlayerCount::Solo),
+        &game_setup::get_all_dungeons(),
+        make_policy,
+        config,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::policy::GreedyPolicy;
+
+    fn report(seed: u64) -> SimulationReport {
+        let heroes = game_setup::get_all_heroes(PlayerCount::Solo);
+        let dungeons = game_setup::get_all_dungeons();
+        let config = SimulationConfig {
+            games: 2,
+            seed,
+            ..Default::default()
+        };
+        simulate(
+            &heroes,
+            &dungeons,
+            |seed| GreedyPolicy::new(seed).with_samples(2),
+            &config,
+        )
+    }
+
+    #[test]
+    fn same_seed_same_report() {
+        assert_eq!(
+            serde_json::to_value(report(3)).unwrap(),
+            serde_json::to_value(report(3)).unwrap()
+        );
+    }
+
+    #[test]
+    fn plays_every_hero_against_every_dungeon() {
+        let report = report(5);
+        assert_eq!(report.policy, "greedy");
+        assert_eq!(
+            report.matchups.len(),
+            report.heroes.len() * report.dungeons.len()
+        );
+        for hero in report.heroes.iter() {
+            for dungeon in report.dungeons.iter() {
+                let stats = report.get(hero, dungeon).unwrap();
+                assert_eq!(stats.games, 2);
+                let lost: usize = stats.death_causes.values().sum();
+                assert_eq!(stats.wins + lost, 2);
+            }
+        }
+    }
+}
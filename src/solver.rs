@@ -0,0 +1,890 @@
+use crate::{
+    dungeon::ChallengeBox,
+    game::{Action, Dice, EncounterState, PlacedBox},
+    hero::DiceColour,
+};
+
+/// How much each unfilled consequence counts against an assignment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Weights {
+    pub health: usize,
+    pub time: usize,
+}
+
+impl Default for Weights {
+    fn default() -> Self {
+        Weights { health: 3, time: 1 }
+    }
+}
+
+impl Weights {
+    pub fn box_cost(&self, challenge: &ChallengeBox) -> usize {
+        let (health, time) = challenge.costs();
+        health * self.health + time * self.time
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SolverOptions {
+    pub weights: Weights,
+    /// Dice needed for one heroic dice, or `None` to never combine dice.
+    pub heroic_cost: Option<usize>,
+}
+
+impl Default for SolverOptions {
+    fn default() -> Self {
+        SolverOptions {
+            weights: Weights::default(),
+            heroic_cost: Some(2),
+        }
+    }
+}
+
+/// A dice placed by the solver: one from the pool, or a heroic dice combined from several.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SolvedDice {
+    Rolled(usize),
+    Heroic(Vec<usize>),
+}
+
+/// The best placement found. Dice are indices into the pool as it was passed in,
+/// and `boxes` lines up with the challenge boxes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Assignment {
+    pub boxes: Vec<Vec<SolvedDice>>,
+    pub completed: Vec<bool>,
+    pub leftover: Vec<usize>,
+    pub health: usize,
+    pub time: usize,
+    pub cost: usize,
+    /// False when the search was cut short, so a cheaper placement may exist.
+    pub exact: bool,
+    pool_len: usize,
+    priority: Vec<bool>,
+}
+
+impl Assignment {
+    /// The game actions that carry out this assignment: heroic dice are combined
+    /// first, then priority boxes are filled before the rest.
+    pub fn actions(&self) -> Vec<Action> {
+        let mut pool: Vec<usize> = (0..self.pool_len).collect();
+        let mut actions = Vec::new();
+        let mut placements: Vec<(usize, usize)> = Vec::new();
+        for (challenge_box, dice) in self.boxes.iter().enumerate() {
+            for solved in dice {
+                let tag = match solved {
+                    SolvedDice::Rolled(index) => *index,
+                    SolvedDice::Heroic(members) => {
+                        let mut indices: Vec<usize> = members
+                            .iter()
+                            .filter_map(|member| pool.iter().position(|dice| dice == member))
+                            .collect();
+                        indices.sort_unstable();
+                        for index in indices.iter().rev() {
+                            pool.remove(*index);
+                        }
+                        // Combined dice are tagged past the end of the original pool.
+                        let tag = self.pool_len + actions.len();
+                        pool.push(tag);
+                        actions.push(Action::MakeHeroic(indices));
+                        tag
+                    }
+                };
+                placements.push((challenge_box, tag));
+            }
+        }
+        placements.sort_by_key(|(challenge_box, _)| !self.priority[*challenge_box]);
+        for (challenge_box, tag) in placements {
+            if let Some(dice) = pool.iter().position(|dice| *dice == tag) {
+                pool.remove(dice);
+                actions.push(Action::PlaceDice {
+                    dice,
+                    challenge_box,
+                });
+            }
+        }
+        actions
+    }
+}
+
+/// Finds the placement of `pool` into fresh `boxes` that leaves the cheapest
+/// consequences unfilled.
+pub fn solve(pool: &[Dice], boxes: &[ChallengeBox], options: &SolverOptions) -> Assignment {
+    let placed: Vec<PlacedBox> = boxes.iter().cloned().map(PlacedBox::new).collect();
+    solve_placed(pool, &placed, options)
+}
+
+/// Solves what is left of an encounter that may already have dice in its boxes.
+pub fn solve_encounter(state: &EncounterState, weights: Weights) -> Assignment {
+    let options = SolverOptions {
+        weights,
+        heroic_cost: Some(state.heroic_cost()),
+    };
+    solve_placed(&state.pool, &state.boxes, &options)
+}
+
+/// A dice the solver can place: either one rolled dice or a heroic dice
+/// combined from several. `kinds` lists the kind of every pool dice it uses.
+#[derive(Debug, Clone)]
+struct Unit {
+    dice: Dice,
+    kinds: Vec<usize>,
+}
+
+/// Sets tried per box before the search settles for the ones it has; only very
+/// large pools get near this.
+const MAX_CANDIDATES: usize = 256;
+
+/// Unfilled cost first, then boxes left open; lower is better.
+type Score = (usize, usize);
+
+/// Kinds of dice the search keeps track of; pools never come close.
+const MAX_KINDS: usize = 32;
+
+/// Boxes the search keeps track of, one bit each.
+const MAX_BOXES: usize = 64;
+
+type Counts = [u8; MAX_KINDS];
+
+/// Search steps before settling for the best assignment found so far. A hero's
+/// full pool plus several heroic dice against a card and its floor boxes finishes
+/// well inside it. Past the cap no new branches are opened: the search keeps the
+/// cheapest complete placement it had reached, which is never worse than its
+/// first dive through the priority and then the costliest boxes, and clears
+/// [`Assignment::exact`] so callers such as the odds can say so.
+const MAX_NODES: usize = 50_000;
+
+struct Search<'a> {
+    pool: &'a [Dice],
+    boxes: &'a [PlacedBox],
+    options: &'a SolverOptions,
+    order: Vec<usize>,
+    costs: Vec<usize>,
+    /// Identical dice are interchangeable, so the search only counts how many
+    /// of each kind are left.
+    kinds: Vec<Dice>,
+    chosen: Vec<Vec<Unit>>,
+    best: Option<(Score, Vec<Vec<Unit>>)>,
+    floor: Score,
+    nodes: usize,
+    /// Set once any limit stopped the search from trying everything.
+    truncated: bool,
+}
+
+fn solve_placed(pool: &[Dice], boxes: &[PlacedBox], options: &SolverOptions) -> Assignment {
+    let costs: Vec<usize> = boxes
+        .iter()
+        .map(|placed| {
+            if placed.is_complete() {
+                0
+            } else {
+                options.weights.box_cost(&placed.challenge)
+            }
+        })
+        .collect();
+    // Priority boxes are decided first so later boxes know which are left open.
+    let mut order: Vec<usize> = (0..boxes.len().min(MAX_BOXES))
+        .filter(|index| !boxes[*index].is_complete())
+        .collect();
+    order.sort_by_key(|index| {
+        (
+            !boxes[*index].challenge.priority,
+            std::cmp::Reverse(costs[*index]),
+        )
+    });
+
+    let mut truncated = boxes.len() > MAX_BOXES;
+    let mut kinds: Vec<Dice> = Vec::new();
+    let mut counts: Counts = [0; MAX_KINDS];
+    for dice in pool {
+        match kinds.iter().position(|kind| kind == dice) {
+            Some(kind) => counts[kind] = counts[kind].saturating_add(1),
+            None if kinds.len() < MAX_KINDS => {
+                counts[kinds.len()] = 1;
+                kinds.push(dice.clone());
+            }
+            None => truncated = true,
+        }
+    }
+
+    let mut search = Search {
+        pool,
+        boxes,
+        options,
+        order,
+        costs,
+        kinds,
+        chosen: vec![Vec::new(); boxes.len()],
+        best: None,
+        floor: (0, 0),
+        nodes: 0,
+        truncated,
+    };
+    search.floor = search.lower_bound(0, &counts);
+    search.visit(0, counts, 0, (0, 0));
+
+    // Turn the kinds of dice chosen back into actual dice from the pool.
+    let best = search
+        .best
+        .take()
+        .map_or(vec![Vec::new(); boxes.len()], |(_, chosen)| chosen);
+    let mut chosen: Vec<Vec<SolvedDice>> = vec![Vec::new(); boxes.len()];
+    let mut used = vec![false; pool.len()];
+    for (box_index, units) in best.iter().enumerate() {
+        for unit in units.iter() {
+            let mut members = Vec::new();
+            for kind in unit.kinds.iter() {
+                if let Some(index) = (0..pool.len())
+                    .find(|index| !used[*index] && pool[*index] == search.kinds[*kind])
+                {
+                    used[index] = true;
+                    members.push(index);
+                }
+            }
+            match members.as_slice() {
+                [] => {}
+                [index] => chosen[box_index].push(SolvedDice::Rolled(*index)),
+                _ => chosen[box_index].push(SolvedDice::Heroic(members)),
+            }
+        }
+    }
+    search.assignment(chosen, used)
+}
+
+impl Search<'_> {
+    fn visit(&mut self, depth: usize, counts: Counts, open: u64, score: Score) {
+        self.nodes += 1;
+        if let Some((best, _)) = &self.best {
+            if *best == self.floor {
+                return;
+            }
+            if self.nodes > MAX_NODES {
+                self.truncated = true;
+                return;
+            }
+            let bound = self.lower_bound(depth, &counts);
+            if (score.0 + bound.0, score.1 + bound.1) >= *best {
+                return;
+            }
+        }
+        if depth == self.order.len() {
+            self.best = Some((score, self.chosen.clone()));
+            return;
+        }
+        let box_index = self.order[depth];
+        let (candidates, cut) = self.candidates(box_index, &counts, open);
+        self.truncated |= cut;
+        for units in candidates {
+            let mut left = counts;
+            for kind in units.iter().flat_map(|unit| unit.kinds.iter()) {
+                left[*kind] -= 1;
+            }
+            self.chosen[box_index] = units;
+            self.visit(depth + 1, left, open, score);
+        }
+        self.chosen[box_index] = Vec::new();
+        let open = if self.boxes[box_index].challenge.priority {
+            open | 1 << box_index
+        } else {
+            open
+        };
+        self.visit(
+            depth + 1,
+            counts,
+            open,
+            (score.0 + self.costs[box_index], score.1 + 1),
+        );
+    }
+
+    /// What the boxes from `depth` on cost at least: the ones that cannot be
+    /// completed even with every dice left to themselves.
+    fn lower_bound(&self, depth: usize, counts: &Counts) -> Score {
+        let mut bound = (0, 0);
+        for box_index in self.order[depth..].iter().copied() {
+            if !self.could_complete(box_index, counts) {
+                bound.0 += self.costs[box_index];
+                bound.1 += 1;
+            }
+        }
+        bound
+    }
+
+    fn could_complete(&self, box_index: usize, counts: &Counts) -> bool {
+        let placed = &self.boxes[box_index];
+        let heroic_cost = self.options.heroic_cost.filter(|cost| *cost > 1);
+        let mut accepted = 0;
+        let mut spare = Vec::new();
+        for (kind, dice) in self.kinds.iter().enumerate() {
+            if counts[kind] == 0 {
+                continue;
+            }
+            if placed.accepts(dice) {
+                if placed.challenge.single_dice {
+                    return true;
+                }
+                accepted += dice.value as i32 * counts[kind] as i32;
+            } else {
+                spare.extend(std::iter::repeat_n(dice.value, counts[kind] as usize));
+            }
+        }
+        let needed = (placed.challenge.total_value - placed.total()) as i32;
+        let Some(cost) = heroic_cost else {
+            return !placed.challenge.single_dice && accepted >= needed;
+        };
+        if placed.challenge.single_dice {
+            return spare
+                .iter()
+                .filter(|value| **value as i32 >= needed)
+                .count()
+                >= cost;
+        }
+        let highest = spare.iter().copied().max().unwrap_or(0) as i32;
+        accepted + (spare.len() / cost) as i32 * highest >= needed
+    }
+
+    fn kind_dice(&self, kind: usize) -> &Dice {
+        &self.kinds[kind]
+    }
+
+    /// Dice placed outside the priority boxes must not fit a priority box that
+    /// is left open, or the game would insist on them going there first.
+    fn allowed(&self, box_index: usize, open: u64, dice: &Dice) -> bool {
+        self.boxes[box_index].challenge.priority
+            || (0..self.boxes.len())
+                .filter(|index| open & (1 << index) != 0)
+                .all(|index| !self.boxes[index].accepts(dice))
+    }
+
+    fn units(&self, box_index: usize, counts: &[u8], open: u64) -> Vec<Unit> {
+        let placed = &self.boxes[box_index];
+        let mut units: Vec<Unit> = (0..self.kinds.len())
+            .filter(|kind| counts[*kind] > 0 && placed.accepts(self.kind_dice(*kind)))
+            .map(|kind| Unit {
+                dice: self.kind_dice(kind).clone(),
+                kinds: vec![kind],
+            })
+            .filter(|unit| self.allowed(box_index, open, &unit.dice))
+            .collect();
+
+        // A dice that fits on its own is always better placed as it is, so heroic
+        // dice are only combined from dice the box would not take.
+        if let Some(cost) = self.options.heroic_cost.filter(|cost| *cost > 1) {
+            let spare: Vec<usize> = (0..self.kinds.len())
+                .filter(|kind| counts[*kind] > 0 && !placed.accepts(self.kind_dice(*kind)))
+                .collect();
+            units.extend(self.heroic_units(box_index, counts, open, &spare, cost));
+        }
+        units.sort_by_key(|unit| std::cmp::Reverse(unit.dice.value));
+        units
+    }
+
+    /// Heroic dice made from each spare dice as the lowest of the group, topped up
+    /// with the weakest spare dice of each type that do not lower the value.
+    fn heroic_units(
+        &self,
+        box_index: usize,
+        counts: &[u8],
+        open: u64,
+        spare: &[usize],
+        cost: usize,
+    ) -> Vec<Unit> {
+        let mut units = Vec::new();
+        for lowest in spare.iter().copied() {
+            let value = self.kind_dice(lowest).value;
+            let mut left = counts.to_vec();
+            left[lowest] -= 1;
+            // Spare dice that could join, weakest first within each type.
+            let mut by_type: Vec<Vec<usize>> = Vec::new();
+            let mut partners: Vec<usize> = spare
+                .iter()
+                .copied()
+                .filter(|kind| left[*kind] > 0 && self.kind_dice(*kind).value >= value)
+                .collect();
+            partners.sort_by_key(|kind| self.kind_dice(*kind).value);
+            for kind in partners {
+                let dice_type = &self.kind_dice(kind).dice_type;
+                let list = match by_type
+                    .iter_mut()
+                    .find(|list| &self.kind_dice(list[0]).dice_type == dice_type)
+                {
+                    Some(list) => list,
+                    None => {
+                        by_type.push(Vec::new());
+                        by_type.last_mut().unwrap()
+                    }
+                };
+                for _ in 0..left[kind] {
+                    list.push(kind);
+                }
+            }
+            let mut group = vec![lowest];
+            self.top_up(&by_type, 0, cost - 1, &mut group, &mut units);
+        }
+        units.retain(|unit| {
+            self.boxes[box_index].accepts(&unit.dice) && self.allowed(box_index, open, &unit.dice)
+        });
+        units
+    }
+
+    fn top_up(
+        &self,
+        by_type: &[Vec<usize>],
+        type_index: usize,
+        missing: usize,
+        group: &mut Vec<usize>,
+        units: &mut Vec<Unit>,
+    ) {
+        if missing == 0 {
+            let mut kinds = group.clone();
+            kinds.sort_unstable();
+            if !units.iter().any(|unit| unit.kinds == kinds) {
+                units.push(Unit {
+                    dice: Dice {
+                        dice_type: DiceColour::Heroic,
+                        value: self.kind_dice(group[0]).value,
+                    },
+                    kinds,
+                });
+            }
+            return;
+        }
+        if type_index == by_type.len() {
+            return;
+        }
+        for taken in 0..=missing.min(by_type[type_index].len()) {
+            group.extend_from_slice(&by_type[type_index][..taken]);
+            self.top_up(by_type, type_index + 1, missing - taken, group, units);
+            group.truncate(group.len() - taken);
+        }
+    }
+
+    /// Every minimal set of units that completes the box with the dice left, and
+    /// whether there were too many to list them all.
+    fn candidates(&self, box_index: usize, counts: &[u8], open: u64) -> (Vec<Vec<Unit>>, bool) {
+        let placed = &self.boxes[box_index];
+        let units = self.units(box_index, counts, open);
+        if placed.challenge.single_dice {
+            // Any accepted dice finishes the box, so only the weakest of each type counts.
+            let candidates = units
+                .iter()
+                .enumerate()
+                .filter(|(index, unit)| {
+                    unit.kinds.len() > 1
+                        || !units[index + 1..].iter().any(|other| {
+                            other.kinds.len() == 1 && other.dice.dice_type == unit.dice.dice_type
+                        })
+                })
+                .map(|(_, unit)| vec![unit.clone()])
+                .collect();
+            return (candidates, false);
+        }
+        let needed = (placed.challenge.total_value - placed.total()) as i32;
+        let mut candidates = Vec::new();
+        let mut current = Vec::new();
+        collect_sets(
+            &units,
+            0,
+            needed,
+            &mut counts.to_owned(),
+            &mut current,
+            &mut candidates,
+        );
+        let cut = candidates.len() >= MAX_CANDIDATES;
+
+        // A set that spends weaker dice of the same colours leaves a pool that is
+        // at least as good, so only the sets nothing else beats are worth trying.
+        let spent: Vec<Vec<&Dice>> = candidates
+            .iter()
+            .map(|units| {
+                let mut dice: Vec<&Dice> = units
+                    .iter()
+                    .flat_map(|unit| unit.kinds.iter().map(|kind| self.kind_dice(*kind)))
+                    .collect();
+                dice.sort_by_key(|dice| dice.value);
+                dice
+            })
+            .collect();
+        let keep: Vec<bool> = (0..candidates.len())
+            .map(|index| {
+                !(0..candidates.len()).any(|other| {
+                    other != index
+                        && weaker(&spent[other], &spent[index])
+                        && (!weaker(&spent[index], &spent[other]) || other < index)
+                })
+            })
+            .collect();
+        let mut candidates: Vec<(usize, i32, Vec<Unit>)> = candidates
+            .into_iter()
+            .zip(spent.iter())
+            .zip(keep)
+            .filter_map(|((units, spent), keep)| {
+                let value = spent.iter().map(|dice| dice.value as i32).sum();
+                keep.then_some((spent.len(), value, units))
+            })
+            .collect();
+        // Cheapest sets first, so the search finds good answers early.
+        candidates.sort_by_key(|(dice, value, _)| (*dice, *value));
+        let candidates = candidates.into_iter().map(|(_, _, units)| units).collect();
+        (candidates, cut)
+    }
+
+    fn assignment(&self, mut boxes: Vec<Vec<SolvedDice>>, used: Vec<bool>) -> Assignment {
+        let mut completed: Vec<bool> = self
+            .boxes
+            .iter()
+            .zip(boxes.iter())
+            .map(|(placed, dice)| placed.is_complete() || !dice.is_empty())
+            .collect();
+        let mut leftover: Vec<usize> = (0..self.pool.len()).filter(|index| !used[*index]).collect();
+
+        // The game only lets dice go outside the priority boxes once nothing left
+        // fits an open one, so spare dice that would fit have to be dumped there.
+        let places_elsewhere = boxes
+            .iter()
+            .enumerate()
+            .any(|(index, dice)| !self.boxes[index].challenge.priority && !dice.is_empty());
+        if places_elsewhere {
+            for (index, placed) in self.boxes.iter().enumerate() {
+                if !placed.challenge.priority || completed[index] {
+                    continue;
+                }
+                let mut dumped = placed.clone();
+                leftover.retain(|dice| {
+                    if !dumped.accepts(&self.pool[*dice]) {
+                        return true;
+                    }
+                    dumped.dice.push(self.pool[*dice].clone());
+                    boxes[index].push(SolvedDice::Rolled(*dice));
+                    false
+                });
+                completed[index] = dumped.is_complete();
+            }
+        }
+
+        let mut health = 0;
+        let mut time = 0;
+        let mut cost = 0;
+        for (index, placed) in self.boxes.iter().enumerate() {
+            if !completed[index] {
+                let (box_health, box_time) = placed.challenge.costs();
+                health += box_health;
+                time += box_time;
+                cost += self.options.weights.box_cost(&placed.challenge);
+            }
+        }
+        Assignment {
+            boxes,
+            completed,
+            leftover,
+            health,
+            time,
+            cost,
+            exact: !self.truncated,
+            pool_len: self.pool.len(),
+            priority: self
+                .boxes
+                .iter()
+                .map(|placed| placed.challenge.priority)
+                .collect(),
+        }
+    }
+}
+
+/// Whether every dice in `a` can be matched to its own dice of the same type
+/// and at least the same value in `b`. Both are sorted by value.
+fn weaker(a: &[&Dice], b: &[&Dice]) -> bool {
+    if a.len() > b.len() {
+        return false;
+    }
+    let mut taken: u64 = 0;
+    // Matching the largest dice first against the largest free partner is optimal.
+    for dice in a.iter().rev() {
+        let partner = (0..b.len()).rev().find(|index| {
+            taken & (1 << index) == 0
+                && b[*index].dice_type == dice.dice_type
+                && b[*index].value >= dice.value
+        });
+        match partner {
+            Some(index) => taken |= 1 << index,
+            None => return false,
+        }
+    }
+    true
+}
+
+/// Walks units from largest to smallest, stopping as soon as the target is met
+/// so every set is minimal. A unit can be taken again while its dice last.
+fn collect_sets(
+    units: &[Unit],
+    start: usize,
+    needed: i32,
+    counts: &mut Vec<u8>,
+    current: &mut Vec<Unit>,
+    candidates: &mut Vec<Vec<Unit>>,
+) {
+    if needed <= 0 {
+        candidates.push(current.clone());
+        return;
+    }
+    let available = |unit: &Unit, counts: &[u8]| {
+        unit.kinds.iter().all(|kind| {
+            counts[*kind] as usize >= unit.kinds.iter().filter(|other| *other == kind).count()
+        })
+    };
+    for index in start..units.len() {
+        if candidates.len() >= MAX_CANDIDATES {
+            return;
+        }
+        let unit = &units[index];
+        if !available(unit, counts) {
+            continue;
+        }
+        // When one dice finishes the box, only the weakest of its type is worth spending.
+        let finishes = |other: &Unit| other.dice.value as i32 >= needed;
+        if finishes(unit)
+            && unit.kinds.len() == 1
+            && units[index + 1..].iter().any(|other| {
+                other.kinds.len() == 1
+                    && other.dice.dice_type == unit.dice.dice_type
+                    && finishes(other)
+                    && available(other, counts)
+            })
+        {
+            continue;
+        }
+        for kind in unit.kinds.iter() {
+            counts[*kind] -= 1;
+        }
+        current.push(unit.clone());
+        collect_sets(
+            units,
+            index,
+            needed - unit.dice.value as i32,
+            counts,
+            current,
+            candidates,
+        );
+        current.pop();
+        for kind in unit.kinds.iter() {
+            counts[*kind] += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    use super::*;
+    use crate::{
+        game_setup,
+        hero::{Attribute, PlayerCount, Resource},
+    };
+
+    fn dice(dice_type: DiceColour, value: i8) -> Dice {
+        Dice { dice_type, value }
+    }
+
+    fn challenge(
+        dice_type: Option<DiceColour>,
+        total_value: i8,
+        single_dice: bool,
+        priority: bool,
+        health: usize,
+    ) -> ChallengeBox {
+        ChallengeBox {
+            dice_type,
+            total_value,
+            single_dice,
+            priority,
+            consequences: if health > 0 {
+                vec![Attribute::Resource(Resource::Health, health)]
+            } else {
+                Vec::new()
+            },
+        }
+    }
+
+    #[test]
+    fn combines_spare_dice_into_a_heroic_wildcard() {
+        let pool = [dice(DiceColour::Agility, 5), dice(DiceColour::Magic, 6)];
+        let boxes = [challenge(Some(DiceColour::Strength), 5, true, false, 2)];
+        let assignment = solve(&pool, &boxes, &SolverOptions::default());
+        assert!(assignment.exact);
+        assert_eq!(assignment.completed, vec![true]);
+        assert_eq!(assignment.boxes[0], vec![SolvedDice::Heroic(vec![0, 1])]);
+        assert_eq!(assignment.actions()[0], Action::MakeHeroic(vec![0, 1]));
+
+        let never = SolverOptions {
+            heroic_cost: None,
+            ..SolverOptions::default()
+        };
+        let assignment = solve(&pool, &boxes, &never);
+        assert_eq!(assignment.completed, vec![false]);
+        assert_eq!(assignment.health, 2);
+    }
+
+    #[test]
+    fn fills_priority_boxes_before_the_rest() {
+        // The only dice fits both boxes, and the game makes it go to the priority
+        // box even though the other box costs health.
+        let pool = [dice(DiceColour::Strength, 4)];
+        let boxes = [
+            challenge(Some(DiceColour::Strength), 3, true, false, 1),
+            challenge(Some(DiceColour::Strength), 3, true, true, 0),
+        ];
+        let assignment = solve(&pool, &boxes, &SolverOptions::default());
+        assert_eq!(assignment.completed, vec![false, true]);
+        assert_eq!(assignment.health, 1);
+        assert_eq!(
+            assignment.actions(),
+            vec![Action::PlaceDice {
+                dice: 0,
+                challenge_box: 1
+            }]
+        );
+    }
+
+    #[test]
+    fn single_dice_boxes_need_one_dice_that_is_high_enough() {
+        let boxes = [challenge(Some(DiceColour::Magic), 5, true, false, 1)];
+        let low = [dice(DiceColour::Magic, 3), dice(DiceColour::Magic, 3)];
+        let never = SolverOptions {
+            heroic_cost: None,
+            ..SolverOptions::default()
+        };
+        assert_eq!(solve(&low, &boxes, &never).completed, vec![false]);
+        let high = [dice(DiceColour::Magic, 3), dice(DiceColour::Magic, 5)];
+        let assignment = solve(&high, &boxes, &never);
+        assert_eq!(assignment.completed, vec![true]);
+        assert_eq!(assignment.boxes[0], vec![SolvedDice::Rolled(1)]);
+        assert_eq!(assignment.leftover, vec![0]);
+    }
+
+    #[test]
+    fn handles_an_empty_pool() {
+        let boxes = [challenge(None, 3, false, false, 1)];
+        let assignment = solve(&[], &boxes, &SolverOptions::default());
+        assert!(assignment.exact);
+        assert_eq!(assignment.completed, vec![false]);
+        assert!(assignment.actions().is_empty());
+    }
+
+    /// The cheapest cost over every way of sending each dice to a box or
+    /// keeping it back, without heroic dice or priority boxes.
+    fn brute_force(pool: &[Dice], boxes: &[ChallengeBox], weights: Weights) -> usize {
+        let choices = boxes.len() + 1;
+        let mut best = usize::MAX;
+        for mut code in 0..choices.pow(pool.len() as u32) {
+            let mut placed: Vec<PlacedBox> = boxes.iter().cloned().map(PlacedBox::new).collect();
+            for dice in pool {
+                if let Some(target) = placed.get_mut(code % choices) {
+                    if target.accepts(dice) {
+                        target.dice.push(dice.clone());
+                    }
+                }
+                code /= choices;
+            }
+            let cost = placed
+                .iter()
+                .filter(|placed| !placed.is_complete())
+                .map(|placed| weights.box_cost(&placed.challenge))
+                .sum();
+            best = best.min(cost);
+        }
+        best
+    }
+
+    #[test]
+    fn matches_brute_force_on_small_pools() {
+        let colours = [DiceColour::Strength, DiceColour::Agility, DiceColour::Magic];
+        let options = SolverOptions {
+            heroic_cost: None,
+            ..SolverOptions::default()
+        };
+        let mut rng = StdRng::seed_from_u64(33);
+        for _ in 0..500 {
+            let pool: Vec<Dice> = (0..rng.gen_range(0..=5))
+                .map(|_| Dice::roll(colours[rng.gen_range(0..3)], &mut rng))
+                .collect();
+            let boxes: Vec<ChallengeBox> = (0..rng.gen_range(1..=3))
+                .map(|_| {
+                    let single_dice = rng.gen_bool(0.5);
+                    let dice_type = match rng.gen_range(0..4) {
+                        3 => None,
+                        colour => Some(colours[colour]),
+                    };
+                    let total_value = if single_dice {
+                        rng.gen_range(2..=6)
+                    } else {
+                        rng.gen_range(4..=12)
+                    };
+                    let mut challenge = challenge(
+                        dice_type,
+                        total_value,
+                        single_dice,
+                        false,
+                        rng.gen_range(1..=3),
+                    );
+                    if rng.gen_bool(0.5) {
+                        challenge
+                            .consequences
+                            .push(Attribute::Resource(Resource::Time, rng.gen_range(1..=2)));
+                    }
+                    challenge
+                })
+                .collect();
+            let assignment = solve(&pool, &boxes, &options);
+            assert!(assignment.exact);
+            assert_eq!(
+                assignment.cost,
+                brute_force(&pool, &boxes, options.weights),
+                "{:?} into {:?}",
+                pool,
+                boxes
+            );
+        }
+    }
+
+    /// Every built-in hero's pool plus two heroic dice against every combat and
+    /// the combat boxes of every floor, timed. Run it with
+    /// `cargo test --release -- --ignored`.
+    #[test]
+    #[ignore]
+    fn solves_typical_pools_in_under_a_millisecond() {
+        let mut rng = StdRng::seed_from_u64(118);
+        let mut solved = 0;
+        let mut elapsed = std::time::Duration::ZERO;
+        for hero in game_setup::get_all_heroes(PlayerCount::Solo) {
+            let mut colours: Vec<DiceColour> =
+                [DiceColour::Strength, DiceColour::Agility, DiceColour::Magic]
+                    .into_iter()
+                    .flat_map(|colour| std::iter::repeat_n(colour, hero.dice_count(&colour)))
+                    .collect();
+            colours.extend([DiceColour::Heroic; 2]);
+            for dungeon in game_setup::get_all_dungeons() {
+                for combat in game_setup::get_all_combats() {
+                    for floor in dungeon.combat_challenges.values() {
+                        let boxes: Vec<ChallengeBox> =
+                            combat.challenges.iter().chain(floor).cloned().collect();
+                        let pool: Vec<Dice> = colours
+                            .iter()
+                            .map(|colour| Dice::roll(*colour, &mut rng))
+                            .collect();
+                        let start = std::time::Instant::now();
+                        let assignment = solve(&pool, &boxes, &SolverOptions::default());
+                        elapsed += start.elapsed();
+                        assert!(assignment.exact, "{:?} into {:?}", pool, boxes);
+                        solved += 1;
+                    }
+                }
+            }
+        }
+        let average = elapsed / solved;
+        assert!(
+            average < std::time::Duration::from_millis(1),
+            "{:?} per pool",
+            average
+        );
+    }
+}
@@ -0,0 +1,485 @@
+use std::{io, path::PathBuf};
+
+use crossterm::event::KeyCode;
+
+use crate::{
+    dungeon::Dungeon,
+    error::DataError,
+    game::{Action, Game, LootChoice, Phase},
+    game_setup::{
+        difficulty::Difficulty,
+        pack::{self, ContentPack},
+    },
+    hero::{Hero, PlayerCount},
+    hero_builder::{self, HeroBuilder},
+    history::{History, RunRecord},
+    policy::{GreedyPolicy, Policy},
+};
+
+use super::editor::HeroEditor;
+
+const LOG_LENGTH: usize = 200;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Screen {
+    Setup,
+    Playing,
+    Editor,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SetupFocus {
+    Hero,
+    Partner,
+    Dungeon,
+    Packs,
+}
+
+pub struct App {
+    pub screen: Screen,
+    /// Every pack, and whether each one is in the game. The hero and dungeon
+    /// lists only offer cards from the packs that are.
+    pub packs: Vec<ContentPack>,
+    pub included: Vec<bool>,
+    pub heroes: Vec<Hero>,
+    pub dungeons: Vec<Dungeon>,
+    /// Heroes made in the editor this session, offered after the pack heroes.
+    pub custom_heroes: Vec<Hero>,
+    pub editor: Option<HeroEditor>,
+    pub hero_cursor: usize,
+    /// 0 plays solo; otherwise the partner is `heroes[partner_cursor - 1]`.
+    pub partner_cursor: usize,
+    pub dungeon_cursor: usize,
+    pub pack_cursor: usize,
+    pub setup_focus: SetupFocus,
+    pub difficulty: Difficulty,
+    pub game: Option<Game>,
+    pub dice_cursor: usize,
+    pub box_cursor: usize,
+    pub marked: Vec<usize>,
+    pub log: Vec<String>,
+    pub should_quit: bool,
+    /// Past games, shown on the setup screen when `show_stats` is on.
+    pub history: History,
+    pub show_stats: bool,
+    history_path: Option<PathBuf>,
+    hero_dir: PathBuf,
+    autoplay: GreedyPolicy,
+    seed: u64,
+}
+
+impl App {
+    pub fn new(seed: u64) -> App {
+        let packs = pack::get_all_packs(PlayerCount::Solo);
+        let mut app = App {
+            screen: Screen::Setup,
+            included: vec![true; packs.len()],
+            packs,
+            heroes: Vec::new(),
+            dungeons: Vec::new(),
+            custom_heroes: Vec::new(),
+            editor: None,
+            hero_cursor: 0,
+            partner_cursor: 0,
+            dungeon_cursor: 0,
+            pack_cursor: 0,
+            setup_focus: SetupFocus::Hero,
+            difficulty: Difficulty::default(),
+            game: None,
+            dice_cursor: 0,
+            box_cursor: 0,
+            marked: Vec::new(),
+            log: Vec::new(),
+            should_quit: false,
+            history: History::default(),
+            show_stats: false,
+            history_path: None,
+            hero_dir: PathBuf::from("."),
+            autoplay: GreedyPolicy::new(seed),
+            seed,
+        };
+        app.refresh_content();
+        app
+    }
+
+    /// Where the editor saves heroes; the current directory unless set.
+    pub fn with_hero_dir(mut self, dir: PathBuf) -> App {
+        self.hero_dir = dir;
+        self
+    }
+
+    /// Includes only the packs named in `names`, ignoring case.
+    pub fn with_packs(mut self, names: &[String]) -> App {
+        self.included = self
+            .packs
+            .iter()
+            .map(|pack| {
+                names
+                    .iter()
+                    .any(|name| name.eq_ignore_ascii_case(&pack.name))
+            })
+            .collect();
+        self.refresh_content();
+        self
+    }
+
+    /// The included packs, with heroes on the side of their cards for `players`.
+    fn included_packs(&self, players: PlayerCount) -> Vec<ContentPack> {
+        pack::get_all_packs(players)
+            .into_iter()
+            .zip(self.included.iter())
+            .filter(|(_, included)| **included)
+            .map(|(pack, _)| pack)
+            .collect()
+    }
+
+    fn refresh_content(&mut self) {
+        let packs = self.included_packs(PlayerCount::Solo);
+        self.heroes = packs.iter().flat_map(|pack| pack.heroes.clone()).collect();
+        self.heroes.extend(self.custom_heroes.iter().cloned());
+        self.dungeons = packs
+            .iter()
+            .flat_map(|pack| pack.dungeons.clone())
+            .collect();
+        self.hero_cursor = self.hero_cursor.min(self.heroes.len().saturating_sub(1));
+        self.partner_cursor = self.partner_cursor.min(self.heroes.len());
+        self.dungeon_cursor = self
+            .dungeon_cursor
+            .min(self.dungeons.len().saturating_sub(1));
+    }
+
+    pub fn with_difficulty(mut self, difficulty: Difficulty) -> App {
+        self.difficulty = difficulty;
+        self
+    }
+
+    /// Loads the history at `path` and appends every game finished from now on.
+    pub fn with_history(mut self, path: PathBuf) -> App {
+        match History::load(&path) {
+            Ok(history) => self.history = history,
+            Err(err) => self.push_log(err.to_string()),
+        }
+        self.history_path = Some(path);
+        self
+    }
+
+    /// Skips the setup screen and starts straight away with the given game.
+    pub fn with_game(game: Game) -> App {
+        let mut app = App::new(game.seed).with_difficulty(game.difficulty);
+        app.push_log(format!(
+            "{} enters {}",
+            game.hero_names(),
+            game.dungeon.name
+        ));
+        app.game = Some(game);
+        app.screen = Screen::Playing;
+        app
+    }
+
+    pub fn push_log(&mut self, message: String) {
+        self.log.push(message);
+        if self.log.len() > LOG_LENGTH {
+            self.log.remove(0);
+        }
+    }
+
+    pub fn handle_key(&mut self, key: KeyCode) {
+        // The editor takes every key so that names can be typed.
+        if self.screen == Screen::Editor {
+            self.handle_editor_key(key);
+            return;
+        }
+        if key == KeyCode::Char('q') || key == KeyCode::Esc {
+            self.should_quit = true;
+            return;
+        }
+        match self.screen {
+            Screen::Setup => self.handle_setup_key(key),
+            Screen::Playing => self.handle_playing_key(key),
+            Screen::Editor => {}
+        }
+    }
+
+    fn handle_setup_key(&mut self, key: KeyCode) {
+        let (cursor, len) = match self.setup_focus {
+            SetupFocus::Hero => (&mut self.hero_cursor, self.heroes.len()),
+            SetupFocus::Partner => (&mut self.partner_cursor, self.heroes.len() + 1),
+            SetupFocus::Dungeon => (&mut self.dungeon_cursor, self.dungeons.len()),
+            SetupFocus::Packs => (&mut self.pack_cursor, self.packs.len()),
+        };
+        match key {
+            KeyCode::Up if len > 0 => *cursor = (*cursor + len - 1) % len,
+            KeyCode::Down if len > 0 => *cursor = (*cursor + 1) % len,
+            KeyCode::Tab | KeyCode::Left | KeyCode::Right => {
+                self.setup_focus = match self.setup_focus {
+                    SetupFocus::Hero => SetupFocus::Partner,
+                    SetupFocus::Partner => SetupFocus::Dungeon,
+                    SetupFocus::Dungeon => SetupFocus::Packs,
+                    SetupFocus::Packs => SetupFocus::Hero,
+                }
+            }
+            KeyCode::Char(' ') if self.setup_focus == SetupFocus::Packs => {
+                if let Some(included) = self.included.get_mut(self.pack_cursor) {
+                    *included = !*included;
+                }
+                self.refresh_content();
+            }
+            KeyCode::Char('d') => self.difficulty = self.difficulty.next(),
+            KeyCode::Char('s') => self.show_stats = !self.show_stats,
+            KeyCode::Char('e') => self.open_editor(),
+            KeyCode::Enter => self.start_game(),
+            _ => {}
+        }
+    }
+
+    /// Opens the editor on a copy of the highlighted hero.
+    fn open_editor(&mut self) {
+        let builder = match self.heroes.get(self.hero_cursor) {
+            Some(hero) => {
+                HeroBuilder::from_hero(hero.clone()).with_name(&format!("Custom {}", hero.name))
+            }
+            None => HeroBuilder::new("Custom Hero"),
+        };
+        self.editor = Some(HeroEditor::new(builder.hero().clone()));
+        self.screen = Screen::Editor;
+    }
+
+    fn handle_editor_key(&mut self, key: KeyCode) {
+        let Some(editor) = self.editor.as_mut() else {
+            self.screen = Screen::Setup;
+            return;
+        };
+        match key {
+            KeyCode::Esc => {
+                self.editor = None;
+                self.screen = Screen::Setup;
+            }
+            KeyCode::Enter => self.save_custom_hero(),
+            key => editor.handle_key(key),
+        }
+    }
+
+    /// Saves the hero in the editor as JSON and offers it on the setup screen.
+    fn save_custom_hero(&mut self) {
+        let Some(editor) = self.editor.as_mut() else {
+            return;
+        };
+        let hero = match HeroBuilder::from_hero(editor.hero.clone()).build() {
+            Ok(hero) => hero,
+            Err(err) => {
+                editor.message = Some(err.to_string());
+                return;
+            }
+        };
+        let path = self.hero_dir.join(hero_builder::file_name(&hero));
+        match hero_builder::save_hero(&hero, &path) {
+            Ok(()) => {}
+            Err(DataError::Io(err)) if err.kind() == io::ErrorKind::AlreadyExists => {
+                editor.message = Some(format!(
+                    "{} already exists; rename the hero to save it",
+                    path.display()
+                ));
+                return;
+            }
+            Err(err) => {
+                editor.message = Some(err.to_string());
+                return;
+            }
+        }
+        self.push_log(format!("Saved {} to {}", hero.name, path.display()));
+        self.custom_heroes.retain(|known| known.name != hero.name);
+        self.custom_heroes.push(hero);
+        self.refresh_content();
+        self.hero_cursor = self.heroes.len() - 1;
+        self.editor = None;
+        self.screen = Screen::Setup;
+    }
+
+    fn start_game(&mut self) {
+        let deck = pack::deck(&self.included_packs(PlayerCount::Solo));
+        let Some(dungeon) = self.dungeons.get(self.dungeon_cursor).cloned() else {
+            self.push_log(String::from("The chosen packs have no dungeons"));
+            return;
+        };
+        if self.heroes.is_empty() || deck.is_empty() {
+            self.push_log(String::from("The chosen packs need heroes and cards too"));
+            return;
+        }
+        if self.partner_cursor.checked_sub(1) == Some(self.hero_cursor) {
+            self.push_log(format!(
+                "{} cannot be both the hero and the partner",
+                self.heroes[self.hero_cursor].name
+            ));
+            return;
+        }
+        let game = match self.partner_cursor.checked_sub(1) {
+            None => {
+                let hero = self.heroes[self.hero_cursor].clone();
+                Game::with_difficulty(hero, dungeon, self.seed, self.difficulty)
+            }
+            Some(partner) => {
+                let mut heroes: Vec<Hero> = self
+                    .included_packs(PlayerCount::Coop)
+                    .into_iter()
+                    .flat_map(|pack| pack.heroes)
+                    .chain(
+                        self.custom_heroes
+                            .iter()
+                            .cloned()
+                            .map(hero_builder::coop_side),
+                    )
+                    .collect();
+                let partner = heroes[partner].clone();
+                let hero = heroes.swap_remove(self.hero_cursor);
+                Game::co_op(hero, partner, dungeon, self.seed, self.difficulty)
+            }
+        };
+        let game = game.with_deck(deck);
+        self.log.clear();
+        self.push_log(format!(
+            "{} enters {} ({})",
+            game.hero_names(),
+            game.dungeon.name,
+            self.difficulty
+        ));
+        self.game = Some(game);
+        self.seed = self.seed.wrapping_add(1);
+        self.dice_cursor = 0;
+        self.box_cursor = 0;
+        self.marked.clear();
+        self.screen = Screen::Playing;
+    }
+
+    fn handle_playing_key(&mut self, key: KeyCode) {
+        let Some(game) = self.game.as_ref() else {
+            self.screen = Screen::Setup;
+            return;
+        };
+        let action = match (&game.phase, key) {
+            (Phase::Won | Phase::Lost(_), KeyCode::Char('n')) => {
+                self.game = None;
+                self.screen = Screen::Setup;
+                return;
+            }
+            (phase, KeyCode::Char('a')) if !matches!(phase, Phase::Won | Phase::Lost(_)) => {
+                Some(self.autoplay.decide(&game.observe()))
+            }
+            (Phase::Exploring, KeyCode::Char('e')) => Some(Action::Explore),
+            (Phase::Exploring, KeyCode::Char('p')) => Some(Action::DrinkPotion),
+            (Phase::Exploring, KeyCode::Char('d')) => Some(Action::Descend),
+            (Phase::Exploring, KeyCode::Char(c)) if c.is_ascii_digit() => {
+                digit_index(c).map(Action::OpenDoor)
+            }
+            (Phase::DoorOpened(_), KeyCode::Char('f')) => Some(Action::Fight),
+            (Phase::DoorOpened(_), KeyCode::Char('r')) => Some(Action::Flee),
+            (Phase::ChoosingPeril(_), KeyCode::Char(c)) if c.is_ascii_digit() => {
+                digit_index(c).map(Action::ChoosePeril)
+            }
+            (Phase::Encounter, key) => self.encounter_key(key),
+            (Phase::Loot, KeyCode::Char('i')) => Some(Action::Loot(LootChoice::Item)),
+            (Phase::Loot, KeyCode::Char('s')) => Some(Action::Loot(LootChoice::Skill)),
+            (Phase::Loot, KeyCode::Char('x')) => Some(Action::Loot(LootChoice::Xp)),
+            _ => None,
+        };
+        if let Some(action) = action {
+            self.perform(action);
+        }
+    }
+
+    fn encounter_key(&mut self, key: KeyCode) -> Option<Action> {
+        let state = self.game.as_ref()?.encounter.as_ref()?;
+        let dice_count = state.pool.len().max(1);
+        let box_count = state.boxes.len().max(1);
+        match key {
+            KeyCode::Left => {
+                self.dice_cursor = (self.dice_cursor + dice_count - 1) % dice_count;
+                None
+            }
+            KeyCode::Right => {
+                self.dice_cursor = (self.dice_cursor + 1) % dice_count;
+                None
+            }
+            KeyCode::Up => {
+                self.box_cursor = (self.box_cursor + box_count - 1) % box_count;
+                None
+            }
+            KeyCode::Down => {
+                self.box_cursor = (self.box_cursor + 1) % box_count;
+                None
+            }
+            KeyCode::Enter | KeyCode::Char(' ') => Some(Action::PlaceDice {
+                dice: self.dice_cursor,
+                challenge_box: self.box_cursor,
+            }),
+            KeyCode::Char('m') => {
+                if let Some(position) = self.marked.iter().position(|i| *i == self.dice_cursor) {
+                    self.marked.remove(position);
+                } else {
+                    self.marked.push(self.dice_cursor);
+                }
+                None
+            }
+            KeyCode::Char('c') => Some(Action::MakeHeroic(std::mem::take(&mut self.marked))),
+            KeyCode::Char('x') => Some(Action::FinishEncounter),
+            // Skills aim at the dice under the cursor when they can aim at all.
+            KeyCode::Char(c) if c.is_ascii_digit() => digit_index(c).map(|skill| {
+                let aimed = Action::UseSkill {
+                    skill,
+                    dice: Some(self.dice_cursor),
+                };
+                let game = self.game.as_ref();
+                if game.is_some_and(|game| game.legal_actions().contains(&aimed)) {
+                    aimed
+                } else {
+                    Action::UseSkill { skill, dice: None }
+                }
+            }),
+            _ => None,
+        }
+    }
+
+    fn perform(&mut self, action: Action) {
+        let Some(game) = self.game.as_mut() else {
+            return;
+        };
+        let was_over = game.is_over();
+        let result = game.apply(action);
+        let finished = (!was_over && game.is_over()).then(|| RunRecord::from_game(game));
+        let pool_len = game.encounter.as_ref().map_or(0, |state| state.pool.len());
+        let box_len = game.encounter.as_ref().map_or(0, |state| state.boxes.len());
+        match result {
+            Ok(messages) => {
+                for message in messages {
+                    self.push_log(message);
+                }
+            }
+            Err(err) => self.push_log(err.to_string()),
+        }
+        if self.dice_cursor >= pool_len {
+            self.dice_cursor = pool_len.saturating_sub(1);
+        }
+        if self.box_cursor >= box_len {
+            self.box_cursor = box_len.saturating_sub(1);
+        }
+        self.marked.retain(|index| *index < pool_len);
+        if let Some(run) = finished {
+            self.record(run);
+        }
+    }
+
+    fn record(&mut self, run: RunRecord) {
+        if let Some(path) = self.history_path.as_ref() {
+            if let Err(err) = History::append(path, &run) {
+                self.push_log(err.to_string());
+            }
+        }
+        self.history.runs.push(run);
+    }
+}
+
+/// Keys 1-9 map to zero-based indices.
+fn digit_index(c: char) -> Option<usize> {
+    c.to_digit(10)
+        .filter(|digit| *digit > 0)
+        .map(|digit| digit as usize - 1)
+}
@@ -0,0 +1,479 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Padding, Paragraph, Widget},
+};
+use strum::IntoEnumIterator;
+
+use crate::{
+    dungeon::{ChallengeBox, Dungeon},
+    encounter::{Combat, Encounter, Peril},
+    hero::{Attribute, Hero, Resource, Skill},
+};
+
+use super::ui::dice_color;
+
+const DICE_ICON: &str = "\u{25a0}";
+
+/// The contents of a card, laid out once and shared by the widget and the plain-text fallback.
+pub trait CardContent {
+    fn lines(&self) -> Vec<Line<'static>>;
+
+    /// Renders the card as bordered ASCII text with its contents wrapped to `width`.
+    fn to_plain_text(&self, width: usize) -> String {
+        let mut output = border(width);
+        for line in wrap_lines(&self.lines(), width) {
+            let text: String = line
+                .spans
+                .iter()
+                .map(|span| span.content.as_ref())
+                .collect();
+            output.push_str(&format!("| {: <width$} |\n", text));
+        }
+        output.push_str(&border(width));
+        output
+    }
+}
+
+fn border(width: usize) -> String {
+    format!("|{}|\n", "-".repeat(width + 2))
+}
+
+fn render_card(content: &impl CardContent, area: Rect, buf: &mut Buffer) {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .padding(Padding::horizontal(1));
+    let inner = block.inner(area);
+    block.render(area, buf);
+    Paragraph::new(wrap_lines(&content.lines(), inner.width as usize)).render(inner, buf);
+}
+
+/// Word-wraps styled lines to `width`, honouring embedded newlines and splitting
+/// words that are longer than a whole line.
+pub fn wrap_lines(lines: &[Line<'static>], width: usize) -> Vec<Line<'static>> {
+    let width = width.max(1);
+    let mut wrapped = Vec::new();
+    for line in lines {
+        let mut current: Vec<Span<'static>> = Vec::new();
+        let mut column = 0;
+        for span in line.spans.iter() {
+            for (index, segment) in span.content.split('\n').enumerate() {
+                if index > 0 {
+                    wrapped.push(finish_line(&mut current));
+                    column = 0;
+                }
+                for word in segment.split_inclusive(' ') {
+                    let mut word: String = word.to_string();
+                    let length = word.trim_end().chars().count();
+                    if column > 0 && column + length > width {
+                        wrapped.push(finish_line(&mut current));
+                        column = 0;
+                        if word.trim().is_empty() {
+                            continue;
+                        }
+                    }
+                    while word.trim_end().chars().count() > width - column {
+                        let head: String = word.chars().take(width - column).collect();
+                        word = word.chars().skip(width - column).collect();
+                        current.push(Span::styled(head, span.style));
+                        wrapped.push(finish_line(&mut current));
+                        column = 0;
+                    }
+                    column += word.chars().count();
+                    current.push(Span::styled(word, span.style));
+                }
+            }
+        }
+        wrapped.push(finish_line(&mut current));
+    }
+    wrapped
+}
+
+/// Drops the trailing space left behind by the last word on a wrapped line.
+fn finish_line(current: &mut Vec<Span<'static>>) -> Line<'static> {
+    let mut spans = std::mem::take(current);
+    if let Some(last) = spans.last_mut() {
+        let trimmed = last.content.trim_end().to_string();
+        last.content = trimmed.into();
+    }
+    Line::from(spans)
+}
+
+fn bold(text: String) -> Line<'static> {
+    Line::from(Span::styled(
+        text,
+        Style::default().add_modifier(Modifier::BOLD),
+    ))
+}
+
+/// Shows dice counts as coloured dice icons, e.g. "■■ Strength".
+fn attribute_span(attribute: &Attribute) -> Span<'static> {
+    match attribute {
+        Attribute::DiceCount(colour, quantity) => Span::styled(
+            format!("{} {}", DICE_ICON.repeat(*quantity), colour),
+            Style::default().fg(dice_color(colour)),
+        ),
+        Attribute::DieOfValue(colour, _) => Span::styled(
+            attribute.to_string(),
+            Style::default().fg(dice_color(colour)),
+        ),
+        Attribute::Resource(_, _) => Span::raw(attribute.to_string()),
+    }
+}
+
+fn attribute_list(label: &str, attributes: &[Attribute]) -> Line<'static> {
+    let mut spans = vec![Span::raw(label.to_string())];
+    for (index, attribute) in attributes.iter().enumerate() {
+        if index > 0 {
+            spans.push(Span::raw(", "));
+        }
+        spans.push(attribute_span(attribute));
+    }
+    Line::from(spans)
+}
+
+fn challenge_line(challenge: &ChallengeBox) -> Line<'static> {
+    let style = challenge
+        .dice_type
+        .as_ref()
+        .map_or(Style::default(), |dice_type| {
+            Style::default().fg(dice_color(dice_type))
+        });
+    Line::from(Span::styled(challenge.to_string(), style))
+}
+
+fn encounters_line(encounters: &[Encounter]) -> Line<'static> {
+    Line::from(
+        encounters
+            .iter()
+            .map(|encounter| encounter.to_string())
+            .collect::<Vec<String>>()
+            .join(", "),
+    )
+}
+
+fn reward_lines(
+    xp_reward: i8,
+    item_reward: &[Attribute],
+    skill_reward: &Skill,
+) -> Vec<Line<'static>> {
+    let mut lines = vec![
+        Line::from(format!("Reward: {} XP", xp_reward)),
+        attribute_list("Item: ", item_reward),
+    ];
+    lines.extend(SkillCard::new(skill_reward).lines());
+    lines
+}
+
+pub struct SkillCard<'a> {
+    skill: &'a Skill,
+}
+
+impl<'a> SkillCard<'a> {
+    pub fn new(skill: &'a Skill) -> SkillCard<'a> {
+        SkillCard { skill }
+    }
+}
+
+impl CardContent for SkillCard<'_> {
+    fn lines(&self) -> Vec<Line<'static>> {
+        let mut lines = vec![bold(format!("Skill: {}", self.skill.name))];
+        if let Some(description) = &self.skill.description {
+            lines.push(Line::from(description.clone()));
+        }
+        match &self.skill.requirements {
+            Some(requirements) => lines.push(Line::from(vec![
+                Span::raw("Requirements: "),
+                attribute_span(requirements),
+            ])),
+            None => lines.push(Line::from("Requirements: Free skill")),
+        }
+        lines.push(Line::from(self.skill.effect.to_string()));
+        lines.push(encounters_line(&self.skill.encounters));
+        lines
+    }
+}
+
+impl Widget for SkillCard<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        render_card(&self, area, buf);
+    }
+}
+
+pub struct HeroCard<'a> {
+    hero: &'a Hero,
+}
+
+impl<'a> HeroCard<'a> {
+    pub fn new(hero: &'a Hero) -> HeroCard<'a> {
+        HeroCard { hero }
+    }
+}
+
+impl CardContent for HeroCard<'_> {
+    fn lines(&self) -> Vec<Line<'static>> {
+        let hero = self.hero;
+        let mut lines = vec![
+            bold(hero.name.to_uppercase()),
+            Line::from(format!("{} side", hero.players)),
+        ];
+        for attribute in hero.attributes.values() {
+            lines.push(Line::from(attribute_span(attribute)));
+        }
+        lines.push(Line::from(attribute_span(&Attribute::Resource(
+            Resource::Health,
+            hero.health,
+        ))));
+        lines.push(Line::from(""));
+
+        lines.push(bold(format!("Heroic Feat: {}", hero.heroic_feat.name)));
+        lines.push(Line::from(hero.heroic_feat.description.clone()));
+        lines.push(Line::from(
+            Encounter::iter()
+                .map(|encounter| {
+                    if hero.heroic_feat.encounters.contains(&encounter) {
+                        encounter.to_string()
+                    } else {
+                        format!("Not {}", encounter)
+                    }
+                })
+                .collect::<Vec<String>>()
+                .join(", "),
+        ));
+        lines.push(Line::from(""));
+
+        for skill in hero.skills.iter() {
+            lines.extend(SkillCard::new(skill).lines());
+        }
+        lines
+    }
+}
+
+impl Widget for HeroCard<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        render_card(&self, area, buf);
+    }
+}
+
+pub struct DungeonCard<'a> {
+    dungeon: &'a Dungeon,
+}
+
+impl<'a> DungeonCard<'a> {
+    pub fn new(dungeon: &'a Dungeon) -> DungeonCard<'a> {
+        DungeonCard { dungeon }
+    }
+}
+
+impl CardContent for DungeonCard<'_> {
+    fn lines(&self) -> Vec<Line<'static>> {
+        let dungeon = self.dungeon;
+        let mut lines = vec![
+            bold(dungeon.name.to_uppercase()),
+            Line::from(format!("Difficulty: {}", dungeon.difficulty)),
+            Line::from(""),
+        ];
+        let mut levels: Vec<i8> = dungeon
+            .peril_challenges
+            .keys()
+            .chain(dungeon.combat_challenges.keys())
+            .copied()
+            .collect();
+        levels.sort_unstable();
+        levels.dedup();
+        for level in levels {
+            lines.push(bold(format!("Level {}", level)));
+            lines.push(Line::from("Peril:"));
+            for challenge in dungeon.peril_challenges.get(&level).into_iter().flatten() {
+                lines.push(challenge_line(challenge));
+            }
+            lines.push(Line::from("Combat:"));
+            for challenge in dungeon.combat_challenges.get(&level).into_iter().flatten() {
+                lines.push(challenge_line(challenge));
+            }
+            lines.push(Line::from(""));
+        }
+        lines.push(bold(format!(
+            "Boss: {} ({} x Health)",
+            dungeon.boss.name, dungeon.boss.health
+        )));
+        lines.push(Line::from(dungeon.boss.special_ability.to_string()));
+        for challenge in dungeon.boss.challenges.iter() {
+            lines.push(challenge_line(challenge));
+        }
+        lines
+    }
+}
+
+impl Widget for DungeonCard<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        render_card(&self, area, buf);
+    }
+}
+
+pub struct CombatCard<'a> {
+    combat: &'a Combat,
+}
+
+impl<'a> CombatCard<'a> {
+    pub fn new(combat: &'a Combat) -> CombatCard<'a> {
+        CombatCard { combat }
+    }
+}
+
+impl CardContent for CombatCard<'_> {
+    fn lines(&self) -> Vec<Line<'static>> {
+        let combat = self.combat;
+        let mut lines = vec![
+            bold(combat.name.to_uppercase()),
+            Line::from(combat.special_ability.to_string()),
+        ];
+        for challenge in combat.challenges.iter() {
+            lines.push(challenge_line(challenge));
+        }
+        lines.push(Line::from(""));
+        lines.extend(reward_lines(
+            combat.xp_reward,
+            &combat.item_reward,
+            &combat.skill_reward,
+        ));
+        lines
+    }
+}
+
+impl Widget for CombatCard<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        render_card(&self, area, buf);
+    }
+}
+
+pub struct PerilCard<'a> {
+    peril: &'a Peril,
+}
+
+impl<'a> PerilCard<'a> {
+    pub fn new(peril: &'a Peril) -> PerilCard<'a> {
+        PerilCard { peril }
+    }
+}
+
+impl CardContent for PerilCard<'_> {
+    fn lines(&self) -> Vec<Line<'static>> {
+        let peril = self.peril;
+        let mut lines = vec![bold(peril.name.to_uppercase())];
+        for (index, (choice, time_cost)) in [
+            (&peril.choice_one, peril.choice_one_time_cost),
+            (&peril.choice_two, peril.choice_two_time_cost),
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            match time_cost {
+                Some(time_cost) => lines.push(Line::from(format!(
+                    "Option {} ({} x Time):",
+                    index + 1,
+                    time_cost
+                ))),
+                None => lines.push(Line::from(format!("Option {}:", index + 1))),
+            }
+            for challenge in choice.iter() {
+                lines.push(challenge_line(challenge));
+            }
+        }
+        lines.push(Line::from(""));
+        lines.extend(reward_lines(
+            peril.xp_reward,
+            &peril.item_reward,
+            &peril.skill_reward,
+        ));
+        lines
+    }
+}
+
+impl Widget for PerilCard<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        render_card(&self, area, buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game_setup;
+    use ratatui::style::Color;
+
+    fn texts(lines: &[Line<'static>]) -> Vec<String> {
+        lines.iter().map(|line| line.to_string()).collect()
+    }
+
+    fn longest_skill() -> Skill {
+        game_setup::get_all_skills()
+            .into_iter()
+            .max_by_key(|skill| skill.description.as_ref().map_or(0, String::len))
+            .unwrap()
+    }
+
+    #[test]
+    fn wraps_a_long_description_without_losing_words() {
+        let description = longest_skill().description.unwrap();
+        let wrapped = wrap_lines(&[Line::from(description.clone())], 20);
+        assert!(wrapped.len() > 1);
+        assert!(wrapped.iter().all(|line| line.width() <= 20));
+        let words: Vec<String> = texts(&wrapped)
+            .iter()
+            .flat_map(|line| {
+                line.split_whitespace()
+                    .map(String::from)
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+        let expected: Vec<&str> = description.split_whitespace().collect();
+        assert_eq!(words, expected);
+    }
+
+    #[test]
+    fn splits_words_longer_than_a_line() {
+        let wrapped = wrap_lines(&[Line::from("ab abcdefghijkl")], 5);
+        assert_eq!(texts(&wrapped), ["ab", "abcde", "fghij", "kl"]);
+    }
+
+    #[test]
+    fn breaks_lines_at_embedded_newlines() {
+        let wrapped = wrap_lines(&[Line::from("one\ntwo three")], 20);
+        assert_eq!(texts(&wrapped), ["one", "two three"]);
+    }
+
+    #[test]
+    fn keeps_the_style_across_a_wrap() {
+        let style = Style::default().fg(Color::Red);
+        let line = Line::from(vec![
+            Span::raw("Gain "),
+            Span::styled("value 6 STRENGTH", style),
+        ]);
+        let wrapped = wrap_lines(&[line], 8);
+        assert_eq!(texts(&wrapped), ["Gain", "value 6", "STRENGTH"]);
+        for line in &wrapped[1..] {
+            assert!(line.spans.iter().all(|span| span.style == style));
+        }
+    }
+
+    #[test]
+    fn plain_text_matches_the_widget() {
+        let skill = longest_skill();
+        let width = 24;
+        let plain = SkillCard::new(&skill).to_plain_text(width);
+        let rows: Vec<&str> = plain.lines().collect();
+        let area = Rect::new(0, 0, width as u16 + 4, rows.len() as u16);
+        let mut buf = Buffer::empty(area);
+        SkillCard::new(&skill).render(area, &mut buf);
+        for (y, row) in rows.iter().enumerate().skip(1).take(rows.len() - 2) {
+            let rendered: String = (2..2 + width as u16)
+                .map(|x| buf.get(x, y as u16).symbol())
+                .collect();
+            let text: String = row.chars().skip(2).take(width).collect();
+            assert_eq!(rendered.trim_end(), text.trim_end(), "row {}", y);
+        }
+    }
+}
@@ -0,0 +1,240 @@
+use crossterm::event::KeyCode;
+
+use crate::{
+    game_setup,
+    hero::{Attribute, DiceColour, Hero, HeroicFeat, LevelStats, PlayerCount, Skill},
+    hero_builder,
+    lint::LintIssue,
+};
+
+const MAX_NAME_LENGTH: usize = 24;
+const MAX_DICE: usize = 8;
+const MAX_HEALTH: usize = 12;
+const MAX_POTIONS: i8 = 5;
+const MAX_LEVEL_STAT: i8 = 9;
+const MAX_LEVELS: usize = 6;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EditorField {
+    Name,
+    Dice(DiceColour),
+    Health,
+    Potions,
+    Side,
+    HeroicFeat,
+    Level(i8, LevelField),
+    /// A skill from the registry, by index, that the hero may start with.
+    Skill(usize),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LevelField {
+    Items,
+    Skills,
+    HeroicDice,
+}
+
+/// The homebrew hero being put together on the editor screen.
+pub struct HeroEditor {
+    pub hero: Hero,
+    pub cursor: usize,
+    /// Every skill a hero can start with and every heroic feat to borrow.
+    pub skills: Vec<Skill>,
+    pub feats: Vec<HeroicFeat>,
+    /// Why the last save failed, if it did.
+    pub message: Option<String>,
+}
+
+impl HeroEditor {
+    pub fn new(hero: Hero) -> HeroEditor {
+        HeroEditor {
+            hero,
+            cursor: 0,
+            skills: game_setup::get_all_skills(),
+            feats: game_setup::get_all_heroes(PlayerCount::Solo)
+                .into_iter()
+                .map(|hero| hero.heroic_feat)
+                .collect(),
+            message: None,
+        }
+    }
+
+    /// Every field in the order they are listed, one row each.
+    pub fn fields(&self) -> Vec<EditorField> {
+        let mut fields = vec![EditorField::Name];
+        fields.extend(
+            [DiceColour::Strength, DiceColour::Agility, DiceColour::Magic]
+                .into_iter()
+                .map(EditorField::Dice),
+        );
+        fields.extend([
+            EditorField::Health,
+            EditorField::Potions,
+            EditorField::Side,
+            EditorField::HeroicFeat,
+        ]);
+        for level in self.hero.levels.keys() {
+            for field in [
+                LevelField::Items,
+                LevelField::Skills,
+                LevelField::HeroicDice,
+            ] {
+                fields.push(EditorField::Level(*level, field));
+            }
+        }
+        fields.extend((0..self.skills.len()).map(EditorField::Skill));
+        fields
+    }
+
+    pub fn field(&self) -> Option<EditorField> {
+        self.fields().into_iter().nth(self.cursor)
+    }
+
+    pub fn issues(&self) -> Vec<LintIssue> {
+        hero_builder::validate(&self.hero)
+    }
+
+    pub fn label(&self, field: &EditorField) -> String {
+        let hero = &self.hero;
+        match field {
+            EditorField::Name => format!("Name: {}", hero.name),
+            EditorField::Dice(colour) => {
+                format!("{}: {}", colour, hero.dice_count(colour))
+            }
+            EditorField::Health => format!("Health: {}", hero.health),
+            EditorField::Potions => format!("Potions: {}", hero.potions),
+            EditorField::Side => format!("Card side: {}", hero.players),
+            EditorField::HeroicFeat => format!("Heroic feat: {}", hero.heroic_feat.name),
+            EditorField::Level(level, field) => {
+                let stats = hero.levels.get(level);
+                let (name, value) = match field {
+                    LevelField::Items => ("items", stats.map(|stats| stats.items)),
+                    LevelField::Skills => ("skills", stats.map(|stats| stats.skills)),
+                    LevelField::HeroicDice => {
+                        ("heroic dice", stats.map(|stats| stats.encounter_bonus))
+                    }
+                };
+                format!("Level {} {}: {}", level, name, value.unwrap_or(0))
+            }
+            EditorField::Skill(index) => {
+                let skill = &self.skills[*index];
+                let chosen = hero.skills.iter().any(|known| known.name == skill.name);
+                format!("[{}] {}", if chosen { "x" } else { " " }, skill.name)
+            }
+        }
+    }
+
+    /// Handles every key except Enter and Esc, which the app uses to save and
+    /// to leave the editor.
+    pub fn handle_key(&mut self, key: KeyCode) {
+        let len = self.fields().len();
+        let Some(field) = self.field() else {
+            return;
+        };
+        match (key, &field) {
+            (KeyCode::Up, _) => self.cursor = (self.cursor + len - 1) % len,
+            (KeyCode::Down, _) => self.cursor = (self.cursor + 1) % len,
+            (KeyCode::Backspace, EditorField::Name) => {
+                self.hero.name.pop();
+            }
+            (KeyCode::Char(c), EditorField::Name)
+                if self.hero.name.chars().count() < MAX_NAME_LENGTH =>
+            {
+                self.hero.name.push(c);
+            }
+            (KeyCode::Char(_), EditorField::Name) => {}
+            (KeyCode::Left, _) => self.adjust(&field, -1),
+            (KeyCode::Right, _) => self.adjust(&field, 1),
+            (KeyCode::Char(' '), EditorField::Skill(index)) => self.toggle_skill(*index),
+            (KeyCode::Char('+'), EditorField::Level(..)) => self.add_level(),
+            (KeyCode::Char('-'), EditorField::Level(..)) => self.remove_level(),
+            _ => {}
+        }
+    }
+
+    fn adjust(&mut self, field: &EditorField, change: i8) {
+        let hero = &mut self.hero;
+        match field {
+            EditorField::Name => {}
+            EditorField::Dice(colour) => {
+                let count = step(hero.dice_count(colour), change, MAX_DICE);
+                hero.attributes
+                    .insert(*colour, Attribute::DiceCount(*colour, count));
+            }
+            EditorField::Health => hero.health = step(hero.health, change, MAX_HEALTH),
+            EditorField::Potions => hero.potions = (hero.potions + change).clamp(0, MAX_POTIONS),
+            EditorField::Side => {
+                hero.players = match hero.players {
+                    PlayerCount::Solo => PlayerCount::Coop,
+                    PlayerCount::Coop => PlayerCount::Solo,
+                }
+            }
+            EditorField::HeroicFeat => {
+                let len = self.feats.len();
+                if len == 0 {
+                    return;
+                }
+                let current = self
+                    .feats
+                    .iter()
+                    .position(|feat| feat.name == hero.heroic_feat.name);
+                let next = match (current, change > 0) {
+                    (Some(index), true) => (index + 1) % len,
+                    (Some(index), false) => (index + len - 1) % len,
+                    (None, _) => 0,
+                };
+                hero.heroic_feat = self.feats[next].clone();
+            }
+            EditorField::Level(level, field) => {
+                if let Some(stats) = hero.levels.get_mut(level) {
+                    let value = match field {
+                        LevelField::Items => &mut stats.items,
+                        LevelField::Skills => &mut stats.skills,
+                        LevelField::HeroicDice => &mut stats.encounter_bonus,
+                    };
+                    *value = (*value + change).clamp(0, MAX_LEVEL_STAT);
+                }
+            }
+            EditorField::Skill(index) => self.toggle_skill(*index),
+        }
+    }
+
+    fn toggle_skill(&mut self, index: usize) {
+        let skill = &self.skills[index];
+        match self
+            .hero
+            .skills
+            .iter()
+            .position(|known| known.name == skill.name)
+        {
+            Some(position) => {
+                self.hero.skills.remove(position);
+            }
+            None => self.hero.skills.push(skill.clone()),
+        }
+    }
+
+    /// Adds a level after the last one, copying its stats.
+    fn add_level(&mut self) {
+        if self.hero.levels.len() >= MAX_LEVELS {
+            return;
+        }
+        let (last, stats): (i8, LevelStats) = match self.hero.levels.last_key_value() {
+            Some((level, stats)) => (*level, *stats),
+            None => return,
+        };
+        self.hero.levels.insert(last + 1, stats);
+    }
+
+    /// Drops the last level, always keeping the first.
+    fn remove_level(&mut self) {
+        if self.hero.levels.len() > 1 {
+            self.hero.levels.pop_last();
+            self.cursor = self.cursor.min(self.fields().len() - 1);
+        }
+    }
+}
+
+fn step(value: usize, change: i8, max: usize) -> usize {
+    value.saturating_add_signed(change as isize).min(max)
+}
@@ -0,0 +1,552 @@
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Wrap},
+    Frame,
+};
+
+use crate::{
+    game::{Action, Card, Game, Phase},
+    hero::{Attribute, DiceColour, Resource},
+};
+
+use super::{
+    app::{App, Screen, SetupFocus},
+    cards::{CombatCard, HeroCard, PerilCard},
+    editor::HeroEditor,
+};
+
+pub fn draw(frame: &mut Frame, app: &App) {
+    match app.screen {
+        Screen::Setup => draw_setup(frame, app),
+        Screen::Playing => match app.game.as_ref() {
+            Some(game) => draw_game(frame, app, game),
+            None => draw_setup(frame, app),
+        },
+        Screen::Editor => match app.editor.as_ref() {
+            Some(editor) => draw_editor(frame, editor),
+            None => draw_setup(frame, app),
+        },
+    }
+}
+
+pub fn dice_color(dice_type: &DiceColour) -> Color {
+    match dice_type {
+        DiceColour::Strength => Color::Red,
+        DiceColour::Agility => Color::Green,
+        DiceColour::Magic => Color::Blue,
+        DiceColour::Heroic => Color::Yellow,
+    }
+}
+
+fn draw_setup(frame: &mut Frame, app: &App) {
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(5), Constraint::Length(3)])
+        .split(frame.size());
+    if app.show_stats {
+        frame.render_widget(
+            Paragraph::new(app.history.report().to_string())
+                .block(Block::default().borders(Borders::ALL).title("Stats")),
+            rows[0],
+        );
+    } else {
+        draw_setup_lists(frame, app, rows[0]);
+    }
+    frame.render_widget(
+        Paragraph::new(format!(
+            "Up/Down: choose   Tab: switch list   Space: toggle pack   d: difficulty ({})   s: stats   e: edit hero   Enter: start   q: quit",
+            app.difficulty
+        ))
+        .block(Block::default().borders(Borders::ALL)),
+        rows[1],
+    );
+}
+
+fn draw_setup_lists(frame: &mut Frame, app: &App, area: Rect) {
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Percentage(20),
+            Constraint::Percentage(20),
+            Constraint::Percentage(40),
+            Constraint::Percentage(20),
+        ])
+        .split(area);
+    let heroes: Vec<ListItem> = app
+        .heroes
+        .iter()
+        .map(|hero| ListItem::new(hero.name.clone()))
+        .collect();
+    let partners: Vec<ListItem> = std::iter::once(ListItem::new("Solo"))
+        .chain(
+            app.heroes
+                .iter()
+                .map(|hero| ListItem::new(hero.name.clone())),
+        )
+        .collect();
+    let dungeons: Vec<ListItem> = app
+        .dungeons
+        .iter()
+        .map(|dungeon| {
+            ListItem::new(format!(
+                "{} (difficulty {})",
+                dungeon.name, dungeon.difficulty
+            ))
+        })
+        .collect();
+    let packs: Vec<ListItem> = app
+        .packs
+        .iter()
+        .zip(app.included.iter())
+        .map(|(pack, included)| {
+            ListItem::new(format!(
+                "[{}] {}",
+                if *included { "x" } else { " " },
+                pack.name
+            ))
+        })
+        .collect();
+    let highlight = Style::default()
+        .fg(Color::Black)
+        .bg(Color::Yellow)
+        .add_modifier(Modifier::BOLD);
+
+    let mut hero_state = ListState::default().with_selected(Some(app.hero_cursor));
+    let mut partner_state = ListState::default().with_selected(Some(app.partner_cursor));
+    let mut dungeon_state = ListState::default().with_selected(Some(app.dungeon_cursor));
+    let mut pack_state = ListState::default().with_selected(Some(app.pack_cursor));
+    frame.render_stateful_widget(
+        List::new(heroes)
+            .block(focus_block("Hero", app.setup_focus == SetupFocus::Hero))
+            .highlight_style(highlight),
+        columns[0],
+        &mut hero_state,
+    );
+    frame.render_stateful_widget(
+        List::new(partners)
+            .block(focus_block(
+                "Co-op partner",
+                app.setup_focus == SetupFocus::Partner,
+            ))
+            .highlight_style(highlight),
+        columns[1],
+        &mut partner_state,
+    );
+    frame.render_stateful_widget(
+        List::new(dungeons)
+            .block(focus_block(
+                "Dungeon",
+                app.setup_focus == SetupFocus::Dungeon,
+            ))
+            .highlight_style(highlight),
+        columns[2],
+        &mut dungeon_state,
+    );
+    frame.render_stateful_widget(
+        List::new(packs)
+            .block(focus_block("Packs", app.setup_focus == SetupFocus::Packs))
+            .highlight_style(highlight),
+        columns[3],
+        &mut pack_state,
+    );
+}
+
+fn draw_editor(frame: &mut Frame, editor: &HeroEditor) {
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(5), Constraint::Length(3)])
+        .split(frame.size());
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(40), Constraint::Percentage(60)])
+        .split(rows[0]);
+    let preview = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(10), Constraint::Length(8)])
+        .split(columns[1]);
+
+    let fields: Vec<ListItem> = editor
+        .fields()
+        .iter()
+        .map(|field| ListItem::new(editor.label(field)))
+        .collect();
+    let highlight = Style::default()
+        .fg(Color::Black)
+        .bg(Color::Yellow)
+        .add_modifier(Modifier::BOLD);
+    let mut state = ListState::default().with_selected(Some(editor.cursor));
+    frame.render_stateful_widget(
+        List::new(fields)
+            .block(focus_block("Custom hero", true))
+            .highlight_style(highlight),
+        columns[0],
+        &mut state,
+    );
+    frame.render_widget(HeroCard::new(&editor.hero), preview[0]);
+
+    let mut lines: Vec<Line> = editor
+        .issues()
+        .iter()
+        .map(|issue| {
+            Line::from(Span::styled(
+                issue.message.clone(),
+                Style::default().fg(Color::Red),
+            ))
+        })
+        .collect();
+    if lines.is_empty() {
+        lines.push(Line::from("Ready to save"));
+    }
+    if let Some(message) = editor.message.as_ref() {
+        lines.push(Line::from(message.clone()));
+    }
+    frame.render_widget(
+        Paragraph::new(lines)
+            .block(Block::default().borders(Borders::ALL).title("Problems"))
+            .wrap(Wrap { trim: false }),
+        preview[1],
+    );
+    frame.render_widget(
+        Paragraph::new(
+            "Up/Down: field   Left/Right: change   Space: toggle skill   +/-: add/remove level   type: rename   Enter: save   Esc: back",
+        )
+        .block(Block::default().borders(Borders::ALL)),
+        rows[1],
+    );
+}
+
+fn focus_block(title: &str, focused: bool) -> Block<'_> {
+    let style = if focused {
+        Style::default().fg(Color::Yellow)
+    } else {
+        Style::default()
+    };
+    Block::default()
+        .borders(Borders::ALL)
+        .title(title.to_string())
+        .border_style(style)
+}
+
+fn draw_game(frame: &mut Frame, app: &App, game: &Game) {
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(12),
+            Constraint::Length(8),
+            Constraint::Length(3),
+        ])
+        .split(frame.size());
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(35), Constraint::Percentage(65)])
+        .split(rows[0]);
+    let board = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(8),
+            Constraint::Min(5),
+            Constraint::Length(4),
+        ])
+        .split(columns[1]);
+
+    draw_hero(frame, game, columns[0]);
+    draw_dungeon(frame, game, board[0]);
+    draw_boxes(frame, app, game, board[1]);
+    draw_dice(frame, app, game, board[2]);
+    draw_log(frame, app, rows[1]);
+    frame.render_widget(
+        Paragraph::new(help_text(&game.phase)).block(Block::default().borders(Borders::ALL)),
+        rows[2],
+    );
+}
+
+fn draw_hero(frame: &mut Frame, game: &Game, area: Rect) {
+    let hero = &game.hero;
+    let title = if game.partner.is_some() {
+        format!("{}'s turn", hero.name)
+    } else {
+        String::from("Hero")
+    };
+    let mut lines = vec![Line::from(Span::styled(
+        hero.name.to_uppercase(),
+        Style::default().add_modifier(Modifier::BOLD),
+    ))];
+    for attribute in hero.attributes.values() {
+        let color = match attribute {
+            Attribute::DiceCount(colour, _) | Attribute::DieOfValue(colour, _) => {
+                dice_color(colour)
+            }
+            Attribute::Resource(_, _) => Color::White,
+        };
+        lines.push(Line::from(Span::styled(
+            attribute.to_string(),
+            Style::default().fg(color),
+        )));
+    }
+    lines.push(Line::from(format!(
+        "Health: {}/{}",
+        game.remaining_health(),
+        hero.health
+    )));
+    lines.push(Line::from(format!(
+        "Potions: {}   XP: {}   Level: {}",
+        hero.potions, hero.xp, hero.current_level
+    )));
+    if let Some(partner) = game.partner.as_ref() {
+        lines.push(Line::from(Span::styled(
+            format!(
+                "Next turn: {} (Health {}/{}, Potions {}, XP {})",
+                partner.hero.name,
+                partner.remaining_health(),
+                partner.hero.health,
+                partner.hero.potions,
+                partner.hero.xp
+            ),
+            Style::default().add_modifier(Modifier::ITALIC),
+        )));
+    }
+    if let Some(stats) = hero.level_stats() {
+        lines.push(Line::from(format!(
+            "Items: {}/{}   Skills: {}/{}",
+            hero.items.len(),
+            stats.items,
+            hero.skills.len(),
+            stats.skills
+        )));
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::from(format!(
+        "Heroic Feat: {}",
+        hero.heroic_feat.name
+    )));
+    lines.push(Line::from(""));
+    // A co-op partner's skills are numbered on from the hero's, as encounters use them.
+    for (index, (owner, skill)) in game.encounter_skills().into_iter().enumerate() {
+        let requirement = skill
+            .requirements
+            .as_ref()
+            .map_or(String::from("free"), |requirement| requirement.to_string());
+        let owner = if std::ptr::eq(owner, hero) {
+            String::new()
+        } else {
+            format!(", {}", owner.name)
+        };
+        lines.push(Line::from(Span::styled(
+            format!("{}. {} ({}{})", index + 1, skill.name, requirement, owner),
+            Style::default().add_modifier(Modifier::BOLD),
+        )));
+        lines.push(Line::from(format!("   {}", skill.effect)));
+    }
+    frame.render_widget(
+        Paragraph::new(lines)
+            .block(Block::default().borders(Borders::ALL).title(title))
+            .wrap(Wrap { trim: false }),
+        area,
+    );
+}
+
+fn draw_dungeon(frame: &mut Frame, game: &Game, area: Rect) {
+    let floor = if game.is_boss_floor() {
+        format!(
+            "Boss: {} ({}/{})",
+            game.dungeon.boss.name, game.boss_damage, game.dungeon.boss.health
+        )
+    } else {
+        format!("Floor {}", game.floor)
+    };
+    let mut lines = vec![Line::from(format!(
+        "{}   {}   Deck: {}   Discard: {}",
+        game.dungeon.name,
+        floor,
+        game.deck.len(),
+        game.discard.len()
+    ))];
+    for (index, door) in game.doors.iter().enumerate() {
+        let label = if door.revealed {
+            format!("{} ({})", door.card.name(), door.card.encounter())
+        } else {
+            String::from("closed")
+        };
+        let style = match game.phase {
+            Phase::DoorOpened(open) | Phase::ChoosingPeril(open) if open == index => {
+                Style::default().fg(Color::Yellow)
+            }
+            _ => Style::default(),
+        };
+        lines.push(Line::from(Span::styled(
+            format!("Door {}: {}", index + 1, label),
+            style,
+        )));
+    }
+    if let Some(card) = game.loot.as_ref() {
+        lines.push(Line::from(format!(
+            "Loot {}: item {} / skill {} / {} XP",
+            card.name(),
+            card.item_reward()
+                .iter()
+                .map(|attribute| attribute.to_string())
+                .collect::<Vec<String>>()
+                .join(", "),
+            card.skill_reward().name,
+            card.xp_reward()
+        )));
+    }
+    frame.render_widget(
+        Paragraph::new(lines)
+            .block(Block::default().borders(Borders::ALL).title("Dungeon"))
+            .wrap(Wrap { trim: false }),
+        area,
+    );
+}
+
+fn draw_card(frame: &mut Frame, card: &Card, area: Rect) {
+    match card {
+        Card::Peril(peril) => frame.render_widget(PerilCard::new(peril), area),
+        Card::Combat(combat) => frame.render_widget(CombatCard::new(combat), area),
+    }
+}
+
+fn draw_boxes(frame: &mut Frame, app: &App, game: &Game, area: Rect) {
+    match (&game.phase, game.loot.as_ref()) {
+        (Phase::DoorOpened(door), _) => return draw_card(frame, &game.doors[*door].card, area),
+        (Phase::Loot, Some(card)) => return draw_card(frame, card, area),
+        _ => {}
+    }
+    let mut lines = Vec::new();
+    match (&game.phase, game.encounter.as_ref()) {
+        (_, Some(state)) => {
+            lines.push(Line::from(Span::styled(
+                format!("{} - {}", state.name, state.special_ability),
+                Style::default().add_modifier(Modifier::BOLD),
+            )));
+            let legal_actions = game.legal_actions();
+            for (index, placed) in state.boxes.iter().enumerate() {
+                let marker = if index == app.box_cursor { "> " } else { "  " };
+                let fits = legal_actions.contains(&Action::PlaceDice {
+                    dice: app.dice_cursor,
+                    challenge_box: index,
+                });
+                let status = if placed.is_complete() {
+                    String::from("done")
+                } else {
+                    format!("{}/{}", placed.total(), placed.challenge.total_value)
+                };
+                let color = placed
+                    .challenge
+                    .dice_type
+                    .as_ref()
+                    .map_or(Color::White, dice_color);
+                lines.push(Line::from(vec![
+                    Span::raw(format!("{}{}. ", marker, index + 1)),
+                    Span::styled(placed.challenge.to_string(), Style::default().fg(color)),
+                    Span::raw(format!("  [{}]", status)),
+                    Span::styled(
+                        if fits { "  fits" } else { "" },
+                        Style::default().fg(Color::Green),
+                    ),
+                ]));
+            }
+            if !state.prevented.is_empty() {
+                lines.push(Line::from(format!(
+                    "Preventing: {}",
+                    state
+                        .prevented
+                        .iter()
+                        .map(Resource::to_string)
+                        .collect::<Vec<String>>()
+                        .join(", ")
+                )));
+            }
+        }
+        (Phase::ChoosingPeril(door), None) => {
+            if let Card::Peril(peril) = &game.doors[*door].card {
+                for (index, (choice, time)) in [
+                    (&peril.choice_one, peril.choice_one_time_cost),
+                    (&peril.choice_two, peril.choice_two_time_cost),
+                ]
+                .into_iter()
+                .enumerate()
+                {
+                    lines.push(Line::from(format!(
+                        "Option {} (spend {} x Time first):",
+                        index + 1,
+                        time.unwrap_or(0)
+                    )));
+                    for challenge in choice {
+                        lines.push(Line::from(format!("   {}", challenge)));
+                    }
+                }
+            }
+        }
+        (Phase::Won, None) => lines.push(Line::from(format!(
+            "Victory! Score {} on {}. Press n for a new game.",
+            game.score(),
+            game.difficulty
+        ))),
+        (Phase::Lost(cause), None) => lines.push(Line::from(format!(
+            "Defeated by {}. Score {} on {}. Press n for a new game.",
+            cause,
+            game.score(),
+            game.difficulty
+        ))),
+        _ => lines.push(Line::from("No encounter in progress")),
+    }
+    frame.render_widget(
+        Paragraph::new(lines)
+            .block(Block::default().borders(Borders::ALL).title("Encounter"))
+            .wrap(Wrap { trim: false }),
+        area,
+    );
+}
+
+fn draw_dice(frame: &mut Frame, app: &App, game: &Game, area: Rect) {
+    let mut spans = Vec::new();
+    if let Some(state) = game.encounter.as_ref() {
+        for (index, dice) in state.pool.iter().enumerate() {
+            let mut style = Style::default()
+                .fg(Color::Black)
+                .bg(dice_color(&dice.dice_type));
+            if index == app.dice_cursor {
+                style = style.add_modifier(Modifier::REVERSED | Modifier::BOLD);
+            }
+            if app.marked.contains(&index) {
+                style = style.add_modifier(Modifier::UNDERLINED);
+            }
+            spans.push(Span::styled(format!(" {} ", dice.value), style));
+            spans.push(Span::raw(" "));
+        }
+    }
+    frame.render_widget(
+        Paragraph::new(Line::from(spans))
+            .block(Block::default().borders(Borders::ALL).title("Dice")),
+        area,
+    );
+}
+
+fn draw_log(frame: &mut Frame, app: &App, area: Rect) {
+    let visible = area.height.saturating_sub(2) as usize;
+    let start = app.log.len().saturating_sub(visible);
+    let lines: Vec<Line> = app.log[start..]
+        .iter()
+        .map(|message| Line::from(message.clone()))
+        .collect();
+    frame.render_widget(
+        Paragraph::new(lines).block(Block::default().borders(Borders::ALL).title("Log")),
+        area,
+    );
+}
+
+fn help_text(phase: &Phase) -> &'static str {
+    match phase {
+        Phase::Exploring => "e: explore   1-4: open door   p: potion   d: descend   a: auto   q: quit",
+        Phase::DoorOpened(_) => "f: fight   r: flee   a: auto   q: quit",
+        Phase::ChoosingPeril(_) => "1/2: choose option   a: auto   q: quit",
+        Phase::Encounter => {
+            "Left/Right: dice   Up/Down: box   Enter: place   m: mark   c: combine heroic   1-9: skill   x: finish   a: auto"
+        }
+        Phase::Loot => "i: item   s: skill   x: xp   a: auto   q: quit",
+        Phase::Won | Phase::Lost(_) => "n: new game   q: quit",
+    }
+}